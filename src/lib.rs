@@ -22,6 +22,7 @@ extern crate rand;
 
 pub mod queue;
 pub mod process;
+pub mod loggers;
 
 
 #[cfg(test)]
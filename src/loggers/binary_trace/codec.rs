@@ -0,0 +1,65 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::process::config::AbstractProcessConfiguration;
+
+
+
+/** 
+ * Encodes domain-specific payloads (nodes, steps and filtration results) into bytes 
+ * to be stored in a binary trace, and decodes them back when the trace is replayed.
+ * 
+ * Decoding errors are reported as strings.
+ * **/
+pub trait AbstractBinaryPayloadCodec<Conf : AbstractProcessConfiguration> {
+
+    fn encode_node(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        node : &Conf::DomainSpecificNode
+    ) -> Vec<u8>;
+
+    fn decode_node(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        bytes : &[u8]
+    ) -> Result<Conf::DomainSpecificNode,String>;
+
+    fn encode_step(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        step : &Conf::DomainSpecificStep
+    ) -> Vec<u8>;
+
+    fn decode_step(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        bytes : &[u8]
+    ) -> Result<Conf::DomainSpecificStep,String>;
+
+    fn encode_filtration_result(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        filtration_result : &Conf::FiltrationResult
+    ) -> Vec<u8>;
+
+    fn decode_filtration_result(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        bytes : &[u8]
+    ) -> Result<Conf::FiltrationResult,String>;
+
+}
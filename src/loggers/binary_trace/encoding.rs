@@ -0,0 +1,315 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read};
+
+use crate::process::filter_statistics::FilterKind;
+use crate::queue::strategy::QueueSearchStrategy;
+
+
+
+/// bytes with which every binary trace starts
+pub const BINARY_TRACE_MAGIC : &[u8; 4] = b"GPMT";
+/// version of the format, written right after the magic bytes
+pub const BINARY_TRACE_VERSION : u64 = 1;
+
+
+
+/** 
+ * The kinds of events of a binary trace.
+ * 
+ * A trace consists of the magic bytes, the version (as a varint) and a sequence of events.
+ * Each event consists of its kind (as a varint) followed by its fields.
+ * Identifiers, depths and counters are encoded as varints (LEB128) 
+ * and domain-specific payloads are length-prefixed (by a varint).
+ * 
+ * So as to keep traces compact:
+ * - a step leading to the node that has just been reached does not repeat the payload of that node
+ * - a memo hit right after the step it concerns does not repeat the payload of that step
+ * **/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BinaryTraceEventKind {
+    /// strategy, use_memoization
+    Initialize,
+    /// node_id, node
+    NewNode,
+    /// origin_node_id, target_node_id, step (the target is the last new node)
+    NewStepToNewNode,
+    /// origin_node_id, target_node_id, step, target_node
+    NewStepToKnownNode,
+    /// origin_node_id, target_node_id (the step is that of the last step)
+    MemoHitOfLastStep,
+    /// origin_node_id, target_node_id, step
+    MemoHit,
+    /// memoized_node_id, new_node_id, new_depth
+    NodeReexpanded,
+    /// parent_node_id
+    LastChildStepProcessed,
+    /// node_id
+    NodeWithoutChildren,
+    /// parent_node_id, filtration_result_id, filtration_result
+    Filtered,
+    /// parent_node_id, step, filtration_result_id, filtration_result
+    StepFiltered,
+    /// parent_node_id, step, child_node, filtration_result_id, filtration_result
+    EdgeFiltered,
+    /// node_id, has_step, (step)
+    Deferred,
+    /// number of filters, and for each: name, kind, description, enabled, defers, statistics
+    FiltersSummary,
+    /// no field
+    Terminate,
+    /// no field (the global state being provided upon replay)
+    Resume,
+    /// num_memoized_nodes
+    MemoSize
+}
+
+impl BinaryTraceEventKind {
+
+    pub fn to_code(&self) -> u64 {
+        match self {
+            BinaryTraceEventKind::Initialize => 0,
+            BinaryTraceEventKind::NewNode => 1,
+            BinaryTraceEventKind::NewStepToNewNode => 2,
+            BinaryTraceEventKind::NewStepToKnownNode => 3,
+            BinaryTraceEventKind::MemoHitOfLastStep => 4,
+            BinaryTraceEventKind::MemoHit => 5,
+            BinaryTraceEventKind::NodeReexpanded => 6,
+            BinaryTraceEventKind::LastChildStepProcessed => 7,
+            BinaryTraceEventKind::NodeWithoutChildren => 8,
+            BinaryTraceEventKind::Filtered => 9,
+            BinaryTraceEventKind::EdgeFiltered => 10,
+            BinaryTraceEventKind::Deferred => 11,
+            BinaryTraceEventKind::FiltersSummary => 12,
+            BinaryTraceEventKind::Terminate => 13,
+            BinaryTraceEventKind::Resume => 14,
+            BinaryTraceEventKind::MemoSize => 15,
+            BinaryTraceEventKind::StepFiltered => 16
+        }
+    }
+
+    pub fn from_code(code : u64) -> Option<Self> {
+        match code {
+            0 => Some(BinaryTraceEventKind::Initialize),
+            1 => Some(BinaryTraceEventKind::NewNode),
+            2 => Some(BinaryTraceEventKind::NewStepToNewNode),
+            3 => Some(BinaryTraceEventKind::NewStepToKnownNode),
+            4 => Some(BinaryTraceEventKind::MemoHitOfLastStep),
+            5 => Some(BinaryTraceEventKind::MemoHit),
+            6 => Some(BinaryTraceEventKind::NodeReexpanded),
+            7 => Some(BinaryTraceEventKind::LastChildStepProcessed),
+            8 => Some(BinaryTraceEventKind::NodeWithoutChildren),
+            9 => Some(BinaryTraceEventKind::Filtered),
+            10 => Some(BinaryTraceEventKind::EdgeFiltered),
+            11 => Some(BinaryTraceEventKind::Deferred),
+            12 => Some(BinaryTraceEventKind::FiltersSummary),
+            13 => Some(BinaryTraceEventKind::Terminate),
+            14 => Some(BinaryTraceEventKind::Resume),
+            15 => Some(BinaryTraceEventKind::MemoSize),
+            16 => Some(BinaryTraceEventKind::StepFiltered),
+            _ => None
+        }
+    }
+
+}
+
+
+
+pub(crate) fn strategy_to_code(strategy : &QueueSearchStrategy) -> u64 {
+    match strategy {
+        QueueSearchStrategy::BFS => 0,
+        QueueSearchStrategy::DFS => 1,
+        QueueSearchStrategy::HCS => 2
+    }
+}
+
+pub(crate) fn strategy_from_code(code : u64) -> Option<QueueSearchStrategy> {
+    match code {
+        0 => Some(QueueSearchStrategy::BFS),
+        1 => Some(QueueSearchStrategy::DFS),
+        2 => Some(QueueSearchStrategy::HCS),
+        _ => None
+    }
+}
+
+pub(crate) fn filter_kind_to_code(kind : &FilterKind) -> u64 {
+    match kind {
+        FilterKind::NodePre => 0,
+        FilterKind::NodePost => 1,
+        FilterKind::Step => 2,
+        FilterKind::Edge => 3
+    }
+}
+
+pub(crate) fn filter_kind_from_code(code : u64) -> Option<FilterKind> {
+    match code {
+        0 => Some(FilterKind::NodePre),
+        1 => Some(FilterKind::NodePost),
+        2 => Some(FilterKind::Step),
+        3 => Some(FilterKind::Edge),
+        _ => None
+    }
+}
+
+
+
+/** 
+ * Errors that may occur when reading a binary trace.
+ * **/
+#[derive(Debug)]
+pub enum BinaryTraceError {
+    Io(io::Error),
+    /// the trace does not start with the magic bytes
+    NotABinaryTrace,
+    UnsupportedVersion(u64),
+    UnknownEventKind(u64),
+    UnknownStrategy(u64),
+    UnknownFilterKind(u64),
+    /// a varint is longer than 10 bytes
+    MalformedVarint,
+    /// a string is not valid UTF-8
+    MalformedString,
+    /// an event refers to a node or step that has not been recorded right before it
+    MissingPrecedingEvent(BinaryTraceEventKind),
+    /// the codec failed to decode a payload
+    Payload(String)
+}
+
+impl fmt::Display for BinaryTraceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryTraceError::Io(e) => {
+                write!(f,"i/o error : {}", e)
+            },
+            BinaryTraceError::NotABinaryTrace => {
+                write!(f,"not a binary trace")
+            },
+            BinaryTraceError::UnsupportedVersion(version) => {
+                write!(f,"unsupported binary trace version {}", version)
+            },
+            BinaryTraceError::UnknownEventKind(code) => {
+                write!(f,"unknown event kind {}", code)
+            },
+            BinaryTraceError::UnknownStrategy(code) => {
+                write!(f,"unknown strategy {}", code)
+            },
+            BinaryTraceError::UnknownFilterKind(code) => {
+                write!(f,"unknown filter kind {}", code)
+            },
+            BinaryTraceError::MalformedVarint => {
+                write!(f,"malformed varint")
+            },
+            BinaryTraceError::MalformedString => {
+                write!(f,"malformed string")
+            },
+            BinaryTraceError::MissingPrecedingEvent(kind) => {
+                write!(f,"event {:?} is not preceded by the event it refers to", kind)
+            },
+            BinaryTraceError::Payload(reason) => {
+                write!(f,"could not decode payload : {}", reason)
+            }
+        }
+    }
+}
+
+impl Error for BinaryTraceError {}
+
+impl From<io::Error> for BinaryTraceError {
+    fn from(e : io::Error) -> Self {
+        BinaryTraceError::Io(e)
+    }
+}
+
+
+
+pub fn write_varint(buffer : &mut Vec<u8>, mut value : u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+pub fn write_bytes(buffer : &mut Vec<u8>, bytes : &[u8]) {
+    write_varint(buffer, bytes.len() as u64);
+    buffer.extend_from_slice(bytes);
+}
+
+pub fn write_string(buffer : &mut Vec<u8>, string : &str) {
+    write_bytes(buffer, string.as_bytes());
+}
+
+/** 
+ * Reads a single byte, returning None if the source is exhausted.
+ * **/
+pub fn read_byte_or_end(source : &mut dyn Read) -> io::Result<Option<u8>> {
+    let mut byte = [0u8];
+    loop {
+        match source.read(&mut byte) {
+            Ok(0) => return Ok(None),
+            Ok(_) => return Ok(Some(byte[0])),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+            Err(e) => return Err(e)
+        }
+    }
+}
+
+/** 
+ * Reads a varint whose first byte has already been read.
+ * **/
+pub fn read_varint_from(source : &mut dyn Read, first_byte : u8) -> Result<u64,BinaryTraceError> {
+    let mut value = (first_byte & 0x7f) as u64;
+    let mut byte = first_byte;
+    let mut shift = 0;
+    while byte & 0x80 != 0 {
+        shift += 7;
+        if shift > 63 {
+            return Err(BinaryTraceError::MalformedVarint);
+        }
+        let mut next = [0u8];
+        source.read_exact(&mut next)?;
+        byte = next[0];
+        value |= ((byte & 0x7f) as u64) << shift;
+    }
+    Ok(value)
+}
+
+pub fn read_varint(source : &mut dyn Read) -> Result<u64,BinaryTraceError> {
+    let mut first = [0u8];
+    source.read_exact(&mut first)?;
+    read_varint_from(source, first[0])
+}
+
+pub fn read_u32(source : &mut dyn Read) -> Result<u32,BinaryTraceError> {
+    u32::try_from(read_varint(source)?).map_err(|_| BinaryTraceError::MalformedVarint)
+}
+
+pub fn read_bytes(source : &mut dyn Read) -> Result<Vec<u8>,BinaryTraceError> {
+    let length = read_varint(source)?;
+    let mut bytes = vec![];
+    source.take(length).read_to_end(&mut bytes)?;
+    if (bytes.len() as u64) < length {
+        return Err(BinaryTraceError::Io(io::Error::from(io::ErrorKind::UnexpectedEof)));
+    }
+    Ok(bytes)
+}
+
+pub fn read_string(source : &mut dyn Read) -> Result<String,BinaryTraceError> {
+    String::from_utf8(read_bytes(source)?).map_err(|_| BinaryTraceError::MalformedString)
+}
@@ -0,0 +1,295 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::time::Duration;
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::filter::GenericFiltersManager;
+use crate::process::filter_statistics::{FilterActivationStatistics, ManagedFilterInfo};
+use crate::process::logger::*;
+use crate::queue::priorities::GenericProcessPriorities;
+
+use super::codec::AbstractBinaryPayloadCodec;
+use super::encoding::*;
+
+
+
+/** 
+ * Reads a binary trace recorded by a 
+ * [BinaryTraceWriterLogger](crate::loggers::binary_trace::writer::BinaryTraceWriterLogger)
+ * so as to replay it into other loggers.
+ * 
+ * Domain-specific payloads are decoded by the "codec", which must match the one used for recording.
+ * The trace is read event by event, so that a buffered source (e.g., a BufReader) should be used.
+ * **/
+pub struct BinaryTraceReader<Source,Codec> {
+    pub source : Source,
+    pub codec : Codec
+}
+
+impl<Source : Read, Codec> BinaryTraceReader<Source,Codec> {
+
+    /** 
+     * Checks the header of the trace (magic bytes and version) before returning the reader.
+     * **/
+    pub fn new(mut source : Source, codec : Codec) -> Result<Self,BinaryTraceError> {
+        let mut magic = [0u8;4];
+        source.read_exact(&mut magic).map_err(|_| BinaryTraceError::NotABinaryTrace)?;
+        if &magic != BINARY_TRACE_MAGIC {
+            return Err(BinaryTraceError::NotABinaryTrace);
+        }
+        let version = read_varint(&mut source)?;
+        if version != BINARY_TRACE_VERSION {
+            return Err(BinaryTraceError::UnsupportedVersion(version));
+        }
+        Ok(Self { source, codec })
+    }
+
+    /** 
+     * Replays all the remaining events of the trace into the given loggers
+     * and returns the number of replayed events.
+     * 
+     * The trace does not record the priorities, the filters and the global state of the process.
+     * Those which are given here are provided to the loggers upon initialization 
+     * (the "global_state" being also provided upon resumption and termination).
+     * **/
+    pub fn replay<Conf>(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        priorities : &GenericProcessPriorities<Conf::Priorities>,
+        filters_manager : &GenericFiltersManager<Conf>,
+        global_state : &Conf::MutablePersistentState,
+        loggers : &mut [Box<dyn AbstractProcessLogger<Conf>>]
+    ) -> Result<u64,BinaryTraceError>
+        where 
+            Conf : AbstractProcessConfiguration,
+            Codec : AbstractBinaryPayloadCodec<Conf>
+    {
+        let mut num_events = 0;
+        // the node that has just been reached, as a step leading to it does not repeat it
+        let mut last_new_node : Option<(u32,Conf::DomainSpecificNode)> = None;
+        // the last step, as a memo hit right after it does not repeat it
+        let mut last_step : Option<(u32,u32,Conf::DomainSpecificStep)> = None;
+        while let Some(first_byte) = read_byte_or_end(&mut self.source)? {
+            let code = read_varint_from(&mut self.source, first_byte)?;
+            let kind = BinaryTraceEventKind::from_code(code).ok_or(BinaryTraceError::UnknownEventKind(code))?;
+            match kind {
+                BinaryTraceEventKind::Initialize => {
+                    let strategy_code = read_varint(&mut self.source)?;
+                    let strategy = strategy_from_code(strategy_code).ok_or(BinaryTraceError::UnknownStrategy(strategy_code))?;
+                    let use_memoization = read_varint(&mut self.source)? != 0;
+                    last_new_node = None;
+                    last_step = None;
+                    loggers_initialize(
+                        loggers.iter_mut(), 
+                        context_and_param, 
+                        &strategy, 
+                        priorities, 
+                        filters_manager, 
+                        global_state, 
+                        use_memoization
+                    );
+                },
+                BinaryTraceEventKind::NewNode => {
+                    let node_id = read_u32(&mut self.source)?;
+                    let node = self.read_node(context_and_param)?;
+                    loggers_new_node(loggers.iter_mut(), context_and_param, node_id, &node);
+                    last_new_node = Some((node_id,node));
+                },
+                BinaryTraceEventKind::NewStepToNewNode | BinaryTraceEventKind::NewStepToKnownNode => {
+                    let origin_node_id = read_u32(&mut self.source)?;
+                    let target_node_id = read_u32(&mut self.source)?;
+                    let step = self.read_step(context_and_param)?;
+                    let target_node = if kind == BinaryTraceEventKind::NewStepToNewNode {
+                        match last_new_node.take() {
+                            Some((node_id,node)) if node_id == target_node_id => node,
+                            _ => {
+                                return Err(BinaryTraceError::MissingPrecedingEvent(kind));
+                            }
+                        }
+                    } else {
+                        last_new_node = None;
+                        self.read_node(context_and_param)?
+                    };
+                    loggers_new_step(loggers.iter_mut(), context_and_param, origin_node_id, &step, target_node_id, &target_node);
+                    last_step = Some((origin_node_id,target_node_id,step));
+                },
+                BinaryTraceEventKind::MemoHitOfLastStep => {
+                    let origin_node_id = read_u32(&mut self.source)?;
+                    let target_node_id = read_u32(&mut self.source)?;
+                    match &last_step {
+                        Some((step_origin_id,step_target_id,step)) if *step_origin_id == origin_node_id && *step_target_id == target_node_id => {
+                            loggers_memo_hit(loggers.iter_mut(), context_and_param, origin_node_id, step, target_node_id);
+                        },
+                        _ => {
+                            return Err(BinaryTraceError::MissingPrecedingEvent(kind));
+                        }
+                    }
+                },
+                BinaryTraceEventKind::MemoHit => {
+                    let origin_node_id = read_u32(&mut self.source)?;
+                    let target_node_id = read_u32(&mut self.source)?;
+                    let step = self.read_step(context_and_param)?;
+                    loggers_memo_hit(loggers.iter_mut(), context_and_param, origin_node_id, &step, target_node_id);
+                },
+                BinaryTraceEventKind::MemoSize => {
+                    let num_memoized_nodes = usize::try_from(read_varint(&mut self.source)?).map_err(|_| BinaryTraceError::MalformedVarint)?;
+                    loggers_memo_size(loggers.iter_mut(), context_and_param, num_memoized_nodes);
+                },
+                BinaryTraceEventKind::NodeReexpanded => {
+                    let memoized_node_id = read_u32(&mut self.source)?;
+                    let new_node_id = read_u32(&mut self.source)?;
+                    let new_depth = read_u32(&mut self.source)?;
+                    loggers_node_reexpanded(loggers.iter_mut(), context_and_param, memoized_node_id, new_node_id, new_depth);
+                },
+                BinaryTraceEventKind::LastChildStepProcessed => {
+                    let parent_node_id = read_u32(&mut self.source)?;
+                    loggers_notify_last_child_step_of_node_processed(loggers.iter_mut(), context_and_param, parent_node_id);
+                },
+                BinaryTraceEventKind::NodeWithoutChildren => {
+                    let node_id = read_u32(&mut self.source)?;
+                    loggers_notify_node_without_children(loggers.iter_mut(), context_and_param, node_id);
+                },
+                BinaryTraceEventKind::Filtered => {
+                    let parent_node_id = read_u32(&mut self.source)?;
+                    let filtration_result_id = read_u32(&mut self.source)?;
+                    let filtration_result = self.read_filtration_result(context_and_param)?;
+                    loggers_filtered(loggers.iter_mut(), context_and_param, parent_node_id, filtration_result_id, &filtration_result);
+                },
+                BinaryTraceEventKind::StepFiltered => {
+                    let parent_node_id = read_u32(&mut self.source)?;
+                    let step = self.read_step(context_and_param)?;
+                    let filtration_result_id = read_u32(&mut self.source)?;
+                    let filtration_result = self.read_filtration_result(context_and_param)?;
+                    loggers_step_filtered(
+                        loggers.iter_mut(), 
+                        context_and_param, 
+                        parent_node_id, 
+                        &step, 
+                        filtration_result_id, 
+                        &filtration_result
+                    );
+                },
+                BinaryTraceEventKind::EdgeFiltered => {
+                    let parent_node_id = read_u32(&mut self.source)?;
+                    let step = self.read_step(context_and_param)?;
+                    let child_node = self.read_node(context_and_param)?;
+                    let filtration_result_id = read_u32(&mut self.source)?;
+                    let filtration_result = self.read_filtration_result(context_and_param)?;
+                    loggers_edge_filtered(
+                        loggers.iter_mut(), 
+                        context_and_param, 
+                        parent_node_id, 
+                        &step, 
+                        &child_node, 
+                        filtration_result_id, 
+                        &filtration_result
+                    );
+                },
+                BinaryTraceEventKind::Deferred => {
+                    let node_id = read_u32(&mut self.source)?;
+                    let step = if read_varint(&mut self.source)? != 0 {
+                        Some(self.read_step(context_and_param)?)
+                    } else {
+                        None
+                    };
+                    loggers_deferred(loggers.iter_mut(), context_and_param, node_id, step.as_ref());
+                },
+                BinaryTraceEventKind::FiltersSummary => {
+                    let filters_info = self.read_filters_info()?;
+                    let filters_info_refs : Vec<&ManagedFilterInfo> = filters_info.iter().collect();
+                    loggers_filters_summary(loggers.iter_mut(), context_and_param, &filters_info_refs);
+                },
+                BinaryTraceEventKind::Resume => {
+                    loggers_resume(loggers.iter_mut(), context_and_param, global_state);
+                },
+                BinaryTraceEventKind::Terminate => {
+                    loggers_terminate_process(loggers.iter_mut(), context_and_param, global_state);
+                }
+            }
+            num_events += 1;
+        }
+        Ok(num_events)
+    }
+
+    fn read_node<Conf>(&mut self, context_and_param : &Conf::ContextAndParameterization) -> Result<Conf::DomainSpecificNode,BinaryTraceError>
+        where 
+            Conf : AbstractProcessConfiguration,
+            Codec : AbstractBinaryPayloadCodec<Conf>
+    {
+        let bytes = read_bytes(&mut self.source)?;
+        self.codec.decode_node(context_and_param, &bytes).map_err(BinaryTraceError::Payload)
+    }
+
+    fn read_step<Conf>(&mut self, context_and_param : &Conf::ContextAndParameterization) -> Result<Conf::DomainSpecificStep,BinaryTraceError>
+        where 
+            Conf : AbstractProcessConfiguration,
+            Codec : AbstractBinaryPayloadCodec<Conf>
+    {
+        let bytes = read_bytes(&mut self.source)?;
+        self.codec.decode_step(context_and_param, &bytes).map_err(BinaryTraceError::Payload)
+    }
+
+    fn read_filtration_result<Conf>(&mut self, context_and_param : &Conf::ContextAndParameterization) -> Result<Conf::FiltrationResult,BinaryTraceError>
+        where 
+            Conf : AbstractProcessConfiguration,
+            Codec : AbstractBinaryPayloadCodec<Conf>
+    {
+        let bytes = read_bytes(&mut self.source)?;
+        self.codec.decode_filtration_result(context_and_param, &bytes).map_err(BinaryTraceError::Payload)
+    }
+
+    fn read_filters_info(&mut self) -> Result<Vec<ManagedFilterInfo>,BinaryTraceError> {
+        let num_filters = read_varint(&mut self.source)?;
+        let mut filters_info = vec![];
+        for _ in 0..num_filters {
+            let name = read_string(&mut self.source)?;
+            let kind_code = read_varint(&mut self.source)?;
+            let kind = filter_kind_from_code(kind_code).ok_or(BinaryTraceError::UnknownFilterKind(kind_code))?;
+            let description = read_string(&mut self.source)?;
+            let enabled = read_varint(&mut self.source)? != 0;
+            let defers = read_varint(&mut self.source)? != 0;
+            let num_applications = read_varint(&mut self.source)?;
+            let num_activations = read_varint(&mut self.source)?;
+            let total_duration = Duration::from_nanos(read_varint(&mut self.source)?);
+            let mut activation_depths = BTreeMap::new();
+            for _ in 0..read_varint(&mut self.source)? {
+                let depth = read_u32(&mut self.source)?;
+                let num_activations_at_depth = read_varint(&mut self.source)?;
+                activation_depths.insert(depth, num_activations_at_depth);
+            }
+            filters_info.push(
+                ManagedFilterInfo {
+                    name,
+                    kind,
+                    description,
+                    enabled,
+                    defers,
+                    statistics : FilterActivationStatistics {
+                        num_applications,
+                        num_activations,
+                        total_duration,
+                        activation_depths
+                    }
+                }
+            );
+        }
+        Ok(filters_info)
+    }
+
+}
@@ -0,0 +1,342 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::any::Any;
+use std::io::{self, Write};
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::filter::GenericFiltersManager;
+use crate::process::filter_statistics::ManagedFilterInfo;
+use crate::process::logger::AbstractProcessLogger;
+use crate::queue::priorities::GenericProcessPriorities;
+use crate::queue::strategy::QueueSearchStrategy;
+
+use super::codec::AbstractBinaryPayloadCodec;
+use super::encoding::*;
+
+
+
+/** 
+ * Logger that records every exploration event into a sink as a compact binary trace
+ * (see [BinaryTraceEventKind](BinaryTraceEventKind) for the format).
+ * Domain-specific payloads are encoded by the "codec".
+ * 
+ * The trace can later be replayed into other loggers via a 
+ * [BinaryTraceReader](crate::loggers::binary_trace::reader::BinaryTraceReader)
+ * e.g., to draw a recorded run without exploring it again.
+ * 
+ * Each event is written by a single call to the sink, so that a buffered sink (e.g., a BufWriter) should be used.
+ * Should writing into the sink fail, the logger stops writing and the error can be retrieved via "get_io_error".
+ * **/
+pub struct BinaryTraceWriterLogger<Sink,Codec> {
+    pub sink : Sink,
+    pub codec : Codec,
+    has_written_header : bool,
+    last_new_node_id : Option<u32>,
+    /// origin and target of the last step
+    last_step : Option<(u32,u32)>,
+    buffer : Vec<u8>,
+    io_error : Option<io::Error>
+}
+
+impl<Sink : Write, Codec> BinaryTraceWriterLogger<Sink,Codec> {
+
+    pub fn new(sink : Sink, codec : Codec) -> Self {
+        Self { 
+            sink, 
+            codec, 
+            has_written_header : false, 
+            last_new_node_id : None, 
+            last_step : None, 
+            buffer : vec![], 
+            io_error : None 
+        }
+    }
+
+    /** 
+     * Returns the error that occurred when writing into the sink, if any.
+     * **/
+    pub fn get_io_error(&self) -> Option<&io::Error> {
+        self.io_error.as_ref()
+    }
+
+    /** 
+     * Starts encoding an event of the given kind into the buffer.
+     * **/
+    fn start_event(&mut self, kind : BinaryTraceEventKind) {
+        self.buffer.clear();
+        write_varint(&mut self.buffer, kind.to_code());
+    }
+
+    fn write_event(&mut self) {
+        if self.io_error.is_some() {
+            return;
+        }
+        if let Err(e) = self.sink.write_all(&self.buffer) {
+            self.io_error = Some(e);
+        }
+    }
+
+}
+
+impl<Conf, Sink, Codec> AbstractProcessLogger<Conf> for BinaryTraceWriterLogger<Sink,Codec> 
+    where 
+        Conf : AbstractProcessConfiguration,
+        Sink : 'static + Write,
+        Codec : 'static + AbstractBinaryPayloadCodec<Conf>
+{
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        strategy : &QueueSearchStrategy,
+        _priorities : &GenericProcessPriorities<Conf::Priorities>,
+        _filters_manager : &GenericFiltersManager<Conf>,
+        _initial_global_state : &Conf::MutablePersistentState,
+        use_memoization : bool,
+    ) {
+        if !self.has_written_header {
+            self.buffer.clear();
+            self.buffer.extend_from_slice(BINARY_TRACE_MAGIC);
+            write_varint(&mut self.buffer, BINARY_TRACE_VERSION);
+            self.write_event();
+            self.has_written_header = true;
+        }
+        self.start_event(BinaryTraceEventKind::Initialize);
+        write_varint(&mut self.buffer, strategy_to_code(strategy));
+        write_varint(&mut self.buffer, use_memoization as u64);
+        self.write_event();
+        self.last_new_node_id = None;
+        self.last_step = None;
+    }
+
+    fn log_new_node(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        new_node_id : u32,
+        new_node : &Conf::DomainSpecificNode
+    ) {
+        self.start_event(BinaryTraceEventKind::NewNode);
+        write_varint(&mut self.buffer, new_node_id as u64);
+        write_bytes(&mut self.buffer, &self.codec.encode_node(context_and_param, new_node));
+        self.write_event();
+        self.last_new_node_id = Some(new_node_id);
+    }
+
+    fn log_new_step(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        origin_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        target_node_id : u32,
+        target_node : &Conf::DomainSpecificNode
+    ) {
+        let is_to_new_node = self.last_new_node_id == Some(target_node_id);
+        if is_to_new_node {
+            self.start_event(BinaryTraceEventKind::NewStepToNewNode);
+        } else {
+            self.start_event(BinaryTraceEventKind::NewStepToKnownNode);
+        }
+        write_varint(&mut self.buffer, origin_node_id as u64);
+        write_varint(&mut self.buffer, target_node_id as u64);
+        write_bytes(&mut self.buffer, &self.codec.encode_step(context_and_param, step));
+        if !is_to_new_node {
+            write_bytes(&mut self.buffer, &self.codec.encode_node(context_and_param, target_node));
+        }
+        self.write_event();
+        self.last_new_node_id = None;
+        self.last_step = Some((origin_node_id,target_node_id));
+    }
+
+    fn log_memo_hit(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        origin_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        target_node_id : u32
+    ) {
+        if self.last_step == Some((origin_node_id,target_node_id)) {
+            self.start_event(BinaryTraceEventKind::MemoHitOfLastStep);
+            write_varint(&mut self.buffer, origin_node_id as u64);
+            write_varint(&mut self.buffer, target_node_id as u64);
+        } else {
+            self.start_event(BinaryTraceEventKind::MemoHit);
+            write_varint(&mut self.buffer, origin_node_id as u64);
+            write_varint(&mut self.buffer, target_node_id as u64);
+            write_bytes(&mut self.buffer, &self.codec.encode_step(context_and_param, step));
+        }
+        self.write_event();
+    }
+
+    fn log_memo_size(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        num_memoized_nodes : usize
+    ) {
+        self.start_event(BinaryTraceEventKind::MemoSize);
+        write_varint(&mut self.buffer, num_memoized_nodes as u64);
+        self.write_event();
+    }
+
+    fn log_node_reexpanded(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        memoized_node_id : u32,
+        new_node_id : u32,
+        new_depth : u32
+    ) {
+        self.start_event(BinaryTraceEventKind::NodeReexpanded);
+        write_varint(&mut self.buffer, memoized_node_id as u64);
+        write_varint(&mut self.buffer, new_node_id as u64);
+        write_varint(&mut self.buffer, new_depth as u64);
+        self.write_event();
+    }
+
+    fn log_notify_last_child_step_of_node_processed(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32
+    ) {
+        self.start_event(BinaryTraceEventKind::LastChildStepProcessed);
+        write_varint(&mut self.buffer, parent_node_id as u64);
+        self.write_event();
+    }
+
+    fn log_notify_node_without_children(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        node_id : u32
+    ) {
+        self.start_event(BinaryTraceEventKind::NodeWithoutChildren);
+        write_varint(&mut self.buffer, node_id as u64);
+        self.write_event();
+    }
+
+    fn log_filtered(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        self.start_event(BinaryTraceEventKind::Filtered);
+        write_varint(&mut self.buffer, parent_node_id as u64);
+        write_varint(&mut self.buffer, filtration_result_id as u64);
+        write_bytes(&mut self.buffer, &self.codec.encode_filtration_result(context_and_param, filtration_result));
+        self.write_event();
+    }
+
+    fn log_step_filtered(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        self.start_event(BinaryTraceEventKind::StepFiltered);
+        write_varint(&mut self.buffer, parent_node_id as u64);
+        write_bytes(&mut self.buffer, &self.codec.encode_step(context_and_param, step));
+        write_varint(&mut self.buffer, filtration_result_id as u64);
+        write_bytes(&mut self.buffer, &self.codec.encode_filtration_result(context_and_param, filtration_result));
+        self.write_event();
+    }
+
+    fn log_edge_filtered(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        child_node : &Conf::DomainSpecificNode,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        self.start_event(BinaryTraceEventKind::EdgeFiltered);
+        write_varint(&mut self.buffer, parent_node_id as u64);
+        write_bytes(&mut self.buffer, &self.codec.encode_step(context_and_param, step));
+        write_bytes(&mut self.buffer, &self.codec.encode_node(context_and_param, child_node));
+        write_varint(&mut self.buffer, filtration_result_id as u64);
+        write_bytes(&mut self.buffer, &self.codec.encode_filtration_result(context_and_param, filtration_result));
+        self.write_event();
+    }
+
+    fn log_deferred(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        node_id : u32,
+        step : Option<&Conf::DomainSpecificStep>
+    ) {
+        self.start_event(BinaryTraceEventKind::Deferred);
+        write_varint(&mut self.buffer, node_id as u64);
+        write_varint(&mut self.buffer, step.is_some() as u64);
+        if let Some(step) = step {
+            write_bytes(&mut self.buffer, &self.codec.encode_step(context_and_param, step));
+        }
+        self.write_event();
+    }
+
+    fn log_filters_summary(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        filters_info : &[&ManagedFilterInfo]
+    ) {
+        self.start_event(BinaryTraceEventKind::FiltersSummary);
+        write_varint(&mut self.buffer, filters_info.len() as u64);
+        for filter_info in filters_info {
+            write_string(&mut self.buffer, &filter_info.name);
+            write_varint(&mut self.buffer, filter_kind_to_code(&filter_info.kind));
+            write_string(&mut self.buffer, &filter_info.description);
+            write_varint(&mut self.buffer, filter_info.enabled as u64);
+            write_varint(&mut self.buffer, filter_info.defers as u64);
+            let statistics = &filter_info.statistics;
+            write_varint(&mut self.buffer, statistics.num_applications);
+            write_varint(&mut self.buffer, statistics.num_activations);
+            write_varint(&mut self.buffer, u64::try_from(statistics.total_duration.as_nanos()).unwrap_or(u64::MAX));
+            write_varint(&mut self.buffer, statistics.activation_depths.len() as u64);
+            for (depth,num_activations) in &statistics.activation_depths {
+                write_varint(&mut self.buffer, *depth as u64);
+                write_varint(&mut self.buffer, *num_activations);
+            }
+        }
+        self.write_event();
+    }
+
+    fn log_resume(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {
+        self.start_event(BinaryTraceEventKind::Resume);
+        self.write_event();
+    }
+
+    fn log_terminate_process(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {
+        self.start_event(BinaryTraceEventKind::Terminate);
+        self.write_event();
+        if self.io_error.is_none() {
+            self.io_error = self.sink.flush().err();
+        }
+    }
+
+}
@@ -0,0 +1,279 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::any::Any;
+use std::collections::HashSet;
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::filter::GenericFiltersManager;
+use crate::process::logger::AbstractProcessLogger;
+use crate::queue::priorities::GenericProcessPriorities;
+use crate::queue::strategy::QueueSearchStrategy;
+
+use super::graph::{ExploredGraph, ExploredGraphEdge, ExploredGraphEdgeKind, ExploredGraphFiltration, ExploredGraphNode};
+
+
+
+/** 
+ * Logger that builds the [ExploredGraph](ExploredGraph).
+ * 
+ * The domain-specific nodes are only cloned into the graph if "keep_nodes" is set.
+ * Steps and filtration results are always cloned.
+ * 
+ * An edge is typed as a discovery if its target is the node that has just been reached
+ * and as a memo hit upon the notification of the memo hit.
+ * Nodes that are notified as having no children are only marked as terminal if they have not been filtered
+ * (filtrations of steps and edges do not cut the exploration of the node from which they are fired).
+ * The graph is kept when the process resumes from its unexplored frontier so that it covers all the passes.
+ * 
+ * Loggers that render the explored graph (see [ExploredGraphRenderingLogger](crate::loggers::explored_graph::rendering::ExploredGraphRenderingLogger))
+ * embed a builder to which they forward the notifications.
+ * 
+ * Once the process has terminated, the builder can be retrieved from the loggers via the "get_logger_of_type" method of the
+ * [GenericProcessManager](crate::process::manager::GenericProcessManager).
+ * **/
+pub struct ExploredGraphBuilder<Conf : AbstractProcessConfiguration> {
+    pub keep_nodes : bool,
+    graph : ExploredGraph<Conf>,
+    /// node that has been reached last and which the next step leads to
+    last_new_node_id : Option<u32>,
+    /// nodes on which filtration results have been yielded
+    filtered_nodes_ids : HashSet<u32>,
+    /// nodes that have been deferred, and which are explored again if the process resumes
+    deferred_nodes_ids : HashSet<u32>
+}
+
+impl<Conf : AbstractProcessConfiguration> ExploredGraphBuilder<Conf> {
+
+    pub fn new(keep_nodes : bool) -> Self {
+        Self { 
+            keep_nodes, 
+            graph : ExploredGraph::default(), 
+            last_new_node_id : None, 
+            filtered_nodes_ids : HashSet::new(), 
+            deferred_nodes_ids : HashSet::new() 
+        }
+    }
+
+    pub fn get_graph(&self) -> &ExploredGraph<Conf> {
+        &self.graph
+    }
+
+    pub fn into_graph(self) -> ExploredGraph<Conf> {
+        self.graph
+    }
+
+    fn add_filtration(
+        &mut self,
+        node_id : u32,
+        step : Option<&Conf::DomainSpecificStep>,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) 
+        where 
+            Conf::DomainSpecificStep : Clone,
+            Conf::FiltrationResult : Clone
+    {
+        self.graph.filtrations.push(
+            ExploredGraphFiltration { 
+                filtration_result_id, 
+                node_id, 
+                step : step.cloned(), 
+                filtration_result : filtration_result.clone() 
+            }
+        );
+    }
+
+}
+
+impl<Conf> AbstractProcessLogger<Conf> for ExploredGraphBuilder<Conf> 
+    where 
+        Conf : 'static + AbstractProcessConfiguration,
+        Conf::DomainSpecificStep : Clone,
+        Conf::FiltrationResult : Clone
+{
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _strategy : &QueueSearchStrategy,
+        _priorities : &GenericProcessPriorities<Conf::Priorities>,
+        _filters_manager : &GenericFiltersManager<Conf>,
+        _initial_global_state : &Conf::MutablePersistentState,
+        _use_memoization : bool,
+    ) {
+        self.graph = ExploredGraph::default();
+        self.last_new_node_id = None;
+        self.filtered_nodes_ids.clear();
+        self.deferred_nodes_ids.clear();
+    }
+
+    fn log_new_node(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        new_node_id : u32,
+        new_node : &Conf::DomainSpecificNode
+    ) {
+        let discovery_order = self.graph.nodes.len();
+        self.graph.nodes.insert(
+            new_node_id, 
+            ExploredGraphNode { 
+                node_id : new_node_id, 
+                node : if self.keep_nodes { Some(new_node.clone()) } else { None }, 
+                depth : 0, 
+                discovery_order, 
+                discovery_parent_id : None, 
+                is_terminal : false 
+            }
+        );
+        self.last_new_node_id = Some(new_node_id);
+    }
+
+    fn log_new_step(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        origin_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        target_node_id : u32,
+        _target_node : &Conf::DomainSpecificNode
+    ) {
+        // a step that leads to an already known node is added upon the notification of the memo hit
+        if self.last_new_node_id != Some(target_node_id) {
+            return;
+        }
+        self.last_new_node_id = None;
+        let origin_depth = self.graph.nodes.get(&origin_node_id).map(|x| x.depth);
+        if let Some(target) = self.graph.nodes.get_mut(&target_node_id) {
+            target.discovery_parent_id = Some(origin_node_id);
+            target.depth = match origin_depth {
+                Some(depth) => depth + 1,
+                None => 0
+            };
+        }
+        self.graph.edges.push(
+            ExploredGraphEdge { 
+                origin_node_id, 
+                target_node_id, 
+                step : step.clone(), 
+                kind : ExploredGraphEdgeKind::Discovery 
+            }
+        );
+    }
+
+    fn log_memo_hit(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        origin_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        target_node_id : u32
+    ) {
+        self.graph.edges.push(
+            ExploredGraphEdge { 
+                origin_node_id, 
+                target_node_id, 
+                step : step.clone(), 
+                kind : ExploredGraphEdgeKind::MemoHit 
+            }
+        );
+    }
+
+    fn log_notify_last_child_step_of_node_processed(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _parent_node_id : u32
+    ) {}
+
+    fn log_notify_node_without_children(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        node_id : u32
+    ) {
+        // filtered nodes are notified as having no children because their exploration has been cut
+        if self.filtered_nodes_ids.contains(&node_id) {
+            return;
+        }
+        if let Some(node) = self.graph.nodes.get_mut(&node_id) {
+            node.is_terminal = true;
+        }
+    }
+
+    fn log_filtered(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        self.filtered_nodes_ids.insert(parent_node_id);
+        self.add_filtration(parent_node_id, None, filtration_result_id, filtration_result);
+    }
+
+    fn log_step_filtered(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        self.add_filtration(parent_node_id, Some(step), filtration_result_id, filtration_result);
+    }
+
+    fn log_edge_filtered(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        _child_node : &Conf::DomainSpecificNode,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        self.add_filtration(parent_node_id, Some(step), filtration_result_id, filtration_result);
+    }
+
+    fn log_deferred(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        node_id : u32,
+        step : Option<&Conf::DomainSpecificStep>
+    ) {
+        if step.is_none() {
+            self.deferred_nodes_ids.insert(node_id);
+        }
+    }
+
+    fn log_resume(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {
+        // the exploration of the deferred nodes is no longer cut
+        for node_id in self.deferred_nodes_ids.drain() {
+            self.filtered_nodes_ids.remove(&node_id);
+        }
+    }
+
+    fn log_terminate_process(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {}
+
+}
@@ -0,0 +1,165 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::process::config::AbstractProcessConfiguration;
+
+
+
+/** 
+ * A node of the [ExploredGraph](ExploredGraph).
+ * 
+ * "discovery_order" is the rank at which the node has been reached (starting from 0)
+ * and "discovery_parent_id" is the node from which it was first reached (None for the initial node).
+ * "depth" is the depth in this discovery tree.
+ * "is_terminal" is set on the nodes that have no children without having been filtered.
+ * The domain-specific node is only kept if the graph is built with that option.
+ * **/
+pub struct ExploredGraphNode<Conf : AbstractProcessConfiguration> {
+    pub node_id : u32,
+    pub node : Option<Conf::DomainSpecificNode>,
+    pub depth : u32,
+    pub discovery_order : usize,
+    pub discovery_parent_id : Option<u32>,
+    pub is_terminal : bool
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ExploredGraphEdgeKind {
+    /// the step led to a newly reached node
+    Discovery,
+    /// the step led to an already known (memoized) node
+    MemoHit
+}
+
+
+
+/** 
+ * An edge of the [ExploredGraph](ExploredGraph), carrying the step that has been fired.
+ * **/
+pub struct ExploredGraphEdge<Conf : AbstractProcessConfiguration> {
+    pub origin_node_id : u32,
+    pub target_node_id : u32,
+    pub step : Conf::DomainSpecificStep,
+    pub kind : ExploredGraphEdgeKind
+}
+
+
+
+/** 
+ * A filtration result yielded on a node of the [ExploredGraph](ExploredGraph)
+ * or on a step fired from that node (possibly once the child node has been computed, by an edge filter).
+ * "step" is None if the node itself has been filtered.
+ * **/
+pub struct ExploredGraphFiltration<Conf : AbstractProcessConfiguration> {
+    pub filtration_result_id : u32,
+    pub node_id : u32,
+    pub step : Option<Conf::DomainSpecificStep>,
+    pub filtration_result : Conf::FiltrationResult
+}
+
+
+
+/** 
+ * The part of the graph that has been explored by the process, as a standard structure on which
+ * analyses (reachability, counting, export etc.) can operate after the process has terminated.
+ * 
+ * It is built by the [ExploredGraphBuilder](crate::loggers::explored_graph::builder::ExploredGraphBuilder) logger.
+ * **/
+pub struct ExploredGraph<Conf : AbstractProcessConfiguration> {
+    pub nodes : BTreeMap<u32,ExploredGraphNode<Conf>>,
+    pub edges : Vec<ExploredGraphEdge<Conf>>,
+    pub filtrations : Vec<ExploredGraphFiltration<Conf>>
+}
+
+impl<Conf : AbstractProcessConfiguration> Default for ExploredGraph<Conf> {
+    fn default() -> Self {
+        Self { 
+            nodes : BTreeMap::new(), 
+            edges : vec![], 
+            filtrations : vec![] 
+        }
+    }
+}
+
+impl<Conf : AbstractProcessConfiguration> ExploredGraph<Conf> {
+
+    pub fn get_node(&self, node_id : u32) -> Option<&ExploredGraphNode<Conf>> {
+        self.nodes.get(&node_id)
+    }
+
+    /** 
+     * Returns the node from which the exploration started.
+     * **/
+    pub fn get_initial_node(&self) -> Option<&ExploredGraphNode<Conf>> {
+        self.nodes.values().find(|x| x.discovery_order == 0)
+    }
+
+    pub fn get_outgoing_edges(&self, node_id : u32) -> impl Iterator<Item = &ExploredGraphEdge<Conf>> {
+        self.edges.iter().filter(move |x| x.origin_node_id == node_id)
+    }
+
+    pub fn get_incoming_edges(&self, node_id : u32) -> impl Iterator<Item = &ExploredGraphEdge<Conf>> {
+        self.edges.iter().filter(move |x| x.target_node_id == node_id)
+    }
+
+    pub fn get_filtrations_of_node(&self, node_id : u32) -> impl Iterator<Item = &ExploredGraphFiltration<Conf>> {
+        self.filtrations.iter().filter(move |x| x.node_id == node_id)
+    }
+
+    pub fn get_terminal_nodes(&self) -> impl Iterator<Item = &ExploredGraphNode<Conf>> {
+        self.nodes.values().filter(|x| x.is_terminal)
+    }
+
+    /** 
+     * Returns the identifiers of all the nodes that are reachable from the given node (including itself).
+     * **/
+    pub fn get_reachable_nodes(&self, node_id : u32) -> BTreeSet<u32> {
+        let mut successors : BTreeMap<u32,Vec<u32>> = BTreeMap::new();
+        for edge in &self.edges {
+            successors.entry(edge.origin_node_id).or_default().push(edge.target_node_id);
+        }
+        let mut reached = BTreeSet::new();
+        let mut to_visit = vec![node_id];
+        while let Some(current) = to_visit.pop() {
+            if reached.insert(current) {
+                if let Some(targets) = successors.get(&current) {
+                    to_visit.extend(targets.iter().filter(|x| !reached.contains(x)));
+                }
+            }
+        }
+        reached
+    }
+
+    /** 
+     * Returns the identifiers of the nodes on the path of the discovery tree 
+     * from the initial node to the given node (both included).
+     * **/
+    pub fn get_discovery_path(&self, node_id : u32) -> Vec<u32> {
+        let mut path = vec![];
+        let mut current = self.nodes.get(&node_id);
+        while let Some(node) = current {
+            path.push(node.node_id);
+            current = node.discovery_parent_id.and_then(|x| self.nodes.get(&x));
+        }
+        path.reverse();
+        path
+    }
+
+}
@@ -0,0 +1,307 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::any::Any;
+use std::io;
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::filter::GenericFiltersManager;
+use crate::process::logger::AbstractProcessLogger;
+use crate::queue::priorities::GenericProcessPriorities;
+use crate::queue::strategy::QueueSearchStrategy;
+
+use super::builder::ExploredGraphBuilder;
+use super::graph::ExploredGraph;
+
+
+
+/** 
+ * What has just changed in the [ExploredGraph](ExploredGraph) being built.
+ * **/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ExploredGraphUpdate {
+    /// the node with that identifier has been added
+    Node(u32),
+    /// an edge has been added, at that index among the edges of the graph
+    Edge(usize),
+    /// a filtration has been added, at that index among the filtrations of the graph
+    Filtration(usize),
+    /// all the children of the node with that identifier have been processed (or it has none)
+    ChildrenProcessed(u32)
+}
+
+
+
+/** 
+ * Renders the [ExploredGraph](ExploredGraph) that is built by an [ExploredGraphRenderingLogger](ExploredGraphRenderingLogger).
+ * 
+ * The graph is rendered each time the process terminates
+ * and a renderer may also render it incrementally as it is updated.
+ * **/
+pub trait AbstractExploredGraphRenderer<Conf : AbstractProcessConfiguration> {
+
+    /** 
+     * Notifies the renderer that the process starts, with the strategy according to which it is explored.
+     * **/
+    fn on_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _strategy : &QueueSearchStrategy
+    ) {}
+
+    /** 
+     * Notifies the renderer that the graph has been updated.
+     * By default, nothing is rendered incrementally.
+     * **/
+    fn on_graph_updated(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _graph : &ExploredGraph<Conf>,
+        _update : ExploredGraphUpdate
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    /** 
+     * Renders the graph once the process has terminated.
+     * **/
+    fn render(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        graph : &ExploredGraph<Conf>
+    ) -> io::Result<()>;
+
+}
+
+
+
+/** 
+ * Logger that builds the explored graph (see [ExploredGraphBuilder](ExploredGraphBuilder)) 
+ * and has it rendered by the "renderer".
+ * 
+ * The graph is kept when the process resumes from its unexplored frontier
+ * so that it is rendered again, covering all the passes, each time the process terminates.
+ * Should rendering fail, the logger stops rendering until the process starts again 
+ * and the error can be retrieved via "get_io_error".
+ * **/
+pub struct ExploredGraphRenderingLogger<Conf : AbstractProcessConfiguration,Renderer> {
+    pub renderer : Renderer,
+    graph_builder : ExploredGraphBuilder<Conf>,
+    io_error : Option<io::Error>
+}
+
+impl<Conf : AbstractProcessConfiguration,Renderer> ExploredGraphRenderingLogger<Conf,Renderer> {
+
+    pub fn new(renderer : Renderer) -> Self {
+        Self { 
+            renderer, 
+            graph_builder : ExploredGraphBuilder::new(true), 
+            io_error : None 
+        }
+    }
+
+    pub fn get_graph(&self) -> &ExploredGraph<Conf> {
+        self.graph_builder.get_graph()
+    }
+
+    /** 
+     * Returns the error that occurred when rendering the graph, if any.
+     * **/
+    pub fn get_io_error(&self) -> Option<&io::Error> {
+        self.io_error.as_ref()
+    }
+
+}
+
+impl<Conf : AbstractProcessConfiguration,Renderer : AbstractExploredGraphRenderer<Conf>> ExploredGraphRenderingLogger<Conf,Renderer> {
+
+    fn notify_renderer(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        update : ExploredGraphUpdate
+    ) {
+        if self.io_error.is_none() {
+            self.io_error = self.renderer.on_graph_updated(context_and_param, self.graph_builder.get_graph(), update).err();
+        }
+    }
+
+    fn notify_renderer_of_new_edge(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        previous_num_edges : usize
+    ) {
+        if self.graph_builder.get_graph().edges.len() > previous_num_edges {
+            self.notify_renderer(context_and_param, ExploredGraphUpdate::Edge(previous_num_edges));
+        }
+    }
+
+    fn notify_renderer_of_new_filtration(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        previous_num_filtrations : usize
+    ) {
+        if self.graph_builder.get_graph().filtrations.len() > previous_num_filtrations {
+            self.notify_renderer(context_and_param, ExploredGraphUpdate::Filtration(previous_num_filtrations));
+        }
+    }
+
+}
+
+impl<Conf, Renderer> AbstractProcessLogger<Conf> for ExploredGraphRenderingLogger<Conf,Renderer> 
+    where 
+        Conf : 'static + AbstractProcessConfiguration,
+        Conf::DomainSpecificStep : Clone,
+        Conf::FiltrationResult : Clone,
+        Renderer : 'static + AbstractExploredGraphRenderer<Conf>
+{
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        strategy : &QueueSearchStrategy,
+        priorities : &GenericProcessPriorities<Conf::Priorities>,
+        filters_manager : &GenericFiltersManager<Conf>,
+        initial_global_state : &Conf::MutablePersistentState,
+        use_memoization : bool,
+    ) {
+        self.graph_builder.log_initialize(context_and_param, strategy, priorities, filters_manager, initial_global_state, use_memoization);
+        self.io_error = None;
+        self.renderer.on_initialize(context_and_param, strategy);
+    }
+
+    fn log_new_node(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        new_node_id : u32,
+        new_node : &Conf::DomainSpecificNode
+    ) {
+        self.graph_builder.log_new_node(context_and_param, new_node_id, new_node);
+        self.notify_renderer(context_and_param, ExploredGraphUpdate::Node(new_node_id));
+    }
+
+    fn log_new_step(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        origin_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        target_node_id : u32,
+        target_node : &Conf::DomainSpecificNode
+    ) {
+        let previous_num_edges = self.graph_builder.get_graph().edges.len();
+        self.graph_builder.log_new_step(context_and_param, origin_node_id, step, target_node_id, target_node);
+        self.notify_renderer_of_new_edge(context_and_param, previous_num_edges);
+    }
+
+    fn log_memo_hit(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        origin_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        target_node_id : u32
+    ) {
+        let previous_num_edges = self.graph_builder.get_graph().edges.len();
+        self.graph_builder.log_memo_hit(context_and_param, origin_node_id, step, target_node_id);
+        self.notify_renderer_of_new_edge(context_and_param, previous_num_edges);
+    }
+
+    fn log_notify_last_child_step_of_node_processed(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32
+    ) {
+        self.notify_renderer(context_and_param, ExploredGraphUpdate::ChildrenProcessed(parent_node_id));
+    }
+
+    fn log_notify_node_without_children(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        node_id : u32
+    ) {
+        self.graph_builder.log_notify_node_without_children(context_and_param, node_id);
+        self.notify_renderer(context_and_param, ExploredGraphUpdate::ChildrenProcessed(node_id));
+    }
+
+    fn log_filtered(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        let previous_num_filtrations = self.graph_builder.get_graph().filtrations.len();
+        self.graph_builder.log_filtered(context_and_param, parent_node_id, filtration_result_id, filtration_result);
+        self.notify_renderer_of_new_filtration(context_and_param, previous_num_filtrations);
+    }
+
+    fn log_step_filtered(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        let previous_num_filtrations = self.graph_builder.get_graph().filtrations.len();
+        self.graph_builder.log_step_filtered(context_and_param, parent_node_id, step, filtration_result_id, filtration_result);
+        self.notify_renderer_of_new_filtration(context_and_param, previous_num_filtrations);
+    }
+
+    fn log_edge_filtered(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        child_node : &Conf::DomainSpecificNode,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        let previous_num_filtrations = self.graph_builder.get_graph().filtrations.len();
+        self.graph_builder.log_edge_filtered(context_and_param, parent_node_id, step, child_node, filtration_result_id, filtration_result);
+        self.notify_renderer_of_new_filtration(context_and_param, previous_num_filtrations);
+    }
+
+    fn log_deferred(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        node_id : u32,
+        step : Option<&Conf::DomainSpecificStep>
+    ) {
+        self.graph_builder.log_deferred(context_and_param, node_id, step);
+    }
+
+    fn log_resume(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState
+    ) {
+        self.graph_builder.log_resume(context_and_param, global_state);
+    }
+
+    fn log_terminate_process(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {
+        if self.io_error.is_none() {
+            self.io_error = self.renderer.render(context_and_param, self.graph_builder.get_graph()).err();
+        }
+    }
+
+}
@@ -0,0 +1,82 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use crate::loggers::explored_graph::graph::{ExploredGraph, ExploredGraphEdgeKind};
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::rendering::AbstractProcessLabeller;
+
+use super::format::{ExportedEdge, ExportedNode, GraphExportFormat};
+
+
+
+/** 
+ * Writes an [ExploredGraph](ExploredGraph) into a sink in the given format.
+ * 
+ * Node labels are given by the "labeller" if the domain-specific nodes have been kept in the graph
+ * and are otherwise the node identifiers.
+ * Edges are labelled with their steps and filtration results are listed on the nodes they have been yielded on.
+ * **/
+pub fn export_explored_graph<Conf, Labeller, Sink>(
+    graph : &ExploredGraph<Conf>,
+    context_and_param : &Conf::ContextAndParameterization,
+    labeller : &Labeller,
+    format : GraphExportFormat,
+    sink : &mut Sink
+) -> io::Result<()>
+    where 
+        Conf : AbstractProcessConfiguration,
+        Labeller : AbstractProcessLabeller<Conf>,
+        Sink : Write
+{
+    let mut filtrations : BTreeMap<u32,Vec<String>> = BTreeMap::new();
+    for filtration in &graph.filtrations {
+        let label = labeller.get_filtration_result_label(context_and_param, &filtration.filtration_result);
+        filtrations.entry(filtration.node_id).or_default().push(label);
+    }
+    // ***
+    format.write_header(sink)?;
+    for graph_node in graph.nodes.values() {
+        let label = match &graph_node.node {
+            Some(node) => labeller.get_node_label(context_and_param, node),
+            None => graph_node.node_id.to_string()
+        };
+        let exported = ExportedNode {
+            node_id : graph_node.node_id,
+            label,
+            depth : graph_node.depth,
+            discovery_order : graph_node.discovery_order,
+            is_terminal : graph_node.is_terminal,
+            filtrations : filtrations.remove(&graph_node.node_id).unwrap_or_default()
+        };
+        format.write_node(sink, &exported)?;
+    }
+    format.write_end_of_nodes(sink)?;
+    for (edge_id,graph_edge) in graph.edges.iter().enumerate() {
+        let exported = ExportedEdge {
+            edge_id,
+            origin_node_id : graph_edge.origin_node_id,
+            target_node_id : graph_edge.target_node_id,
+            step_label : labeller.get_step_label(context_and_param, &graph_edge.step),
+            is_memo_hit : graph_edge.kind == ExploredGraphEdgeKind::MemoHit
+        };
+        format.write_edge(sink, &exported)?;
+    }
+    format.write_footer(sink)?;
+    sink.flush()
+}
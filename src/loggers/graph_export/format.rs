@@ -0,0 +1,193 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::io::{self, Write};
+
+
+
+/** 
+ * The formats into which explored graphs can be exported so as to be opened in graph viewers such as Gephi or yEd.
+ * **/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GraphExportFormat {
+    GraphMl,
+    Gexf
+}
+
+
+
+/** 
+ * A node, as exported, with its typed attributes.
+ * "filtrations" contains the labels of the filtration results yielded on the node.
+ * **/
+pub struct ExportedNode {
+    pub node_id : u32,
+    pub label : String,
+    pub depth : u32,
+    pub discovery_order : usize,
+    pub is_terminal : bool,
+    pub filtrations : Vec<String>
+}
+
+
+
+/** 
+ * An edge, as exported, with its typed attributes.
+ * **/
+pub struct ExportedEdge {
+    pub edge_id : usize,
+    pub origin_node_id : u32,
+    pub target_node_id : u32,
+    pub step_label : String,
+    pub is_memo_hit : bool
+}
+
+
+
+/** 
+ * Escapes a string so that it can be used as XML text or as an XML attribute value.
+ * **/
+pub fn escape_xml(raw : &str) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c)
+        }
+    }
+    escaped
+}
+
+
+
+/** 
+ * Writes the parts of a graph document.
+ * A document consists of a header, of the nodes, of the edges and of a footer.
+ * In GraphML, nodes and edges may be interleaved, while in GEXF, all the nodes must be written before the edges
+ * (which is marked by "write_end_of_nodes").
+ * **/
+impl GraphExportFormat {
+
+    /** 
+     * Returns true if nodes and edges can be written in any order.
+     * **/
+    pub fn allows_interleaving(&self) -> bool {
+        match self {
+            GraphExportFormat::GraphMl => true,
+            GraphExportFormat::Gexf => false
+        }
+    }
+
+    pub fn write_header(&self, writer : &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        match self {
+            GraphExportFormat::GraphMl => {
+                writeln!(writer, "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">")?;
+                writeln!(writer, "<key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>")?;
+                writeln!(writer, "<key id=\"depth\" for=\"node\" attr.name=\"depth\" attr.type=\"int\"/>")?;
+                writeln!(writer, "<key id=\"discovery_order\" for=\"node\" attr.name=\"discovery_order\" attr.type=\"long\"/>")?;
+                writeln!(writer, "<key id=\"terminal\" for=\"node\" attr.name=\"terminal\" attr.type=\"boolean\"/>")?;
+                writeln!(writer, "<key id=\"filtered\" for=\"node\" attr.name=\"filtered\" attr.type=\"boolean\"/>")?;
+                writeln!(writer, "<key id=\"filtrations\" for=\"node\" attr.name=\"filtrations\" attr.type=\"string\"/>")?;
+                writeln!(writer, "<key id=\"step\" for=\"edge\" attr.name=\"step\" attr.type=\"string\"/>")?;
+                writeln!(writer, "<key id=\"memo_hit\" for=\"edge\" attr.name=\"memo_hit\" attr.type=\"boolean\"/>")?;
+                writeln!(writer, "<graph id=\"process_graph\" edgedefault=\"directed\">")
+            },
+            GraphExportFormat::Gexf => {
+                writeln!(writer, "<gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">")?;
+                writeln!(writer, "<graph defaultedgetype=\"directed\">")?;
+                writeln!(writer, "<attributes class=\"node\">")?;
+                writeln!(writer, "<attribute id=\"depth\" title=\"depth\" type=\"integer\"/>")?;
+                writeln!(writer, "<attribute id=\"discovery_order\" title=\"discovery_order\" type=\"long\"/>")?;
+                writeln!(writer, "<attribute id=\"terminal\" title=\"terminal\" type=\"boolean\"/>")?;
+                writeln!(writer, "<attribute id=\"filtered\" title=\"filtered\" type=\"boolean\"/>")?;
+                writeln!(writer, "<attribute id=\"filtrations\" title=\"filtrations\" type=\"string\"/>")?;
+                writeln!(writer, "</attributes>")?;
+                writeln!(writer, "<attributes class=\"edge\">")?;
+                writeln!(writer, "<attribute id=\"memo_hit\" title=\"memo_hit\" type=\"boolean\"/>")?;
+                writeln!(writer, "</attributes>")?;
+                writeln!(writer, "<nodes>")
+            }
+        }
+    }
+
+    pub fn write_node(&self, writer : &mut dyn Write, node : &ExportedNode) -> io::Result<()> {
+        let label = escape_xml(&node.label);
+        let is_filtered = !node.filtrations.is_empty();
+        let filtrations = escape_xml(&node.filtrations.join("; "));
+        match self {
+            GraphExportFormat::GraphMl => {
+                writeln!(writer,
+                    "<node id=\"n{}\"><data key=\"label\">{}</data><data key=\"depth\">{}</data><data key=\"discovery_order\">{}</data><data key=\"terminal\">{}</data><data key=\"filtered\">{}</data><data key=\"filtrations\">{}</data></node>",
+                    node.node_id, label, node.depth, node.discovery_order, node.is_terminal, is_filtered, filtrations
+                )
+            },
+            GraphExportFormat::Gexf => {
+                writeln!(writer,
+                    "<node id=\"n{}\" label=\"{}\"><attvalues><attvalue for=\"depth\" value=\"{}\"/><attvalue for=\"discovery_order\" value=\"{}\"/><attvalue for=\"terminal\" value=\"{}\"/><attvalue for=\"filtered\" value=\"{}\"/><attvalue for=\"filtrations\" value=\"{}\"/></attvalues></node>",
+                    node.node_id, label, node.depth, node.discovery_order, node.is_terminal, is_filtered, filtrations
+                )
+            }
+        }
+    }
+
+    pub fn write_end_of_nodes(&self, writer : &mut dyn Write) -> io::Result<()> {
+        match self {
+            GraphExportFormat::GraphMl => Ok(()),
+            GraphExportFormat::Gexf => {
+                writeln!(writer, "</nodes>")?;
+                writeln!(writer, "<edges>")
+            }
+        }
+    }
+
+    pub fn write_edge(&self, writer : &mut dyn Write, edge : &ExportedEdge) -> io::Result<()> {
+        let step_label = escape_xml(&edge.step_label);
+        match self {
+            GraphExportFormat::GraphMl => {
+                writeln!(writer,
+                    "<edge id=\"e{}\" source=\"n{}\" target=\"n{}\"><data key=\"step\">{}</data><data key=\"memo_hit\">{}</data></edge>",
+                    edge.edge_id, edge.origin_node_id, edge.target_node_id, step_label, edge.is_memo_hit
+                )
+            },
+            GraphExportFormat::Gexf => {
+                writeln!(writer,
+                    "<edge id=\"e{}\" source=\"n{}\" target=\"n{}\" label=\"{}\"><attvalues><attvalue for=\"memo_hit\" value=\"{}\"/></attvalues></edge>",
+                    edge.edge_id, edge.origin_node_id, edge.target_node_id, step_label, edge.is_memo_hit
+                )
+            }
+        }
+    }
+
+    pub fn write_footer(&self, writer : &mut dyn Write) -> io::Result<()> {
+        match self {
+            GraphExportFormat::GraphMl => {
+                writeln!(writer, "</graph>")?;
+                writeln!(writer, "</graphml>")
+            },
+            GraphExportFormat::Gexf => {
+                writeln!(writer, "</edges>")?;
+                writeln!(writer, "</graph>")?;
+                writeln!(writer, "</gexf>")
+            }
+        }
+    }
+
+}
@@ -0,0 +1,274 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Write};
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::filter::GenericFiltersManager;
+use crate::process::logger::AbstractProcessLogger;
+use crate::process::rendering::AbstractProcessLabeller;
+use crate::queue::priorities::GenericProcessPriorities;
+use crate::queue::strategy::QueueSearchStrategy;
+
+use super::format::{ExportedEdge, ExportedNode, GraphExportFormat};
+
+
+
+/** 
+ * Logger that streams the explored graph into a sink in the GraphML or GEXF format while the process runs.
+ * 
+ * A node is only written once its attributes are settled i.e., once all the steps from it have been processed
+ * or once it is known to have no children.
+ * Hence, only the nodes from which steps remain to be processed are kept in memory.
+ * In GraphML, edges are written as soon as they are notified.
+ * In GEXF, all the nodes must precede all the edges, so edges are kept in memory and written when the process terminates.
+ * 
+ * The document is completed when the process terminates.
+ * Because a streamed document cannot be reopened, the passes that follow a resumption of the process
+ * from its unexplored frontier (see "log_resume") are not exported.
+ * To export all the passes, build the explored graph instead (see [ExploredGraphBuilder](crate::loggers::explored_graph::builder::ExploredGraphBuilder))
+ * and export it once the last pass has terminated.
+ * 
+ * Should writing into the sink fail, the logger stops writing and the error can be retrieved via "get_io_error".
+ * To export a graph that has already been collected, see 
+ * [export_explored_graph](crate::loggers::graph_export::exporter::export_explored_graph).
+ * **/
+pub struct StreamingGraphExportLogger<Sink,Labeller> {
+    pub sink : Sink,
+    pub labeller : Labeller,
+    pub format : GraphExportFormat,
+    /// nodes that have been reached but that are not yet settled
+    pending_nodes : HashMap<u32,ExportedNode>,
+    /// the last notified edge, which may still be marked as a memo hit
+    pending_edge : Option<ExportedEdge>,
+    /// edges waiting for the end of the nodes (only for formats that do not allow interleaving)
+    buffered_edges : Vec<ExportedEdge>,
+    num_discovered_nodes : usize,
+    num_edges : usize,
+    last_new_node_id : Option<u32>,
+    is_closed : bool,
+    io_error : Option<io::Error>
+}
+
+impl<Sink : Write, Labeller> StreamingGraphExportLogger<Sink,Labeller> {
+
+    pub fn new(sink : Sink, labeller : Labeller, format : GraphExportFormat) -> Self {
+        Self { 
+            sink, 
+            labeller, 
+            format, 
+            pending_nodes : HashMap::new(), 
+            pending_edge : None, 
+            buffered_edges : vec![], 
+            num_discovered_nodes : 0, 
+            num_edges : 0, 
+            last_new_node_id : None, 
+            is_closed : false, 
+            io_error : None 
+        }
+    }
+
+    /** 
+     * Returns the error that occurred when writing into the sink, if any.
+     * **/
+    pub fn get_io_error(&self) -> Option<&io::Error> {
+        self.io_error.as_ref()
+    }
+
+    fn write_with(&mut self, write_fn : impl FnOnce(&GraphExportFormat, &mut Sink) -> io::Result<()>) {
+        if self.io_error.is_some() || self.is_closed {
+            return;
+        }
+        if let Err(e) = write_fn(&self.format, &mut self.sink) {
+            self.io_error = Some(e);
+        }
+    }
+
+    fn flush_pending_edge(&mut self) {
+        if let Some(edge) = self.pending_edge.take() {
+            if self.format.allows_interleaving() {
+                self.write_with(|format, sink| format.write_edge(sink, &edge));
+            } else if !self.is_closed {
+                self.buffered_edges.push(edge);
+            }
+        }
+    }
+
+    fn settle_node(&mut self, node_id : u32) {
+        if let Some(node) = self.pending_nodes.remove(&node_id) {
+            self.write_with(|format, sink| format.write_node(sink, &node));
+        }
+    }
+
+}
+
+impl<Conf, Sink, Labeller> AbstractProcessLogger<Conf> for StreamingGraphExportLogger<Sink,Labeller> 
+    where 
+        Conf : AbstractProcessConfiguration,
+        Sink : 'static + Write,
+        Labeller : 'static + AbstractProcessLabeller<Conf>
+{
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _strategy : &QueueSearchStrategy,
+        _priorities : &GenericProcessPriorities<Conf::Priorities>,
+        _filters_manager : &GenericFiltersManager<Conf>,
+        _initial_global_state : &Conf::MutablePersistentState,
+        _use_memoization : bool,
+    ) {
+        self.write_with(|format, sink| format.write_header(sink));
+    }
+
+    fn log_new_node(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        new_node_id : u32,
+        new_node : &Conf::DomainSpecificNode
+    ) {
+        self.flush_pending_edge();
+        if self.is_closed {
+            return;
+        }
+        let node = ExportedNode {
+            node_id : new_node_id,
+            label : self.labeller.get_node_label(context_and_param, new_node),
+            // the depth of the new node is known once the step that leads to it is notified (if it is not the initial node)
+            depth : 0,
+            discovery_order : self.num_discovered_nodes,
+            is_terminal : false,
+            filtrations : vec![]
+        };
+        self.num_discovered_nodes += 1;
+        self.pending_nodes.insert(new_node_id, node);
+        self.last_new_node_id = Some(new_node_id);
+    }
+
+    fn log_new_step(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        origin_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        target_node_id : u32,
+        _target_node : &Conf::DomainSpecificNode
+    ) {
+        self.flush_pending_edge();
+        if self.is_closed {
+            return;
+        }
+        if self.last_new_node_id == Some(target_node_id) {
+            let origin_depth = self.pending_nodes.get(&origin_node_id).map(|x| x.depth);
+            if let (Some(origin_depth),Some(target)) = (origin_depth,self.pending_nodes.get_mut(&target_node_id)) {
+                target.depth = origin_depth + 1;
+            }
+            self.last_new_node_id = None;
+        }
+        self.pending_edge = Some(
+            ExportedEdge {
+                edge_id : self.num_edges,
+                origin_node_id,
+                target_node_id,
+                step_label : self.labeller.get_step_label(context_and_param, step),
+                is_memo_hit : false
+            }
+        );
+        self.num_edges += 1;
+    }
+
+    fn log_memo_hit(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        origin_node_id : u32,
+        _step : &Conf::DomainSpecificStep,
+        target_node_id : u32
+    ) {
+        if let Some(edge) = self.pending_edge.as_mut() {
+            if edge.origin_node_id == origin_node_id && edge.target_node_id == target_node_id {
+                edge.is_memo_hit = true;
+            }
+        }
+    }
+
+    fn log_notify_last_child_step_of_node_processed(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32
+    ) {
+        self.flush_pending_edge();
+        self.settle_node(parent_node_id);
+    }
+
+    fn log_notify_node_without_children(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        node_id : u32
+    ) {
+        self.flush_pending_edge();
+        // filtered nodes are notified as having no children because their exploration has been cut
+        if let Some(node) = self.pending_nodes.get_mut(&node_id) {
+            node.is_terminal = node.filtrations.is_empty();
+        }
+        self.settle_node(node_id);
+    }
+
+    fn log_filtered(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        _filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        self.flush_pending_edge();
+        if let Some(node) = self.pending_nodes.get_mut(&parent_node_id) {
+            let label = self.labeller.get_filtration_result_label(context_and_param, filtration_result);
+            node.filtrations.push(label);
+        }
+    }
+
+    fn log_terminate_process(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {
+        self.flush_pending_edge();
+        if self.is_closed {
+            return;
+        }
+        // nodes that are not settled (e.g., if the process was stopped early) are written with their current attributes
+        let remaining : BTreeMap<u32,ExportedNode> = self.pending_nodes.drain().collect();
+        for node in remaining.values() {
+            self.write_with(|format, sink| format.write_node(sink, node));
+        }
+        self.write_with(|format, sink| format.write_end_of_nodes(sink));
+        let buffered_edges = std::mem::take(&mut self.buffered_edges);
+        for edge in &buffered_edges {
+            self.write_with(|format, sink| format.write_edge(sink, edge));
+        }
+        self.write_with(|format, sink| format.write_footer(sink));
+        if self.io_error.is_none() {
+            self.io_error = self.sink.flush().err();
+        }
+        self.is_closed = true;
+    }
+
+}
@@ -0,0 +1,141 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::loggers::explored_graph::graph::ExploredGraph;
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::rendering::AbstractProcessLabeller;
+
+use super::style::{escape_dot_string, AbstractGraphvizStyler, GraphvizStyle};
+
+
+
+struct GraphvizElement {
+    label : String,
+    style : GraphvizStyle,
+    tooltip : Option<String>,
+    style_class : Option<String>,
+    image : Option<PathBuf>
+}
+
+impl GraphvizElement {
+
+    fn new(label : String, style : GraphvizStyle, tooltip : Option<String>, style_class : Option<String>) -> Self {
+        Self { 
+            label, 
+            style, 
+            tooltip, 
+            style_class, 
+            image : None 
+        }
+    }
+
+    fn to_dot_attributes(&self) -> String {
+        let mut attributes = vec![format!("label=\"{}\"", escape_dot_string(&self.label))];
+        attributes.extend(self.style.to_dot_attributes());
+        if let Some(tooltip) = &self.tooltip {
+            attributes.push(format!("tooltip=\"{}\"", escape_dot_string(tooltip)));
+        }
+        if let Some(style_class) = &self.style_class {
+            attributes.push(format!("class=\"{}\"", escape_dot_string(style_class)));
+        }
+        if let Some(image) = &self.image {
+            attributes.push(format!("image=\"{}\"", escape_dot_string(&image.to_string_lossy())));
+            attributes.push("labelloc=\"b\"".to_string());
+        }
+        attributes.join(", ")
+    }
+
+}
+
+
+
+/** 
+ * Draws an [ExploredGraph](ExploredGraph) as a GraphViz DOT file at "output_file_path".
+ * 
+ * Nodes and steps are labelled by the "labeller" and styled by the "styler".
+ * Tooltips and style classes given by the "labeller" are set as the "tooltip" and "class" attributes of the elements
+ * (which are used in SVG outputs) and node images it may export (next to the DOT file) are set as their "image" attribute.
+ * Nodes whose domain-specific nodes have not been kept in the graph are labelled with their identifiers.
+ * Each filtration result is drawn as a distinct element linked to the node that has been filtered
+ * (or from which the filtered step would have been fired).
+ * Terminal nodes (i.e., nodes without children that have not been filtered) are styled distinctly.
+ * **/
+pub fn draw_explored_graph<Conf, Labeller, Styler>(
+    graph : &ExploredGraph<Conf>,
+    context_and_param : &Conf::ContextAndParameterization,
+    labeller : &Labeller,
+    styler : &Styler,
+    output_file_path : &Path
+) -> io::Result<()>
+    where 
+        Conf : AbstractProcessConfiguration,
+        Labeller : AbstractProcessLabeller<Conf>,
+        Styler : AbstractGraphvizStyler<Conf>
+{
+    let output_directory = output_file_path.parent().unwrap_or(Path::new(""));
+    let terminal_node_style = styler.get_terminal_node_style(context_and_param);
+    let mut writer = BufWriter::new(File::create(output_file_path)?);
+    writeln!(writer, "digraph process_graph {{")?;
+    for graph_node in graph.nodes.values() {
+        let mut element = match &graph_node.node {
+            Some(node) => {
+                let mut element = GraphvizElement::new(
+                    labeller.get_node_label(context_and_param, node),
+                    styler.get_node_style(context_and_param, node),
+                    labeller.get_node_tooltip(context_and_param, node),
+                    labeller.get_node_style_class(context_and_param, node)
+                );
+                element.image = labeller.export_node_image(context_and_param, node, graph_node.node_id, output_directory);
+                element
+            },
+            None => {
+                GraphvizElement::new(graph_node.node_id.to_string(), GraphvizStyle::default(), None, None)
+            }
+        };
+        if graph_node.is_terminal {
+            element.style = element.style.merged_with(&terminal_node_style);
+        }
+        writeln!(writer, "n{} [{}];", graph_node.node_id, element.to_dot_attributes())?;
+    }
+    for filtration in &graph.filtrations {
+        let element = GraphvizElement::new(
+            labeller.get_filtration_result_label(context_and_param, &filtration.filtration_result),
+            styler.get_filtration_result_style(context_and_param, &filtration.filtration_result),
+            labeller.get_filtration_result_tooltip(context_and_param, &filtration.filtration_result),
+            labeller.get_filtration_result_style_class(context_and_param, &filtration.filtration_result)
+        );
+        writeln!(writer, "f{} [{}];", filtration.filtration_result_id, element.to_dot_attributes())?;
+    }
+    for edge in &graph.edges {
+        let element = GraphvizElement::new(
+            labeller.get_step_label(context_and_param, &edge.step),
+            styler.get_step_style(context_and_param, &edge.step),
+            labeller.get_step_tooltip(context_and_param, &edge.step),
+            labeller.get_step_style_class(context_and_param, &edge.step)
+        );
+        writeln!(writer, "n{} -> n{} [{}];", edge.origin_node_id, edge.target_node_id, element.to_dot_attributes())?;
+    }
+    let filtered_edge_attributes = styler.get_filtered_edge_style(context_and_param).to_dot_attributes().join(", ");
+    for filtration in &graph.filtrations {
+        writeln!(writer, "n{} -> f{} [{}];", filtration.node_id, filtration.filtration_result_id, filtered_edge_attributes)?;
+    }
+    writeln!(writer, "}}")?;
+    writer.flush()
+}
@@ -0,0 +1,87 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::io;
+use std::path::PathBuf;
+
+use crate::loggers::explored_graph::graph::ExploredGraph;
+use crate::loggers::explored_graph::rendering::{AbstractExploredGraphRenderer, ExploredGraphRenderingLogger};
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::rendering::AbstractProcessLabeller;
+
+use super::drawer::draw_explored_graph;
+use super::style::AbstractGraphvizStyler;
+
+
+
+/** 
+ * Renders the explored graph as a GraphViz DOT file at "output_file_path",
+ * as described in [draw_explored_graph](draw_explored_graph), with the "labeller" and the "styler".
+ * **/
+pub struct GraphvizRenderer<Labeller,Styler> {
+    pub output_file_path : PathBuf,
+    pub labeller : Labeller,
+    pub styler : Styler
+}
+
+impl<Labeller,Styler> GraphvizRenderer<Labeller,Styler> {
+
+    pub fn new(
+        output_file_path : PathBuf,
+        labeller : Labeller,
+        styler : Styler
+    ) -> Self {
+        Self { 
+            output_file_path, 
+            labeller, 
+            styler 
+        }
+    }
+
+}
+
+impl<Conf, Labeller, Styler> AbstractExploredGraphRenderer<Conf> for GraphvizRenderer<Labeller,Styler> 
+    where 
+        Conf : AbstractProcessConfiguration,
+        Labeller : AbstractProcessLabeller<Conf>,
+        Styler : AbstractGraphvizStyler<Conf>
+{
+
+    fn render(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        graph : &ExploredGraph<Conf>
+    ) -> io::Result<()> {
+        draw_explored_graph(
+            graph,
+            context_and_param,
+            &self.labeller,
+            &self.styler,
+            &self.output_file_path
+        )
+    }
+
+}
+
+
+
+/** 
+ * Logger that draws the explored graph as a GraphViz DOT file.
+ * 
+ * The DOT file is (re)written each time the process terminates
+ * so that, if the process resumes from its unexplored frontier, it covers all the passes.
+ * **/
+pub type GraphvizProcessLogger<Conf,Labeller,Styler> = ExploredGraphRenderingLogger<Conf,GraphvizRenderer<Labeller,Styler>>;
@@ -0,0 +1,270 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::fmt;
+
+use crate::process::config::AbstractProcessConfiguration;
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GraphvizNodeShape {
+    Box,
+    Ellipse,
+    Circle,
+    DoubleCircle,
+    Diamond,
+    Octagon,
+    Point,
+    Plaintext
+}
+
+impl fmt::Display for GraphvizNodeShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphvizNodeShape::Box => {
+                write!(f,"box")
+            },
+            GraphvizNodeShape::Ellipse => {
+                write!(f,"ellipse")
+            },
+            GraphvizNodeShape::Circle => {
+                write!(f,"circle")
+            },
+            GraphvizNodeShape::DoubleCircle => {
+                write!(f,"doublecircle")
+            },
+            GraphvizNodeShape::Diamond => {
+                write!(f,"diamond")
+            },
+            GraphvizNodeShape::Octagon => {
+                write!(f,"octagon")
+            },
+            GraphvizNodeShape::Point => {
+                write!(f,"point")
+            },
+            GraphvizNodeShape::Plaintext => {
+                write!(f,"plaintext")
+            }
+        }
+    }
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GraphvizStyleItem {
+    Solid,
+    Dashed,
+    Dotted,
+    Bold,
+    Filled,
+    Rounded
+}
+
+impl fmt::Display for GraphvizStyleItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphvizStyleItem::Solid => {
+                write!(f,"solid")
+            },
+            GraphvizStyleItem::Dashed => {
+                write!(f,"dashed")
+            },
+            GraphvizStyleItem::Dotted => {
+                write!(f,"dotted")
+            },
+            GraphvizStyleItem::Bold => {
+                write!(f,"bold")
+            },
+            GraphvizStyleItem::Filled => {
+                write!(f,"filled")
+            },
+            GraphvizStyleItem::Rounded => {
+                write!(f,"rounded")
+            }
+        }
+    }
+}
+
+
+
+/** 
+ * Style of a node or an edge in a GraphViz graph.
+ * Attributes that are None are left to their GraphViz default values
+ * (colors are given as GraphViz colors e.g., "red" or "#ff0000").
+ * **/
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct GraphvizStyle {
+    pub shape : Option<GraphvizNodeShape>,
+    pub style : Vec<GraphvizStyleItem>,
+    pub color : Option<String>,
+    pub fillcolor : Option<String>,
+    pub fontcolor : Option<String>,
+    pub peripheries : Option<u32>,
+    pub penwidth : Option<f64>
+}
+
+impl GraphvizStyle {
+
+    /** 
+     * Returns this style overridden by the attributes that are set in "other".
+     * **/
+    pub fn merged_with(&self, other : &GraphvizStyle) -> GraphvizStyle {
+        GraphvizStyle {
+            shape : other.shape.or(self.shape),
+            style : if other.style.is_empty() { self.style.clone() } else { other.style.clone() },
+            color : other.color.clone().or_else(|| self.color.clone()),
+            fillcolor : other.fillcolor.clone().or_else(|| self.fillcolor.clone()),
+            fontcolor : other.fontcolor.clone().or_else(|| self.fontcolor.clone()),
+            peripheries : other.peripheries.or(self.peripheries),
+            penwidth : other.penwidth.or(self.penwidth)
+        }
+    }
+
+    /** 
+     * Returns the GraphViz attributes (e.g., "shape=box") corresponding to this style.
+     * **/
+    pub fn to_dot_attributes(&self) -> Vec<String> {
+        let mut attributes = vec![];
+        if let Some(shape) = &self.shape {
+            attributes.push(format!("shape={}", shape));
+        }
+        if !self.style.is_empty() {
+            let items : Vec<String> = self.style.iter().map(|x| x.to_string()).collect();
+            attributes.push(format!("style=\"{}\"", items.join(",")));
+        }
+        if let Some(color) = &self.color {
+            attributes.push(format!("color=\"{}\"", escape_dot_string(color)));
+        }
+        if let Some(fillcolor) = &self.fillcolor {
+            attributes.push(format!("fillcolor=\"{}\"", escape_dot_string(fillcolor)));
+        }
+        if let Some(fontcolor) = &self.fontcolor {
+            attributes.push(format!("fontcolor=\"{}\"", escape_dot_string(fontcolor)));
+        }
+        if let Some(peripheries) = &self.peripheries {
+            attributes.push(format!("peripheries={}", peripheries));
+        }
+        if let Some(penwidth) = &self.penwidth {
+            attributes.push(format!("penwidth={}", penwidth));
+        }
+        attributes
+    }
+
+}
+
+
+
+/** 
+ * Escapes a string so that it can be used within double quotes in a DOT file.
+ * **/
+pub fn escape_dot_string(raw : &str) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {},
+            _ => escaped.push(c)
+        }
+    }
+    escaped
+}
+
+
+
+/** 
+ * Styles the elements of the graph drawn by the [GraphvizProcessLogger](crate::loggers::graphviz::logger::GraphvizProcessLogger).
+ * 
+ * By default:
+ * - nodes are drawn as boxes
+ * - terminal nodes (nodes without children) are drawn with a double border
+ * - filtration results are drawn as red octagons, linked to the filtered node by dashed red edges
+ * **/
+pub trait AbstractGraphvizStyler<Conf : AbstractProcessConfiguration> {
+
+    fn get_node_style(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _node : &Conf::DomainSpecificNode
+    ) -> GraphvizStyle {
+        GraphvizStyle {
+            shape : Some(GraphvizNodeShape::Box),
+            ..Default::default()
+        }
+    }
+
+    fn get_step_style(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _step : &Conf::DomainSpecificStep
+    ) -> GraphvizStyle {
+        GraphvizStyle::default()
+    }
+
+    /** 
+     * Style that is applied on top of the style of a node once it is known to be terminal.
+     * **/
+    fn get_terminal_node_style(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization
+    ) -> GraphvizStyle {
+        GraphvizStyle {
+            peripheries : Some(2),
+            ..Default::default()
+        }
+    }
+
+    fn get_filtration_result_style(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _filtration_result : &Conf::FiltrationResult
+    ) -> GraphvizStyle {
+        GraphvizStyle {
+            shape : Some(GraphvizNodeShape::Octagon),
+            style : vec![GraphvizStyleItem::Filled],
+            color : Some("red".to_string()),
+            fillcolor : Some("#ffdddd".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /** 
+     * Style of the edge between a filtered node and the filtration result.
+     * **/
+    fn get_filtered_edge_style(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization
+    ) -> GraphvizStyle {
+        GraphvizStyle {
+            style : vec![GraphvizStyleItem::Dashed],
+            color : Some("red".to_string()),
+            ..Default::default()
+        }
+    }
+
+}
+
+
+
+/** 
+ * Styler that keeps all the default styles.
+ * **/
+pub struct DefaultGraphvizStyler;
+
+impl<Conf : AbstractProcessConfiguration> AbstractGraphvizStyler<Conf> for DefaultGraphvizStyler {}
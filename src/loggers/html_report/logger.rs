@@ -0,0 +1,88 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::PathBuf;
+
+use crate::loggers::explored_graph::graph::ExploredGraph;
+use crate::loggers::explored_graph::rendering::{AbstractExploredGraphRenderer, ExploredGraphRenderingLogger};
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::rendering::AbstractProcessLabeller;
+
+use super::report::write_html_report;
+
+
+
+/** 
+ * Renders the explored graph as a single, self-contained, HTML file at "output_file_path",
+ * as described in [write_html_report](write_html_report), with the "labeller" and the "title".
+ * **/
+pub struct HtmlReportRenderer<Labeller> {
+    pub output_file_path : PathBuf,
+    pub labeller : Labeller,
+    pub title : String
+}
+
+impl<Labeller> HtmlReportRenderer<Labeller> {
+
+    pub fn new(
+        output_file_path : PathBuf,
+        labeller : Labeller,
+        title : String
+    ) -> Self {
+        Self { 
+            output_file_path, 
+            labeller, 
+            title 
+        }
+    }
+
+}
+
+impl<Conf, Labeller> AbstractExploredGraphRenderer<Conf> for HtmlReportRenderer<Labeller> 
+    where 
+        Conf : AbstractProcessConfiguration,
+        Labeller : AbstractProcessLabeller<Conf>
+{
+
+    fn render(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        graph : &ExploredGraph<Conf>
+    ) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&self.output_file_path)?);
+        write_html_report(
+            graph,
+            context_and_param,
+            &self.labeller,
+            &self.title,
+            &mut writer
+        )
+    }
+
+}
+
+
+
+/** 
+ * Logger that produces a single, self-contained, HTML file to inspect the explored graph in a web browser
+ * without any other tool or network access.
+ * 
+ * The report is (re)written each time the process terminates
+ * so that, if the process resumes from its unexplored frontier, it covers all the passes.
+ * **/
+pub type HtmlReportLogger<Conf,Labeller> = ExploredGraphRenderingLogger<Conf,HtmlReportRenderer<Labeller>>;
@@ -0,0 +1,184 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use crate::loggers::explored_graph::graph::{ExploredGraph, ExploredGraphEdgeKind};
+use crate::loggers::json_lines::serializer::to_json_string;
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::rendering::AbstractProcessLabeller;
+
+use super::viewer::HTML_REPORT_TEMPLATE;
+
+
+
+enum HtmlReportEntry {
+    Node(u32),
+    Revisit(u32,String),
+    Filtration(String,Option<String>)
+}
+
+struct HtmlReportNode {
+    label : String,
+    tooltip : Option<String>,
+    style_class : Option<String>,
+    parent_node_id : Option<u32>,
+    step_label : Option<String>,
+    depth : u32,
+    is_terminal : bool,
+    children : Vec<HtmlReportEntry>
+}
+
+impl HtmlReportNode {
+    fn to_json(&self, node_id : u32) -> String {
+        let children : Vec<String> = self.children.iter().map(
+            |x| match x {
+                HtmlReportEntry::Node(child_id) => format!("{{\"node\":{}}}", child_id),
+                HtmlReportEntry::Revisit(target_id,step_label) => format!("{{\"revisit\":{},\"step\":{}}}", target_id, to_json_string(step_label)),
+                HtmlReportEntry::Filtration(label,tooltip) => format!("{{\"filtration\":{},\"tooltip\":{}}}", to_json_string(label), optional_json_string(tooltip))
+            }
+        ).collect();
+        format!(
+            "{{\"id\":{},\"label\":{},\"tooltip\":{},\"class\":{},\"parent\":{},\"step\":{},\"depth\":{},\"terminal\":{},\"children\":[{}]}}",
+            node_id,
+            to_json_string(&self.label),
+            optional_json_string(&self.tooltip),
+            optional_json_string(&self.style_class),
+            optional_json_number(self.parent_node_id),
+            optional_json_string(&self.step_label),
+            self.depth,
+            self.is_terminal,
+            children.join(",")
+        )
+    }
+}
+
+fn optional_json_number(raw : Option<u32>) -> String {
+    match raw {
+        Some(number) => number.to_string(),
+        None => "null".to_string()
+    }
+}
+
+fn optional_json_string(raw : &Option<String>) -> String {
+    match raw {
+        Some(string) => to_json_string(string),
+        None => "null".to_string()
+    }
+}
+
+fn get_report_data_as_json<Conf, Labeller>(
+    graph : &ExploredGraph<Conf>,
+    context_and_param : &Conf::ContextAndParameterization,
+    labeller : &Labeller
+) -> String 
+    where 
+        Conf : AbstractProcessConfiguration,
+        Labeller : AbstractProcessLabeller<Conf>
+{
+    let mut nodes : BTreeMap<u32,HtmlReportNode> = BTreeMap::new();
+    for graph_node in graph.nodes.values() {
+        let (label,tooltip,style_class) = match &graph_node.node {
+            Some(node) => (
+                labeller.get_node_label(context_and_param, node),
+                labeller.get_node_tooltip(context_and_param, node),
+                labeller.get_node_style_class(context_and_param, node)
+            ),
+            None => (graph_node.node_id.to_string(), None, None)
+        };
+        nodes.insert(
+            graph_node.node_id,
+            HtmlReportNode {
+                label,
+                tooltip,
+                style_class,
+                parent_node_id : graph_node.discovery_parent_id,
+                step_label : None,
+                depth : graph_node.depth,
+                is_terminal : graph_node.is_terminal,
+                children : vec![]
+            }
+        );
+    }
+    for edge in &graph.edges {
+        let step_label = labeller.get_step_label(context_and_param, &edge.step);
+        let entry = match edge.kind {
+            ExploredGraphEdgeKind::Discovery => {
+                if let Some(target) = nodes.get_mut(&edge.target_node_id) {
+                    target.step_label = Some(step_label);
+                }
+                HtmlReportEntry::Node(edge.target_node_id)
+            },
+            ExploredGraphEdgeKind::MemoHit => {
+                HtmlReportEntry::Revisit(edge.target_node_id, step_label)
+            }
+        };
+        if let Some(origin) = nodes.get_mut(&edge.origin_node_id) {
+            origin.children.push(entry);
+        }
+    }
+    for filtration in &graph.filtrations {
+        let label = labeller.get_filtration_result_label(context_and_param, &filtration.filtration_result);
+        let tooltip = labeller.get_filtration_result_tooltip(context_and_param, &filtration.filtration_result);
+        if let Some(parent) = nodes.get_mut(&filtration.node_id) {
+            parent.children.push(HtmlReportEntry::Filtration(label,tooltip));
+        }
+    }
+    let nodes : Vec<String> = nodes.iter().map(|(node_id,node)| node.to_json(*node_id)).collect();
+    let data = format!(
+        "{{\"root\":{},\"nodes\":[\n{}\n]}}",
+        optional_json_number(graph.get_initial_node().map(|x| x.node_id)),
+        nodes.join(",\n")
+    );
+    // so that no label may close the script element in which the data is embedded
+    data.replace("</", "<\\/")
+}
+
+
+
+/** 
+ * Writes an [ExploredGraph](ExploredGraph) into a sink as a single, self-contained, HTML file 
+ * to inspect the explored graph in a web browser without any other tool or network access.
+ * 
+ * The explored graph is embedded as JSON (with labels, tooltips and style classes given by the "labeller") together with a small viewer
+ * (see [HTML_REPORT_TEMPLATE](HTML_REPORT_TEMPLATE)) which displays the tree of the first discoveries of nodes.
+ * Below each node, the steps fired from it are listed (in the order in which they have been fired), followed by its filtration results.
+ * Subtrees can be expanded and collapsed, nodes can be searched by label, terminal and filtered nodes are highlighted
+ * and the path from the initial node to any selected node is displayed.
+ * **/
+pub fn write_html_report<Conf, Labeller, Sink>(
+    graph : &ExploredGraph<Conf>,
+    context_and_param : &Conf::ContextAndParameterization,
+    labeller : &Labeller,
+    title : &str,
+    sink : &mut Sink
+) -> io::Result<()>
+    where 
+        Conf : AbstractProcessConfiguration,
+        Labeller : AbstractProcessLabeller<Conf>,
+        Sink : Write
+{
+    let title = title
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    let report = HTML_REPORT_TEMPLATE
+        .replace("{{TITLE}}", &title)
+        .replace("{{DATA}}", &get_report_data_as_json(graph, context_and_param, labeller));
+    sink.write_all(report.as_bytes())?;
+    sink.flush()
+}
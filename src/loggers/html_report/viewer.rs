@@ -0,0 +1,305 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+/** 
+ * Self-contained HTML page (style and script included) in which the report of a process is embedded.
+ * 
+ * "{{TITLE}}" is replaced by the (escaped) title of the report
+ * and "{{DATA}}" by the explored graph as JSON, which is an object with:
+ * - "root": the identifier of the initial node
+ * - "nodes": an array of nodes, each with "id", "label", "tooltip", "class" (a style class set on its row),
+ *   "parent" (the node from which it was first reached), "step" (the step from that parent), "depth", "terminal" and "children"
+ * 
+ * "children" are the steps fired from the node (in the order in which they have been fired) followed by its filtration results, each being either:
+ * - {"node":id} for a newly reached node
+ * - {"revisit":id,"step":label} for a step leading to an already known node
+ * - {"filtration":label,"tooltip":tooltip} for a filtration result
+ * **/
+pub const HTML_REPORT_TEMPLATE : &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{{TITLE}}</title>
+<style>
+body { font-family: sans-serif; margin: 0; display: flex; flex-direction: column; height: 100vh; }
+header { padding: 8px 12px; background: #2d3e50; color: #fff; }
+header h1 { font-size: 18px; margin: 0 0 4px 0; }
+header .stats { font-size: 13px; opacity: 0.85; }
+.toolbar { padding: 6px 12px; border-bottom: 1px solid #ccc; display: flex; gap: 6px; align-items: center; flex-wrap: wrap; }
+.toolbar input { width: 280px; padding: 3px; }
+main { flex: 1; display: flex; min-height: 0; }
+#tree { flex: 3; overflow: auto; padding: 8px 12px; font-family: monospace; font-size: 13px; }
+aside { flex: 2; overflow: auto; padding: 8px 12px; border-left: 1px solid #ccc; font-size: 13px; }
+aside h2 { font-size: 14px; margin: 8px 0 4px 0; }
+ul { list-style: none; margin: 0; padding-left: 18px; }
+#tree > ul { padding-left: 0; }
+.row { white-space: nowrap; padding: 1px 2px; border-radius: 3px; }
+.caret { display: inline-block; width: 14px; cursor: pointer; user-select: none; }
+.label { cursor: pointer; }
+.terminal > .label { font-weight: bold; }
+.terminal > .label::after { content: " \25A0"; color: #2a7a2a; }
+.filtered > .label { color: #b00; }
+.filtration { color: #b00; }
+.revisit { color: #777; font-style: italic; cursor: pointer; }
+.match { background: #fff3a0; }
+.selected { background: #cfe3ff; }
+.legend span { margin-right: 10px; }
+.results div, .path div { padding: 2px 0; cursor: pointer; font-family: monospace; }
+.results div:hover, .path div:hover { text-decoration: underline; }
+</style>
+</head>
+<body>
+<header>
+<h1>{{TITLE}}</h1>
+<div class="stats" id="stats"></div>
+</header>
+<div class="toolbar">
+<input type="search" id="search" placeholder="Search by label">
+<button id="expand-all">Expand all</button>
+<button id="collapse-all">Collapse all</button>
+<button id="list-terminal">Terminal nodes</button>
+<button id="list-filtered">Filtered nodes</button>
+<span class="legend"><span style="font-weight:bold">&#9632; terminal</span><span style="color:#b00">filtered</span><span class="revisit">&#8634; revisit</span></span>
+</div>
+<main>
+<div id="tree"></div>
+<aside>
+<h2>Path to root</h2>
+<div class="path" id="path">Select a node to see the path from the initial node.</div>
+<h2 id="results-title">Results</h2>
+<div class="results" id="results"></div>
+</aside>
+</main>
+<script type="application/json" id="report-data">{{DATA}}</script>
+<script>
+(function () {
+  "use strict";
+  var MAX_LISTED = 500;
+  var MAX_EXPANDED = 5000;
+  var data = JSON.parse(document.getElementById("report-data").textContent);
+  var nodes = new Map();
+  data.nodes.forEach(function (n) { nodes.set(n.id, n); });
+  var isFiltered = function (n) { return n.children.some(function (c) { return "filtration" in c; }); };
+  var rendered = new Map();
+  var query = "";
+  var selectedId = null;
+
+  var numTerminal = 0, numFiltered = 0, numRevisits = 0;
+  data.nodes.forEach(function (n) {
+    if (n.terminal) { numTerminal++; }
+    if (isFiltered(n)) { numFiltered++; }
+    n.children.forEach(function (c) { if ("revisit" in c) { numRevisits++; } });
+  });
+  document.getElementById("stats").textContent = data.nodes.length + " nodes | " + numTerminal + " terminal | "
+    + numFiltered + " filtered | " + numRevisits + " revisits";
+
+  function nodeText(n) {
+    return (n.step === null ? "" : n.step + " → ") + n.label + " (n" + n.id + ")";
+  }
+
+  function matches(n) {
+    return query !== "" && n.label.toLowerCase().indexOf(query) >= 0;
+  }
+
+  function rowClass(n) {
+    var classes = ["row"];
+    if (n.terminal) { classes.push("terminal"); }
+    if (isFiltered(n)) { classes.push("filtered"); }
+    if (matches(n)) { classes.push("match"); }
+    if (n.id === selectedId) { classes.push("selected"); }
+    if (n.class !== null) { classes.push(n.class); }
+    return classes.join(" ");
+  }
+
+  function renderNode(id, container) {
+    var n = nodes.get(id);
+    if (!n) { return; }
+    var li = document.createElement("li");
+    var row = document.createElement("div");
+    row.className = rowClass(n);
+    if (n.tooltip !== null) { row.title = n.tooltip; }
+    var caret = document.createElement("span");
+    caret.className = "caret";
+    caret.textContent = n.children.length > 0 ? "▸" : "";
+    caret.onclick = function () { toggle(id); };
+    var label = document.createElement("span");
+    label.className = "label";
+    label.textContent = nodeText(n);
+    label.onclick = function () { select(id); };
+    row.appendChild(caret);
+    row.appendChild(label);
+    li.appendChild(row);
+    container.appendChild(li);
+    rendered.set(id, { id: id, li: li, row: row, caret: caret, list: null });
+  }
+
+  function expand(id) {
+    var element = rendered.get(id);
+    if (!element || element.list) { return; }
+    var list = document.createElement("ul");
+    nodes.get(id).children.forEach(function (c) {
+      if ("node" in c) {
+        renderNode(c.node, list);
+      } else if ("revisit" in c) {
+        var li = document.createElement("li");
+        li.className = "revisit";
+        li.textContent = c.step + " → ↺ n" + c.revisit;
+        li.onclick = function () { select(c.revisit); };
+        list.appendChild(li);
+      } else {
+        var fli = document.createElement("li");
+        fli.className = "filtration";
+        fli.textContent = "✗ " + c.filtration;
+        if (c.tooltip !== null) { fli.title = c.tooltip; }
+        list.appendChild(fli);
+      }
+    });
+    element.li.appendChild(list);
+    element.list = list;
+    element.caret.textContent = "▾";
+  }
+
+  function collapse(id) {
+    var element = rendered.get(id);
+    if (!element || !element.list) { return; }
+    element.li.removeChild(element.list);
+    element.caret.textContent = "▸";
+    // the rendered descendants are forgotten once the whole subtree has been visited
+    var forgotten = [];
+    var stack = [id];
+    while (stack.length > 0) {
+      var current = rendered.get(stack.pop());
+      if (!current || !current.list) { continue; }
+      current.list = null;
+      nodes.get(current.id).children.forEach(function (c) {
+        if ("node" in c) {
+          stack.push(c.node);
+          forgotten.push(c.node);
+        }
+      });
+    }
+    forgotten.forEach(function (child) { rendered.delete(child); });
+  }
+
+  function toggle(id) {
+    var element = rendered.get(id);
+    if (element && element.list) { collapse(id); } else { expand(id); }
+  }
+
+  function pathToRoot(id) {
+    var path = [];
+    var current = nodes.get(id);
+    while (current) {
+      path.push(current);
+      current = current.parent === null ? null : nodes.get(current.parent);
+    }
+    return path.reverse();
+  }
+
+  function reveal(id) {
+    pathToRoot(id).forEach(function (n) {
+      if (n.id !== id) { expand(n.id); }
+    });
+  }
+
+  function refreshRow(id) {
+    var element = rendered.get(id);
+    if (element) { element.row.className = rowClass(nodes.get(id)); }
+  }
+
+  function select(id) {
+    if (!nodes.has(id)) { return; }
+    var previous = selectedId;
+    selectedId = id;
+    if (previous !== null) { refreshRow(previous); }
+    reveal(id);
+    refreshRow(id);
+    var element = rendered.get(id);
+    if (element) { element.row.scrollIntoView({ block: "center" }); }
+    var pathPanel = document.getElementById("path");
+    pathPanel.textContent = "";
+    pathToRoot(id).forEach(function (n) {
+      var div = document.createElement("div");
+      div.style.paddingLeft = (n.depth * 8) + "px";
+      div.textContent = nodeText(n);
+      div.onclick = function () { select(n.id); };
+      pathPanel.appendChild(div);
+    });
+  }
+
+  function listNodes(title, found) {
+    document.getElementById("results-title").textContent = title + " (" + found.length + ")";
+    var results = document.getElementById("results");
+    results.textContent = "";
+    found.slice(0, MAX_LISTED).forEach(function (n) {
+      var div = document.createElement("div");
+      div.textContent = nodeText(n);
+      div.onclick = function () { select(n.id); };
+      results.appendChild(div);
+    });
+    if (found.length > MAX_LISTED) {
+      var more = document.createElement("p");
+      more.textContent = "only the first " + MAX_LISTED + " are listed";
+      results.appendChild(more);
+    }
+  }
+
+  document.getElementById("search").addEventListener("input", function (event) {
+    query = event.target.value.trim().toLowerCase();
+    rendered.forEach(function (_, id) { refreshRow(id); });
+    if (query === "") {
+      listNodes("Results", []);
+    } else {
+      listNodes("Matches", data.nodes.filter(matches));
+    }
+  });
+
+  document.getElementById("expand-all").onclick = function () {
+    var queue = [data.root];
+    var count = 0;
+    while (queue.length > 0 && count < MAX_EXPANDED) {
+      var id = queue.shift();
+      expand(id);
+      count++;
+      nodes.get(id).children.forEach(function (c) { if ("node" in c) { queue.push(c.node); } });
+    }
+  };
+
+  document.getElementById("collapse-all").onclick = function () {
+    collapse(data.root);
+  };
+
+  document.getElementById("list-terminal").onclick = function () {
+    listNodes("Terminal nodes", data.nodes.filter(function (n) { return n.terminal; }));
+  };
+
+  document.getElementById("list-filtered").onclick = function () {
+    listNodes("Filtered nodes", data.nodes.filter(isFiltered));
+  };
+
+  if (nodes.has(data.root)) {
+    var rootList = document.createElement("ul");
+    document.getElementById("tree").appendChild(rootList);
+    renderNode(data.root, rootList);
+    expand(data.root);
+  }
+})();
+</script>
+</body>
+</html>
+"#;
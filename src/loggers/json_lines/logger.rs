@@ -0,0 +1,391 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::any::Any;
+use std::io::{self, Write};
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::filter::GenericFiltersManager;
+use crate::process::filter_statistics::ManagedFilterInfo;
+use crate::process::logger::AbstractProcessLogger;
+use crate::queue::priorities::GenericProcessPriorities;
+use crate::queue::strategy::QueueSearchStrategy;
+
+use super::serializer::{to_json_string, AbstractJsonPayloadSerializer};
+
+
+
+/** 
+ * Logger that writes every exploration event into a sink, as a JSON object per line (JSON Lines).
+ * 
+ * Each object has a "seq" field (the sequence number of the event, starting from 0) 
+ * and an "event" field which is one of:
+ * - "initialize", with "strategy" and "use_memoization"
+ * - "new_node", with "node_id" and "node"
+ * - "new_step", with "origin_node_id", "target_node_id" and "step"
+ * - "memo_hit", with "origin_node_id", "target_node_id" and "step"
+ * - "memo_size", with "num_memoized_nodes"
+ * - "node_reexpanded", with "memoized_node_id", "new_node_id" and "new_depth"
+ * - "filtered", with "parent_node_id", "filtration_result_id" and "filtration_result"
+ * - "step_filtered", with "parent_node_id", "step", "filtration_result_id" and "filtration_result"
+ * - "edge_filtered", with "parent_node_id", "step", "child_node", "filtration_result_id" and "filtration_result"
+ * - "deferred", with "node_id" and "step" (null if the node itself is deferred)
+ * - "last_child_step_processed", with "parent_node_id"
+ * - "node_without_children", with "node_id"
+ * - "filters_summary", with "filters", an array of objects with "name", "kind", "description", "enabled", "defers",
+ *   "num_applications", "num_activations", "total_duration_secs" and "activation_depths" (from depths to numbers of activations)
+ * - "resume", with "global_state"
+ * - "terminate", with "global_state"
+ * 
+ * Domain-specific payloads ("node", "child_node", "step", "filtration_result" and "global_state")
+ * are serialized by the "serializer".
+ * 
+ * Should writing into the sink fail, the logger stops writing and the error can be retrieved via "get_io_error".
+ * **/
+pub struct JsonLinesProcessLogger<Sink,Serializer> {
+    pub sink : Sink,
+    pub serializer : Serializer,
+    sequence_number : u64,
+    io_error : Option<io::Error>
+}
+
+impl<Sink : Write, Serializer> JsonLinesProcessLogger<Sink,Serializer> {
+
+    pub fn new(sink : Sink, serializer : Serializer) -> Self {
+        Self { 
+            sink, 
+            serializer, 
+            sequence_number : 0, 
+            io_error : None 
+        }
+    }
+
+    /** 
+     * Returns the error that occurred when writing into the sink, if any.
+     * **/
+    pub fn get_io_error(&self) -> Option<&io::Error> {
+        self.io_error.as_ref()
+    }
+
+    /** 
+     * Writes an event given the name of the event and its other fields (names and JSON values).
+     * **/
+    fn write_event(&mut self, event : &str, fields : &[(&str,String)]) {
+        if self.io_error.is_some() {
+            return;
+        }
+        let mut line = format!("{{\"seq\":{},\"event\":{}", self.sequence_number, to_json_string(event));
+        for (field_name,field_value) in fields {
+            line.push_str(&format!(",{}:{}", to_json_string(field_name), field_value));
+        }
+        line.push('}');
+        self.sequence_number += 1;
+        if let Err(e) = writeln!(self.sink, "{}", line) {
+            self.io_error = Some(e);
+        }
+    }
+
+}
+
+
+
+/** 
+ * Encodes the information on a filter as a JSON object.
+ * **/
+fn filter_info_to_json(filter_info : &ManagedFilterInfo) -> String {
+    let activation_depths : Vec<String> = filter_info.statistics.activation_depths.iter()
+        .map(|(depth,num_activations)| format!("{}:{}", to_json_string(&depth.to_string()), num_activations))
+        .collect();
+    format!(
+        "{{\"name\":{},\"kind\":{},\"description\":{},\"enabled\":{},\"defers\":{},\"num_applications\":{},\"num_activations\":{},\"total_duration_secs\":{},\"activation_depths\":{{{}}}}}",
+        to_json_string(&filter_info.name),
+        to_json_string(&filter_info.kind.to_string()),
+        to_json_string(&filter_info.description),
+        filter_info.enabled,
+        filter_info.defers,
+        filter_info.statistics.num_applications,
+        filter_info.statistics.num_activations,
+        filter_info.statistics.total_duration.as_secs_f64(),
+        activation_depths.join(",")
+    )
+}
+
+impl<Conf, Sink, Serializer> AbstractProcessLogger<Conf> for JsonLinesProcessLogger<Sink,Serializer> 
+    where 
+        Conf : AbstractProcessConfiguration,
+        Sink : 'static + Write,
+        Serializer : 'static + AbstractJsonPayloadSerializer<Conf>
+{
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        strategy : &QueueSearchStrategy,
+        _priorities : &GenericProcessPriorities<Conf::Priorities>,
+        _filters_manager : &GenericFiltersManager<Conf>,
+        _initial_global_state : &Conf::MutablePersistentState,
+        use_memoization : bool,
+    ) {
+        self.write_event(
+            "initialize", 
+            &[
+                ("strategy", to_json_string(&strategy.to_string())),
+                ("use_memoization", use_memoization.to_string())
+            ]
+        );
+    }
+
+    fn log_new_node(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        new_node_id : u32,
+        new_node : &Conf::DomainSpecificNode
+    ) {
+        let node = self.serializer.node_to_json(context_and_param, new_node);
+        self.write_event(
+            "new_node", 
+            &[
+                ("node_id", new_node_id.to_string()),
+                ("node", node)
+            ]
+        );
+    }
+
+    fn log_new_step(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        origin_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        target_node_id : u32,
+        _target_node : &Conf::DomainSpecificNode
+    ) {
+        let step = self.serializer.step_to_json(context_and_param, step);
+        self.write_event(
+            "new_step", 
+            &[
+                ("origin_node_id", origin_node_id.to_string()),
+                ("target_node_id", target_node_id.to_string()),
+                ("step", step)
+            ]
+        );
+    }
+
+    fn log_memo_hit(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        origin_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        target_node_id : u32
+    ) {
+        let step = self.serializer.step_to_json(context_and_param, step);
+        self.write_event(
+            "memo_hit", 
+            &[
+                ("origin_node_id", origin_node_id.to_string()),
+                ("target_node_id", target_node_id.to_string()),
+                ("step", step)
+            ]
+        );
+    }
+
+    fn log_memo_size(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        num_memoized_nodes : usize
+    ) {
+        self.write_event(
+            "memo_size", 
+            &[
+                ("num_memoized_nodes", num_memoized_nodes.to_string())
+            ]
+        );
+    }
+
+    fn log_node_reexpanded(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        memoized_node_id : u32,
+        new_node_id : u32,
+        new_depth : u32
+    ) {
+        self.write_event(
+            "node_reexpanded", 
+            &[
+                ("memoized_node_id", memoized_node_id.to_string()),
+                ("new_node_id", new_node_id.to_string()),
+                ("new_depth", new_depth.to_string())
+            ]
+        );
+    }
+
+    fn log_notify_last_child_step_of_node_processed(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32
+    ) {
+        self.write_event(
+            "last_child_step_processed", 
+            &[
+                ("parent_node_id", parent_node_id.to_string())
+            ]
+        );
+    }
+
+    fn log_notify_node_without_children(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        node_id : u32
+    ) {
+        self.write_event(
+            "node_without_children", 
+            &[
+                ("node_id", node_id.to_string())
+            ]
+        );
+    }
+
+    fn log_filtered(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        let filtration_result = self.serializer.filtration_result_to_json(context_and_param, filtration_result);
+        self.write_event(
+            "filtered", 
+            &[
+                ("parent_node_id", parent_node_id.to_string()),
+                ("filtration_result_id", filtration_result_id.to_string()),
+                ("filtration_result", filtration_result)
+            ]
+        );
+    }
+
+    fn log_step_filtered(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        let step = self.serializer.step_to_json(context_and_param, step);
+        let filtration_result = self.serializer.filtration_result_to_json(context_and_param, filtration_result);
+        self.write_event(
+            "step_filtered", 
+            &[
+                ("parent_node_id", parent_node_id.to_string()),
+                ("step", step),
+                ("filtration_result_id", filtration_result_id.to_string()),
+                ("filtration_result", filtration_result)
+            ]
+        );
+    }
+
+    fn log_edge_filtered(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        child_node : &Conf::DomainSpecificNode,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        let step = self.serializer.step_to_json(context_and_param, step);
+        let child_node = self.serializer.node_to_json(context_and_param, child_node);
+        let filtration_result = self.serializer.filtration_result_to_json(context_and_param, filtration_result);
+        self.write_event(
+            "edge_filtered", 
+            &[
+                ("parent_node_id", parent_node_id.to_string()),
+                ("step", step),
+                ("child_node", child_node),
+                ("filtration_result_id", filtration_result_id.to_string()),
+                ("filtration_result", filtration_result)
+            ]
+        );
+    }
+
+    fn log_deferred(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        node_id : u32,
+        step : Option<&Conf::DomainSpecificStep>
+    ) {
+        let step = match step {
+            Some(step) => self.serializer.step_to_json(context_and_param, step),
+            None => "null".to_string()
+        };
+        self.write_event(
+            "deferred", 
+            &[
+                ("node_id", node_id.to_string()),
+                ("step", step)
+            ]
+        );
+    }
+
+    fn log_filters_summary(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        filters_info : &[&ManagedFilterInfo]
+    ) {
+        let filters : Vec<String> = filters_info.iter().map(|x| filter_info_to_json(x)).collect();
+        self.write_event(
+            "filters_summary", 
+            &[
+                ("filters", format!("[{}]", filters.join(",")))
+            ]
+        );
+    }
+
+    fn log_resume(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState
+    ) {
+        let global_state = self.serializer.global_state_to_json(context_and_param, global_state);
+        self.write_event(
+            "resume", 
+            &[
+                ("global_state", global_state)
+            ]
+        );
+    }
+
+    fn log_terminate_process(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState
+    ) {
+        let global_state = self.serializer.global_state_to_json(context_and_param, global_state);
+        self.write_event(
+            "terminate", 
+            &[
+                ("global_state", global_state)
+            ]
+        );
+        if self.io_error.is_none() {
+            self.io_error = self.sink.flush().err();
+        }
+    }
+
+}
@@ -0,0 +1,129 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::rendering::AbstractProcessLabeller;
+
+
+
+/** 
+ * Serializes domain-specific data into JSON values (e.g., "42", "\"text\"" or "{\"a\":1}").
+ * The returned strings are inserted as is in the JSON objects written by the
+ * [JsonLinesProcessLogger](crate::loggers::json_lines::logger::JsonLinesProcessLogger)
+ * and must therefore be valid JSON.
+ * **/
+pub trait AbstractJsonPayloadSerializer<Conf : AbstractProcessConfiguration> {
+
+    fn node_to_json(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        node : &Conf::DomainSpecificNode
+    ) -> String;
+
+    fn step_to_json(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        step : &Conf::DomainSpecificStep
+    ) -> String;
+
+    fn filtration_result_to_json(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        filtration_result : &Conf::FiltrationResult
+    ) -> String;
+
+    /** 
+     * By default, the global state is not serialized.
+     * **/
+    fn global_state_to_json(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) -> String {
+        "null".to_string()
+    }
+
+}
+
+
+
+/** 
+ * Serializes nodes, steps and filtration results as JSON strings containing their labels.
+ * **/
+pub struct LabelJsonSerializer<Labeller> {
+    pub labeller : Labeller
+}
+
+impl<Labeller> LabelJsonSerializer<Labeller> {
+    pub fn new(labeller: Labeller) -> Self {
+        Self { labeller }
+    }
+}
+
+impl<Conf, Labeller> AbstractJsonPayloadSerializer<Conf> for LabelJsonSerializer<Labeller> 
+    where 
+        Conf : AbstractProcessConfiguration,
+        Labeller : AbstractProcessLabeller<Conf>
+{
+
+    fn node_to_json(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        node : &Conf::DomainSpecificNode
+    ) -> String {
+        to_json_string(&self.labeller.get_node_label(context_and_param, node))
+    }
+
+    fn step_to_json(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        step : &Conf::DomainSpecificStep
+    ) -> String {
+        to_json_string(&self.labeller.get_step_label(context_and_param, step))
+    }
+
+    fn filtration_result_to_json(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        filtration_result : &Conf::FiltrationResult
+    ) -> String {
+        to_json_string(&self.labeller.get_filtration_result_label(context_and_param, filtration_result))
+    }
+
+}
+
+
+
+/** 
+ * Encodes a string as a JSON string literal (with the surrounding double quotes).
+ * **/
+pub fn to_json_string(raw : &str) -> String {
+    let mut encoded = String::with_capacity(raw.len() + 2);
+    encoded.push('"');
+    for c in raw.chars() {
+        match c {
+            '"' => encoded.push_str("\\\""),
+            '\\' => encoded.push_str("\\\\"),
+            '\n' => encoded.push_str("\\n"),
+            '\r' => encoded.push_str("\\r"),
+            '\t' => encoded.push_str("\\t"),
+            c if (c as u32) < 0x20 => encoded.push_str(&format!("\\u{:04x}", c as u32)),
+            _ => encoded.push(c)
+        }
+    }
+    encoded.push('"');
+    encoded
+}
@@ -0,0 +1,261 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::filter::GenericFiltersManager;
+use crate::process::logger::AbstractProcessLogger;
+use crate::queue::priorities::GenericProcessPriorities;
+use crate::queue::strategy::QueueSearchStrategy;
+
+
+
+/** 
+ * Counters of a [ProgressReporterLogger](ProgressReporterLogger).
+ * **/
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+struct ProgressCounters {
+    num_nodes : u64,
+    num_steps : u64,
+    num_filtrations : u64,
+    num_memo_hits : u64,
+    memo_size : usize
+}
+
+
+
+/** 
+ * Logger that periodically writes a status line into a sink (e.g., stderr) during the process.
+ * 
+ * The status line gives the elapsed time, the number of nodes and steps (with their rates per second over the last interval),
+ * the number of filtrations, the number of memoized nodes and of memo hits (if memoization is used)
+ * and the range of depths of the nodes from which steps remain to be processed.
+ * A summary is written once the process terminates.
+ * If the process resumes from its unexplored frontier, the counters are kept and a summary is written again once it terminates.
+ * 
+ * Status lines are written at most once per "interval".
+ * Reporting is throttled by time i.e., the clock is checked upon new nodes and steps
+ * but nothing is written until the interval has elapsed.
+ * 
+ * Errors when writing into the sink are ignored, so that reporting never interrupts the process.
+ * **/
+pub struct ProgressReporterLogger<Sink> {
+    pub sink : Sink,
+    pub interval : Duration,
+    use_memoization : bool,
+    start : Instant,
+    last_report : Instant,
+    counters : ProgressCounters,
+    counters_at_last_report : ProgressCounters,
+    /// depths of the nodes that have been reached and from which steps may remain to be processed
+    frontier_depths : HashMap<u32,u32>,
+    last_new_node_id : Option<u32>
+}
+
+impl ProgressReporterLogger<io::Stderr> {
+
+    pub fn to_stderr(interval : Duration) -> Self {
+        Self::new(io::stderr(), interval)
+    }
+
+}
+
+impl<Sink : Write> ProgressReporterLogger<Sink> {
+
+    pub fn new(sink : Sink, interval : Duration) -> Self {
+        let now = Instant::now();
+        Self { 
+            sink, 
+            interval, 
+            use_memoization : false,
+            start : now, 
+            last_report : now, 
+            counters : ProgressCounters::default(), 
+            counters_at_last_report : ProgressCounters::default(), 
+            frontier_depths : HashMap::new(), 
+            last_new_node_id : None 
+        }
+    }
+
+    fn get_depth_range(&self) -> Option<(u32,u32)> {
+        let min_depth = self.frontier_depths.values().min()?;
+        let max_depth = self.frontier_depths.values().max()?;
+        Some((*min_depth,*max_depth))
+    }
+
+    fn report_if_due(&mut self) {
+        let now = Instant::now();
+        let since_last_report = now.duration_since(self.last_report);
+        if since_last_report < self.interval {
+            return;
+        }
+        let seconds = since_last_report.as_secs_f64();
+        let nodes_per_second = (self.counters.num_nodes - self.counters_at_last_report.num_nodes) as f64 / seconds;
+        let steps_per_second = (self.counters.num_steps - self.counters_at_last_report.num_steps) as f64 / seconds;
+        let mut line = format!(
+            "[{:.1}s] nodes {} ({:.1}/s) | steps {} ({:.1}/s) | filtered {}",
+            now.duration_since(self.start).as_secs_f64(),
+            self.counters.num_nodes,
+            nodes_per_second,
+            self.counters.num_steps,
+            steps_per_second,
+            self.counters.num_filtrations
+        );
+        if self.use_memoization {
+            line.push_str(&format!(" | memo size {} (hits {})", self.counters.memo_size, self.counters.num_memo_hits));
+        }
+        if let Some((min_depth,max_depth)) = self.get_depth_range() {
+            line.push_str(&format!(" | depths {}..{}", min_depth, max_depth));
+        }
+        let _ = writeln!(self.sink, "{}", line);
+        self.last_report = now;
+        self.counters_at_last_report = self.counters;
+    }
+
+}
+
+impl<Conf, Sink> AbstractProcessLogger<Conf> for ProgressReporterLogger<Sink> 
+    where 
+        Conf : AbstractProcessConfiguration,
+        Sink : 'static + Write
+{
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _strategy : &QueueSearchStrategy,
+        _priorities : &GenericProcessPriorities<Conf::Priorities>,
+        _filters_manager : &GenericFiltersManager<Conf>,
+        _initial_global_state : &Conf::MutablePersistentState,
+        use_memoization : bool,
+    ) {
+        let now = Instant::now();
+        self.use_memoization = use_memoization;
+        self.start = now;
+        self.last_report = now;
+        self.counters = ProgressCounters::default();
+        self.counters_at_last_report = ProgressCounters::default();
+        self.frontier_depths.clear();
+        self.last_new_node_id = None;
+    }
+
+    fn log_new_node(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        new_node_id : u32,
+        _new_node : &Conf::DomainSpecificNode
+    ) {
+        self.counters.num_nodes += 1;
+        // the depth of the new node is known once the step that leads to it is notified (if it is not the initial node)
+        self.frontier_depths.insert(new_node_id, 0);
+        self.last_new_node_id = Some(new_node_id);
+        self.report_if_due();
+    }
+
+    fn log_new_step(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        origin_node_id : u32,
+        _step : &Conf::DomainSpecificStep,
+        target_node_id : u32,
+        _target_node : &Conf::DomainSpecificNode
+    ) {
+        self.counters.num_steps += 1;
+        if self.last_new_node_id == Some(target_node_id) {
+            if let Some(origin_depth) = self.frontier_depths.get(&origin_node_id).copied() {
+                self.frontier_depths.insert(target_node_id, origin_depth + 1);
+            }
+            self.last_new_node_id = None;
+        }
+        self.report_if_due();
+    }
+
+    fn log_memo_hit(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _origin_node_id : u32,
+        _step : &Conf::DomainSpecificStep,
+        _target_node_id : u32
+    ) {
+        self.counters.num_memo_hits += 1;
+    }
+
+    fn log_memo_size(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        num_memoized_nodes : usize
+    ) {
+        self.counters.memo_size = num_memoized_nodes;
+    }
+
+    fn log_notify_last_child_step_of_node_processed(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32
+    ) {
+        self.frontier_depths.remove(&parent_node_id);
+    }
+
+    fn log_notify_node_without_children(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        node_id : u32
+    ) {
+        self.frontier_depths.remove(&node_id);
+    }
+
+    fn log_filtered(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _parent_node_id : u32,
+        _filtration_result_id : u32,
+        _filtration_result : &Conf::FiltrationResult
+    ) {
+        self.counters.num_filtrations += 1;
+    }
+
+    fn log_terminate_process(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {
+        let elapsed = self.start.elapsed();
+        let seconds = elapsed.as_secs_f64().max(f64::EPSILON);
+        let mut line = format!(
+            "terminated after {:.3}s : {} nodes ({:.1}/s), {} steps ({:.1}/s), {} filtered",
+            elapsed.as_secs_f64(),
+            self.counters.num_nodes,
+            self.counters.num_nodes as f64 / seconds,
+            self.counters.num_steps,
+            self.counters.num_steps as f64 / seconds,
+            self.counters.num_filtrations
+        );
+        if self.use_memoization {
+            line.push_str(&format!(", {} memoized nodes, {} memo hits", self.counters.memo_size, self.counters.num_memo_hits));
+        }
+        let _ = writeln!(self.sink, "{}", line);
+        let _ = self.sink.flush();
+    }
+
+}
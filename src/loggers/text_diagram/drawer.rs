@@ -0,0 +1,72 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::io::{self, Write};
+
+use crate::loggers::explored_graph::graph::{ExploredGraph, ExploredGraphEdgeKind};
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::rendering::AbstractProcessLabeller;
+
+use super::syntax::{truncate_label, TextDiagramSyntax};
+
+
+
+/** 
+ * Draws an [ExploredGraph](ExploredGraph) into a sink as a Mermaid flowchart or as a PlantUML state diagram.
+ * 
+ * Labels are given by the "labeller" and are shortened to "max_label_length" characters if it is set.
+ * Nodes whose domain-specific nodes have not been kept in the graph are labelled with their identifiers.
+ * Terminal nodes (i.e., nodes without children that have not been filtered) are drawn with a bold outline.
+ * Memo back-edges are dashed and filtered branches lead to distinctly styled filtration results
+ * (see [TextDiagramSyntax](TextDiagramSyntax)).
+ * **/
+pub fn draw_explored_graph<Conf, Labeller, Sink>(
+    graph : &ExploredGraph<Conf>,
+    context_and_param : &Conf::ContextAndParameterization,
+    labeller : &Labeller,
+    syntax : &TextDiagramSyntax,
+    max_label_length : Option<usize>,
+    sink : &mut Sink
+) -> io::Result<()>
+    where 
+        Conf : AbstractProcessConfiguration,
+        Labeller : AbstractProcessLabeller<Conf>,
+        Sink : Write
+{
+    syntax.write_header(sink)?;
+    for graph_node in graph.nodes.values() {
+        let label = match &graph_node.node {
+            Some(node) => labeller.get_node_label(context_and_param, node),
+            None => graph_node.node_id.to_string()
+        };
+        let is_initial = graph_node.discovery_order == 0;
+        syntax.write_node(sink, graph_node.node_id, &truncate_label(&label, max_label_length), is_initial, graph_node.is_terminal)?;
+    }
+    for filtration in &graph.filtrations {
+        let label = labeller.get_filtration_result_label(context_and_param, &filtration.filtration_result);
+        syntax.write_filtration_result(sink, filtration.filtration_result_id, &truncate_label(&label, max_label_length))?;
+    }
+    for edge in &graph.edges {
+        let label = labeller.get_step_label(context_and_param, &edge.step);
+        let is_memo_hit = edge.kind == ExploredGraphEdgeKind::MemoHit;
+        syntax.write_step(sink, edge.origin_node_id, edge.target_node_id, &truncate_label(&label, max_label_length), is_memo_hit)?;
+    }
+    for filtration in &graph.filtrations {
+        syntax.write_filtered_branch(sink, filtration.node_id, filtration.filtration_result_id)?;
+    }
+    syntax.write_footer(sink)?;
+    sink.flush()
+}
@@ -0,0 +1,94 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::PathBuf;
+
+use crate::loggers::explored_graph::graph::ExploredGraph;
+use crate::loggers::explored_graph::rendering::{AbstractExploredGraphRenderer, ExploredGraphRenderingLogger};
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::rendering::AbstractProcessLabeller;
+
+use super::drawer::draw_explored_graph;
+use super::syntax::TextDiagramSyntax;
+
+
+
+/** 
+ * Renders the explored graph as a Mermaid flowchart or as a PlantUML state diagram at "output_file_path",
+ * as described in [draw_explored_graph](draw_explored_graph), with the "labeller", 
+ * labels being shortened to "max_label_length" characters if it is set.
+ * **/
+pub struct TextDiagramRenderer<Labeller> {
+    pub output_file_path : PathBuf,
+    pub labeller : Labeller,
+    pub syntax : TextDiagramSyntax,
+    pub max_label_length : Option<usize>
+}
+
+impl<Labeller> TextDiagramRenderer<Labeller> {
+
+    pub fn new(
+        output_file_path : PathBuf,
+        labeller : Labeller,
+        syntax : TextDiagramSyntax,
+        max_label_length : Option<usize>
+    ) -> Self {
+        Self { 
+            output_file_path, 
+            labeller, 
+            syntax, 
+            max_label_length 
+        }
+    }
+
+}
+
+impl<Conf, Labeller> AbstractExploredGraphRenderer<Conf> for TextDiagramRenderer<Labeller> 
+    where 
+        Conf : AbstractProcessConfiguration,
+        Labeller : AbstractProcessLabeller<Conf>
+{
+
+    fn render(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        graph : &ExploredGraph<Conf>
+    ) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&self.output_file_path)?);
+        draw_explored_graph(
+            graph,
+            context_and_param,
+            &self.labeller,
+            &self.syntax,
+            self.max_label_length,
+            &mut writer
+        )
+    }
+
+}
+
+
+
+/** 
+ * Logger that draws the explored graph as a Mermaid flowchart or as a PlantUML state diagram,
+ * which is meant for small graphs that are embedded into documentation.
+ * 
+ * The diagram is (re)written each time the process terminates
+ * so that, if the process resumes from its unexplored frontier, it covers all the passes.
+ * **/
+pub type TextDiagramProcessLogger<Conf,Labeller> = ExploredGraphRenderingLogger<Conf,TextDiagramRenderer<Labeller>>;
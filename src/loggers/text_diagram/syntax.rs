@@ -0,0 +1,188 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::io::{self, Write};
+
+
+
+/** 
+ * The textual diagram languages in which small explored graphs can be embedded into documentation (e.g., markdown).
+ * - "Mermaid" yields a "flowchart"
+ * - "PlantUml" yields a state diagram
+ * **/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextDiagramSyntax {
+    Mermaid,
+    PlantUml
+}
+
+
+
+/** 
+ * Shortens a label to at most "max_length" characters (the last of which being an ellipsis).
+ * **/
+pub fn truncate_label(label : &str, max_length : Option<usize>) -> String {
+    match max_length {
+        Some(max_length) if label.chars().count() > max_length => {
+            let mut truncated : String = label.chars().take(max_length.saturating_sub(1)).collect();
+            truncated.push('…');
+            truncated
+        },
+        _ => label.to_string()
+    }
+}
+
+fn escape_mermaid_string(raw : &str) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        match c {
+            '"' => escaped.push_str("#quot;"),
+            '<' => escaped.push_str("#lt;"),
+            '>' => escaped.push_str("#gt;"),
+            '\n' => escaped.push_str("<br>"),
+            _ => escaped.push(c)
+        }
+    }
+    escaped
+}
+
+fn escape_plantuml_string(raw : &str) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        match c {
+            '"' => escaped.push('\''),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c)
+        }
+    }
+    escaped
+}
+
+
+
+/** 
+ * Writes the parts of a textual diagram.
+ * 
+ * Nodes are identified as "n{id}" and filtration results as "f{id}".
+ * Memo back-edges (i.e., steps leading to already known nodes) are dashed,
+ * and filtered branches are drawn as crossed (Mermaid) or red dotted (PlantUML) edges
+ * towards a distinctly styled element holding the filtration result.
+ * **/
+impl TextDiagramSyntax {
+
+    pub fn write_header(&self, writer : &mut dyn Write) -> io::Result<()> {
+        match self {
+            TextDiagramSyntax::Mermaid => {
+                writeln!(writer, "flowchart TD")?;
+                writeln!(writer, "classDef terminal stroke-width:3px")?;
+                writeln!(writer, "classDef filtered fill:#fdd,stroke:#c00,color:#900")
+            },
+            TextDiagramSyntax::PlantUml => {
+                writeln!(writer, "@startuml")?;
+                writeln!(writer, "hide empty description")
+            }
+        }
+    }
+
+    pub fn write_node(
+        &self, 
+        writer : &mut dyn Write, 
+        node_id : u32, 
+        label : &str, 
+        is_initial : bool, 
+        is_terminal : bool
+    ) -> io::Result<()> {
+        match self {
+            TextDiagramSyntax::Mermaid => {
+                let class = if is_terminal { ":::terminal" } else { "" };
+                writeln!(writer, "n{}[\"{}\"]{}", node_id, escape_mermaid_string(label), class)
+            },
+            TextDiagramSyntax::PlantUml => {
+                let line_style = if is_terminal { " ##[bold]" } else { "" };
+                writeln!(writer, "state \"{}\" as n{}{}", escape_plantuml_string(label), node_id, line_style)?;
+                if is_initial {
+                    writeln!(writer, "[*] --> n{}", node_id)?;
+                }
+                if is_terminal {
+                    writeln!(writer, "n{} --> [*]", node_id)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub fn write_filtration_result(
+        &self, 
+        writer : &mut dyn Write, 
+        filtration_result_id : u32, 
+        label : &str
+    ) -> io::Result<()> {
+        match self {
+            TextDiagramSyntax::Mermaid => {
+                writeln!(writer, "f{}{{{{\"{}\"}}}}:::filtered", filtration_result_id, escape_mermaid_string(label))
+            },
+            TextDiagramSyntax::PlantUml => {
+                writeln!(writer, "state \"{}\" as f{} #FFDDDD;line:CC0000", escape_plantuml_string(label), filtration_result_id)
+            }
+        }
+    }
+
+    pub fn write_step(
+        &self, 
+        writer : &mut dyn Write, 
+        origin_node_id : u32, 
+        target_node_id : u32, 
+        label : &str, 
+        is_memo_hit : bool
+    ) -> io::Result<()> {
+        match self {
+            TextDiagramSyntax::Mermaid => {
+                let arrow = if is_memo_hit { "-.->" } else { "-->" };
+                writeln!(writer, "n{} {}|\"{}\"| n{}", origin_node_id, arrow, escape_mermaid_string(label), target_node_id)
+            },
+            TextDiagramSyntax::PlantUml => {
+                let arrow = if is_memo_hit { "-[dashed]->" } else { "-->" };
+                writeln!(writer, "n{} {} n{} : {}", origin_node_id, arrow, target_node_id, escape_plantuml_string(label))
+            }
+        }
+    }
+
+    pub fn write_filtered_branch(
+        &self, 
+        writer : &mut dyn Write, 
+        parent_node_id : u32, 
+        filtration_result_id : u32
+    ) -> io::Result<()> {
+        match self {
+            TextDiagramSyntax::Mermaid => {
+                writeln!(writer, "n{} --x f{}", parent_node_id, filtration_result_id)
+            },
+            TextDiagramSyntax::PlantUml => {
+                writeln!(writer, "n{} -[#CC0000,dotted]-> f{}", parent_node_id, filtration_result_id)
+            }
+        }
+    }
+
+    pub fn write_footer(&self, writer : &mut dyn Write) -> io::Result<()> {
+        match self {
+            TextDiagramSyntax::Mermaid => Ok(()),
+            TextDiagramSyntax::PlantUml => {
+                writeln!(writer, "@enduml")
+            }
+        }
+    }
+
+}
@@ -0,0 +1,196 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::HashSet;
+use std::io::{self, Write};
+
+use crate::loggers::explored_graph::graph::{ExploredGraph, ExploredGraphEdgeKind};
+use crate::loggers::explored_graph::rendering::{AbstractExploredGraphRenderer, ExploredGraphRenderingLogger, ExploredGraphUpdate};
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::rendering::AbstractProcessLabeller;
+use crate::queue::strategy::QueueSearchStrategy;
+
+use super::options::TreePrinterOptions;
+use super::renderer::{render_explored_graph_as_tree, TreeEntry};
+
+
+
+/** 
+ * Renders the explored graph into a sink as an indented tree (in the manner of the "tree" command), 
+ * as described in [render_explored_graph_as_tree](render_explored_graph_as_tree), with nodes and steps labelled by the "labeller".
+ * 
+ * With a depth first search, the tree is printed as the exploration unfolds.
+ * Because it is not known yet whether a child is the last of its parent, all children are then drawn as branches
+ * and chains of nodes are not collapsed.
+ * If the process resumes from its unexplored frontier, the entries of the following passes are printed after those of the previous ones.
+ * With any other strategy, the tree is printed once the process terminates
+ * (and printed again, covering all the passes, each time it terminates after a resumption).
+ * **/
+pub struct TreePrinterRenderer<Sink,Labeller> {
+    pub sink : Sink,
+    pub labeller : Labeller,
+    pub options : TreePrinterOptions,
+    prints_as_explored : bool,
+    /// nodes below which an ellipsis has been printed because of the maximum depth (when printing as explored)
+    elided_parents : HashSet<u32>
+}
+
+impl<Labeller> TreePrinterRenderer<io::Stdout,Labeller> {
+
+    pub fn to_stdout(labeller : Labeller, options : TreePrinterOptions) -> Self {
+        Self::new(io::stdout(), labeller, options)
+    }
+
+}
+
+impl<Sink : Write,Labeller> TreePrinterRenderer<Sink,Labeller> {
+
+    pub fn new(sink : Sink, labeller : Labeller, options : TreePrinterOptions) -> Self {
+        Self { 
+            sink, 
+            labeller, 
+            options, 
+            prints_as_explored : false, 
+            elided_parents : HashSet::new() 
+        }
+    }
+
+    /** 
+     * Prints an entry as soon as it is notified (when printing as explored).
+     * **/
+    fn print_entry_as_explored<Conf : AbstractProcessConfiguration>(
+        &mut self, 
+        graph : &ExploredGraph<Conf>,
+        parent_node_id : u32, 
+        entry : TreeEntry, 
+        node_label : Option<&str>
+    ) -> io::Result<()> {
+        let depth = match graph.get_node(parent_node_id) {
+            Some(parent) => parent.depth + 1,
+            None => {
+                return Ok(());
+            }
+        };
+        let prefix = format!(
+            "{}{}",
+            self.options.glyphs.vertical().repeat(depth as usize - 1),
+            self.options.glyphs.branch()
+        );
+        if self.options.is_printed_depth(depth) {
+            writeln!(self.sink, "{}{}", prefix, entry.get_text(&self.options.glyphs, node_label))?;
+        } else if self.options.is_printed_depth(depth - 1) && self.elided_parents.insert(parent_node_id) {
+            writeln!(self.sink, "{}{}", prefix, self.options.glyphs.ellipsis())?;
+        }
+        Ok(())
+    }
+
+}
+
+impl<Conf, Sink, Labeller> AbstractExploredGraphRenderer<Conf> for TreePrinterRenderer<Sink,Labeller> 
+    where 
+        Conf : AbstractProcessConfiguration,
+        Sink : Write,
+        Labeller : AbstractProcessLabeller<Conf>
+{
+
+    fn on_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        strategy : &QueueSearchStrategy
+    ) {
+        self.prints_as_explored = matches!(strategy, QueueSearchStrategy::DFS);
+        self.elided_parents.clear();
+    }
+
+    fn on_graph_updated(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        graph : &ExploredGraph<Conf>,
+        update : ExploredGraphUpdate
+    ) -> io::Result<()> {
+        if !self.prints_as_explored {
+            return Ok(());
+        }
+        match update {
+            ExploredGraphUpdate::Node(node_id) => {
+                // the other nodes are printed along with the steps that lead to them
+                let graph_node = match graph.get_node(node_id) {
+                    Some(graph_node) if graph_node.discovery_order == 0 => graph_node,
+                    _ => {
+                        return Ok(());
+                    }
+                };
+                let label = match &graph_node.node {
+                    Some(node) => self.labeller.get_node_label(context_and_param, node),
+                    None => node_id.to_string()
+                };
+                writeln!(self.sink, "{} (n{})", label, node_id)
+            },
+            ExploredGraphUpdate::Edge(edge_index) => {
+                let edge = &graph.edges[edge_index];
+                let step_label = self.labeller.get_step_label(context_and_param, &edge.step);
+                match edge.kind {
+                    ExploredGraphEdgeKind::Discovery => {
+                        let node_label = match graph.get_node(edge.target_node_id).and_then(|x| x.node.as_ref()) {
+                            Some(node) => self.labeller.get_node_label(context_and_param, node),
+                            None => edge.target_node_id.to_string()
+                        };
+                        let entry = TreeEntry::Discovery(step_label, edge.target_node_id);
+                        self.print_entry_as_explored(graph, edge.origin_node_id, entry, Some(&node_label))
+                    },
+                    ExploredGraphEdgeKind::MemoHit if self.options.mark_memo_revisits => {
+                        let entry = TreeEntry::Revisit(step_label, edge.target_node_id);
+                        self.print_entry_as_explored(graph, edge.origin_node_id, entry, None)
+                    },
+                    ExploredGraphEdgeKind::MemoHit => {
+                        Ok(())
+                    }
+                }
+            },
+            ExploredGraphUpdate::Filtration(filtration_index) => {
+                let filtration = &graph.filtrations[filtration_index];
+                let entry = TreeEntry::Filtration(self.labeller.get_filtration_result_label(context_and_param, &filtration.filtration_result));
+                self.print_entry_as_explored(graph, filtration.node_id, entry, None)
+            },
+            ExploredGraphUpdate::ChildrenProcessed(node_id) => {
+                self.elided_parents.remove(&node_id);
+                Ok(())
+            }
+        }
+    }
+
+    fn render(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        graph : &ExploredGraph<Conf>
+    ) -> io::Result<()> {
+        if !self.prints_as_explored {
+            let lines = render_explored_graph_as_tree(graph, context_and_param, &self.labeller, &self.options);
+            for line in lines {
+                writeln!(self.sink, "{}", line)?;
+            }
+        }
+        self.sink.flush()
+    }
+
+}
+
+
+
+/** 
+ * Logger that prints the exploration as an indented tree (see [TreePrinterRenderer](TreePrinterRenderer)).
+ * **/
+pub type TreePrinterLogger<Conf,Sink,Labeller> = ExploredGraphRenderingLogger<Conf,TreePrinterRenderer<Sink,Labeller>>;
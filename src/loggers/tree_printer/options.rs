@@ -0,0 +1,131 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+/** 
+ * The characters with which trees are drawn.
+ * **/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TreeGlyphs {
+    Ascii,
+    Unicode
+}
+
+impl TreeGlyphs {
+
+    pub fn branch(&self) -> &'static str {
+        match self {
+            TreeGlyphs::Ascii => "|-- ",
+            TreeGlyphs::Unicode => "├── "
+        }
+    }
+
+    pub fn last_branch(&self) -> &'static str {
+        match self {
+            TreeGlyphs::Ascii => "`-- ",
+            TreeGlyphs::Unicode => "└── "
+        }
+    }
+
+    pub fn vertical(&self) -> &'static str {
+        match self {
+            TreeGlyphs::Ascii => "|   ",
+            TreeGlyphs::Unicode => "│   "
+        }
+    }
+
+    pub fn blank(&self) -> &'static str {
+        "    "
+    }
+
+    pub fn arrow(&self) -> &'static str {
+        match self {
+            TreeGlyphs::Ascii => "->",
+            TreeGlyphs::Unicode => "→"
+        }
+    }
+
+    pub fn revisit(&self) -> &'static str {
+        match self {
+            TreeGlyphs::Ascii => "(revisit)",
+            TreeGlyphs::Unicode => "↺"
+        }
+    }
+
+    pub fn filtered(&self) -> &'static str {
+        match self {
+            TreeGlyphs::Ascii => "x",
+            TreeGlyphs::Unicode => "✗"
+        }
+    }
+
+    pub fn ellipsis(&self) -> &'static str {
+        match self {
+            TreeGlyphs::Ascii => "...",
+            TreeGlyphs::Unicode => "…"
+        }
+    }
+
+}
+
+
+
+/** 
+ * Options of the [TreePrinterLogger](crate::loggers::tree_printer::logger::TreePrinterLogger).
+ * 
+ * - "max_printed_depth": nodes deeper than this are not printed (an ellipsis marks where the tree is cut)
+ * - "collapse_chains_longer_than": chains of nodes with a single child which are longer than this
+ *   are printed on a single line, with only their first and last nodes (hence only chains of at least 3 nodes are collapsed)
+ * - "mark_memo_revisits": if true, steps leading to already known (memoized) nodes are printed
+ *   with a revisit mark, otherwise they are not printed
+ * - "glyphs": the characters with which the tree is drawn
+ * **/
+pub struct TreePrinterOptions {
+    pub max_printed_depth : Option<u32>,
+    pub collapse_chains_longer_than : Option<usize>,
+    pub mark_memo_revisits : bool,
+    pub glyphs : TreeGlyphs
+}
+
+impl TreePrinterOptions {
+    pub fn new(
+        max_printed_depth : Option<u32>,
+        collapse_chains_longer_than : Option<usize>,
+        mark_memo_revisits : bool,
+        glyphs : TreeGlyphs
+    ) -> Self {
+        Self { 
+            max_printed_depth, 
+            collapse_chains_longer_than, 
+            mark_memo_revisits, 
+            glyphs 
+        }
+    }
+
+    pub fn is_printed_depth(&self, depth : u32) -> bool {
+        match self.max_printed_depth {
+            Some(max_depth) => depth <= max_depth,
+            None => true
+        }
+    }
+}
+
+impl std::default::Default for TreePrinterOptions {
+    fn default() -> Self {
+        Self::new(None, None, true, TreeGlyphs::Unicode)
+    }
+}
@@ -0,0 +1,229 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::HashMap;
+
+use crate::loggers::explored_graph::graph::{ExploredGraph, ExploredGraphEdgeKind};
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::rendering::AbstractProcessLabeller;
+
+use super::options::{TreeGlyphs, TreePrinterOptions};
+
+
+
+/** 
+ * What is printed below a node of the tree.
+ * **/
+pub(crate) enum TreeEntry {
+    /// a step leading to a newly reached node
+    Discovery(String,u32),
+    /// a step leading to an already known (memoized) node
+    Revisit(String,u32),
+    /// a filtration result
+    Filtration(String)
+}
+
+impl TreeEntry {
+
+    pub(crate) fn get_text(&self, glyphs : &TreeGlyphs, node_label : Option<&str>) -> String {
+        match self {
+            TreeEntry::Discovery(step_label,node_id) => {
+                format!("{} {} {} (n{})", step_label, glyphs.arrow(), node_label.unwrap_or(""), node_id)
+            },
+            TreeEntry::Revisit(step_label,node_id) => {
+                format!("{} {} {} n{}", step_label, glyphs.arrow(), glyphs.revisit(), node_id)
+            },
+            TreeEntry::Filtration(label) => {
+                format!("{} {}", glyphs.filtered(), label)
+            }
+        }
+    }
+
+}
+
+enum TreeLine<'a> {
+    Entry(&'a TreeEntry),
+    Ellipsis
+}
+
+
+
+/** 
+ * The tree of the first discoveries of the nodes of an explored graph, with what is printed below each node.
+ * **/
+struct TreeRendering<'a> {
+    options : &'a TreePrinterOptions,
+    node_labels : HashMap<u32,String>,
+    children : HashMap<u32,Vec<TreeEntry>>
+}
+
+impl TreeRendering<'_> {
+
+    /** 
+     * Follows, from the entry of a newly reached node, the chain of nodes which have a single child.
+     * Returns the entry of the last node of the chain, its depth, and the number of nodes in the chain.
+     * **/
+    fn follow_chain<'a>(&'a self, entry : &'a TreeEntry, node_id : u32, depth : u32) -> (&'a TreeEntry,u32,usize) {
+        let (mut current_entry, mut current_id, mut current_depth, mut chain_length) = (entry, node_id, depth, 1);
+        while let Some([child_entry @ TreeEntry::Discovery(_,child_id)]) = self.children.get(&current_id).map(|x| x.as_slice()) {
+            if !self.options.is_printed_depth(current_depth + 1) {
+                break;
+            }
+            current_entry = child_entry;
+            current_id = *child_id;
+            current_depth += 1;
+            chain_length += 1;
+        }
+        (current_entry,current_depth,chain_length)
+    }
+
+    /** 
+     * Renders the tree below the root node.
+     * An explicit stack is used given that trees may be very deep.
+     * **/
+    fn render(&self, root_node_id : u32) -> Vec<String> {
+        let glyphs = &self.options.glyphs;
+        let root_label = match self.node_labels.get(&root_node_id) {
+            Some(label) => label.as_str(),
+            None => ""
+        };
+        let mut lines = vec![format!("{} (n{})", root_label, root_node_id)];
+        // each item is: the line, the prefix of the line, whether it is the last child and its depth
+        let mut stack : Vec<(TreeLine,String,bool,u32)> = vec![];
+        self.push_children_lines(&mut stack, root_node_id, 0, String::new());
+        while let Some((tree_line,prefix,is_last,depth)) = stack.pop() {
+            let branch = if is_last { glyphs.last_branch() } else { glyphs.branch() };
+            let entry = match tree_line {
+                TreeLine::Ellipsis => {
+                    lines.push(format!("{}{}{}", prefix, branch, glyphs.ellipsis()));
+                    continue;
+                },
+                TreeLine::Entry(entry) => entry
+            };
+            let child_prefix = format!("{}{}", prefix, if is_last { glyphs.blank() } else { glyphs.vertical() });
+            if let TreeEntry::Discovery(_,node_id) = entry {
+                let mut text = entry.get_text(glyphs, self.node_labels.get(node_id).map(|x| x.as_str()));
+                let (mut last_node_id, mut last_depth) = (*node_id, depth);
+                if let Some(max_chain_length) = self.options.collapse_chains_longer_than {
+                    let (chain_end_entry,chain_end_depth,chain_length) = self.follow_chain(entry, *node_id, depth);
+                    // the first and last nodes of a collapsed chain are printed so there must be at least one node in between
+                    let collapses = chain_length > max_chain_length.max(2);
+                    if let (true, TreeEntry::Discovery(_,chain_end_id)) = (collapses, chain_end_entry) {
+                        text = format!(
+                            "{} {} {} collapsed {} {}",
+                            text,
+                            glyphs.ellipsis(),
+                            chain_length - 2,
+                            glyphs.ellipsis(),
+                            chain_end_entry.get_text(glyphs, self.node_labels.get(chain_end_id).map(|x| x.as_str()))
+                        );
+                        last_node_id = *chain_end_id;
+                        last_depth = chain_end_depth;
+                    }
+                }
+                lines.push(format!("{}{}{}", prefix, branch, text));
+                self.push_children_lines(&mut stack, last_node_id, last_depth, child_prefix);
+            } else {
+                lines.push(format!("{}{}{}", prefix, branch, entry.get_text(glyphs, None)));
+            }
+        }
+        lines
+    }
+
+    fn push_children_lines<'a>(
+        &'a self, 
+        stack : &mut Vec<(TreeLine<'a>,String,bool,u32)>, 
+        node_id : u32, 
+        depth : u32, 
+        prefix : String
+    ) {
+        let children = match self.children.get(&node_id) {
+            Some(children) if !children.is_empty() => children,
+            _ => {
+                return;
+            }
+        };
+        if !self.options.is_printed_depth(depth + 1) {
+            stack.push((TreeLine::Ellipsis, prefix, true, depth + 1));
+            return;
+        }
+        // pushed in reverse order so that the first child is printed first
+        for (index,entry) in children.iter().enumerate().rev() {
+            stack.push((TreeLine::Entry(entry), prefix.clone(), index == children.len() - 1, depth + 1));
+        }
+    }
+
+}
+
+
+
+/** 
+ * Renders an [ExploredGraph](ExploredGraph) as an indented tree (in the manner of the "tree" command), 
+ * with nodes and steps labelled by the "labeller", and returns its lines.
+ * 
+ * Each node is printed below the node from which it was first reached, as "step → node (n{id})".
+ * Below each node, the steps fired from it are printed (in the order in which they have been fired), followed by its filtration results.
+ * Memo revisits may be printed (see [TreePrinterOptions](TreePrinterOptions)).
+ * Nodes whose domain-specific nodes have not been kept in the graph are labelled with their identifiers.
+ * **/
+pub fn render_explored_graph_as_tree<Conf, Labeller>(
+    graph : &ExploredGraph<Conf>,
+    context_and_param : &Conf::ContextAndParameterization,
+    labeller : &Labeller,
+    options : &TreePrinterOptions
+) -> Vec<String>
+    where 
+        Conf : AbstractProcessConfiguration,
+        Labeller : AbstractProcessLabeller<Conf>
+{
+    let root_node_id = match graph.get_initial_node() {
+        Some(root_node) => root_node.node_id,
+        None => {
+            return vec![];
+        }
+    };
+    let mut rendering = TreeRendering {
+        options,
+        node_labels : HashMap::new(),
+        children : HashMap::new()
+    };
+    for graph_node in graph.nodes.values() {
+        let label = match &graph_node.node {
+            Some(node) => labeller.get_node_label(context_and_param, node),
+            None => graph_node.node_id.to_string()
+        };
+        rendering.node_labels.insert(graph_node.node_id, label);
+    }
+    for edge in &graph.edges {
+        let entry = match edge.kind {
+            ExploredGraphEdgeKind::Discovery => {
+                TreeEntry::Discovery(labeller.get_step_label(context_and_param, &edge.step), edge.target_node_id)
+            },
+            ExploredGraphEdgeKind::MemoHit if options.mark_memo_revisits => {
+                TreeEntry::Revisit(labeller.get_step_label(context_and_param, &edge.step), edge.target_node_id)
+            },
+            ExploredGraphEdgeKind::MemoHit => {
+                continue;
+            }
+        };
+        rendering.children.entry(edge.origin_node_id).or_default().push(entry);
+    }
+    for filtration in &graph.filtrations {
+        let entry = TreeEntry::Filtration(labeller.get_filtration_result_label(context_and_param, &filtration.filtration_result));
+        rendering.children.entry(filtration.node_id).or_default().push(entry);
+    }
+    rendering.render(root_node_id)
+}
@@ -65,16 +65,20 @@ use super::{handler::AbstractAlgorithmOperationHandler, persistent_state::Abstra
  * The process has a global state that may evolve as the graph structure is evolved.
  * This is represented by the "MutablePersistentState" associated type.
  * 
- * We consider three kinds of filters:
+ * We consider four kinds of filters:
  * - NodesPreFilters, which evaluate newly encountered nodes
  * - NodesPostFilters, which evalute the set of steps that may be taken from a newly encountered node
  * - StepsFilters, which evalute individual steps
+ * - EdgeFilters, which evaluate individual transitions i.e., a step together with the node it yields
  * 
  * Any of these filters may be used to stop/prevent/preempt the exploration of parts of the graph structure.
  * These filters return an Option<FiltrationResult>:
  * - if is is None, then the node or step is evaluated normally
  * - if is is Some(x), then the process do not explore the successors of the filtered node/step
  *   and x is further used to change the global state and notify loggers
+ * 
+ * Filters may also be made to defer what they filter, which is then kept in an unexplored frontier
+ * from which the exploration can later be resumed.
  * **/
 pub trait AbstractProcessConfiguration : Sized {
     // ***
@@ -97,4 +101,16 @@ pub trait AbstractNodeKind : Sized + Clone + PartialEq + Eq + Hash {
 
     fn is_included_for_memoization(&self, memoized_node : &Self) -> bool;
 
+    /** 
+     * Returns a measure of the progress of the process upon reaching this node 
+     * (e.g., the number of consumed events in runtime verification).
+     * 
+     * This measure must never decrease from a node to its successors.
+     * It is used for the garbage collection of the memo with the sweep-line method.
+     * By default, all nodes have the same progress measure.
+     * **/
+    fn get_progress_measure(&self) -> u64 {
+        0
+    }
+
 }
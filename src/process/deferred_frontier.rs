@@ -0,0 +1,133 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::fmt;
+
+use super::config::AbstractProcessConfiguration;
+
+
+
+/** 
+ * A node that has been filtered by deferring node (pre or post) filters.
+ * It has been reached (and is known to the loggers under "node_id")
+ * but the steps that may be fired from it have not been explored.
+ * 
+ * If it has been deferred by node post filters, the steps that have been collected from it are kept,
+ * so that, upon resuming, they are not collected again (and the global state is not updated twice) :
+ * only the node post filters are applied again.
+ * Otherwise, upon resuming, the node is processed anew, starting with the node pre filters.
+ * **/
+pub struct DeferredNode<Conf : AbstractProcessConfiguration> {
+    pub node_id : u32,
+    pub node : Conf::DomainSpecificNode,
+    pub depth : u32,
+    pub next_steps : Option<Vec<Conf::DomainSpecificStep>>
+}
+
+
+
+/** 
+ * A step that has been filtered by deferring step or edge filters.
+ * 
+ * If it has been deferred by step filters, it has not been fired from its parent node
+ * and, upon resuming, it is processed anew, starting with the step filters.
+ * 
+ * If it has been deferred by edge filters, it has already been fired
+ * (and may have been modified by "process_new_step") and the child node it yielded is kept.
+ * Upon resuming, it is not fired again : only the edge filters are applied again on that same transition.
+ * **/
+pub struct DeferredStep<Conf : AbstractProcessConfiguration> {
+    pub parent_node_id : u32,
+    pub parent_node : Conf::DomainSpecificNode,
+    pub parent_depth : u32,
+    pub step : Conf::DomainSpecificStep,
+    pub child_node : Option<Conf::DomainSpecificNode>
+}
+
+
+
+/** 
+ * The unexplored frontier of the process, which consists of the nodes and steps
+ * that have been deferred by filters instead of being permanently dropped.
+ * 
+ * Exploration can later be resumed from this frontier (see "resume_from_deferred_frontier" in [GenericProcessManager](crate::process::manager::GenericProcessManager)),
+ * for instance after having relaxed or disabled some filters.
+ * **/
+pub struct DeferredFrontier<Conf : AbstractProcessConfiguration> {
+    pub nodes : Vec<DeferredNode<Conf>>,
+    pub steps : Vec<DeferredStep<Conf>>
+}
+
+impl<Conf : AbstractProcessConfiguration> Default for DeferredFrontier<Conf> {
+    fn default() -> Self {
+        Self { 
+            nodes : vec![], 
+            steps : vec![] 
+        }
+    }
+}
+
+impl<Conf : AbstractProcessConfiguration> DeferredFrontier<Conf> {
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty() && self.steps.is_empty()
+    }
+
+    pub fn get_report(&self) -> UnexploredFrontierReport {
+        let depths = self.nodes.iter().map(|x| x.depth)
+            .chain(self.steps.iter().map(|x| x.parent_depth + 1));
+        let min_depth = depths.clone().min();
+        let max_depth = depths.max();
+        UnexploredFrontierReport { 
+            num_deferred_nodes : self.nodes.len(), 
+            num_deferred_steps : self.steps.len(), 
+            min_depth, 
+            max_depth 
+        }
+    }
+
+}
+
+
+
+/** 
+ * Summarizes the unexplored frontier of the process.
+ * The depths are those at which exploration would resume
+ * (i.e., that of the deferred nodes and that of the nodes the deferred steps would reach).
+ * **/
+pub struct UnexploredFrontierReport {
+    pub num_deferred_nodes : usize,
+    pub num_deferred_steps : usize,
+    pub min_depth : Option<u32>,
+    pub max_depth : Option<u32>
+}
+
+impl fmt::Display for UnexploredFrontierReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.num_deferred_nodes == 0 && self.num_deferred_steps == 0 {
+            return write!(f,"no unexplored frontier");
+        }
+        write!(f,
+            "unexplored frontier of {} deferred nodes and {} deferred steps", 
+            self.num_deferred_nodes, 
+            self.num_deferred_steps
+        )?;
+        if let (Some(min_depth),Some(max_depth)) = (self.min_depth,self.max_depth) {
+            write!(f," at depths {} to {}", min_depth, max_depth)?;
+        }
+        Ok(())
+    }
+}
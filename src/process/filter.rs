@@ -15,26 +15,73 @@ limitations under the License.
 */
 
 use std::any::Any;
+use std::time::Instant;
 
 use super::config::AbstractProcessConfiguration;
+use super::filter_statistics::{FilterActivationStatistics, FilterKind, ManagedFilterInfo};
 
 
 
 /** 
  * Filter than can be applied upon reaching a new node in the graph,
  * before computing the steps that can be fired from it.
+ * The depth of the node is also provided (the initial node having depth 0).
  * **/
 pub trait AbstractNodePreFilter<Conf : AbstractProcessConfiguration> {
 
     fn as_any(&self) -> &dyn Any;
 
+    /** 
+     * Describes the filter, e.g., so that loggers may print the active filters.
+     * By default, this is the name of the type of the filter.
+     * **/
+    fn describe(&self) -> String {
+        std::any::type_name::<Self>().to_string()
+    }
+
     fn apply_filter(
-        &self,
+        &mut self,
         context_and_param : &Conf::ContextAndParameterization,
         global_state : &Conf::MutablePersistentState,
-        node : &Conf::DomainSpecificNode
+        node : &Conf::DomainSpecificNode,
+        depth : u32
     ) -> Option<Conf::FiltrationResult>;
 
+    /** 
+     * Called when the process starts, before the filter is applied.
+     * Stateful filters may (re)initialize their state here.
+     * By default, nothing is done.
+     * **/
+    fn on_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _initial_global_state : &Conf::MutablePersistentState
+    ) {}
+
+    /** 
+     * Called when the process resumes its exploration from its unexplored frontier
+     * (see "resume_from_deferred_frontier" in [GenericProcessManager](crate::process::manager::GenericProcessManager)).
+     * Unlike "on_initialize", this is not the start of a new process :
+     * stateful filters may keep their state so that it spans all the passes of the exploration.
+     * By default, nothing is done.
+     * **/
+    fn on_resume(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {}
+
+    /** 
+     * Called when the process terminates.
+     * Stateful filters may finalize the data they report here.
+     * By default, nothing is done.
+     * **/
+    fn on_terminate(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {}
+
 }
 
 
@@ -42,19 +89,55 @@ pub trait AbstractNodePreFilter<Conf : AbstractProcessConfiguration> {
 /** 
  * Filter than can be applied upon reaching a new node in the graph,
  * after computing the steps that can be fired from it.
+ * The depth of the node is also provided (the initial node having depth 0).
  * **/
  pub trait AbstractNodePostFilter<Conf : AbstractProcessConfiguration> {
 
     fn as_any(&self) -> &dyn Any;
 
+    /** 
+     * Describes the filter (see the same method of [AbstractNodePreFilter](AbstractNodePreFilter)).
+     * **/
+    fn describe(&self) -> String {
+        std::any::type_name::<Self>().to_string()
+    }
+
     fn apply_filter(
-        &self,
+        &mut self,
         context_and_param : &Conf::ContextAndParameterization,
         global_state : &Conf::MutablePersistentState,
         node : &Conf::DomainSpecificNode,
+        depth : u32,
         next_steps : &[Conf::DomainSpecificStep]
     ) -> Option<Conf::FiltrationResult>;
 
+    /** 
+     * Called when the process starts (see the same method of [AbstractNodePreFilter](AbstractNodePreFilter)).
+     * **/
+    fn on_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _initial_global_state : &Conf::MutablePersistentState
+    ) {}
+
+    /** 
+     * Called when the process resumes (see the same method of [AbstractNodePreFilter](AbstractNodePreFilter)).
+     * **/
+    fn on_resume(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {}
+
+    /** 
+     * Called when the process terminates (see the same method of [AbstractNodePreFilter](AbstractNodePreFilter)).
+     * **/
+    fn on_terminate(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {}
+
 }
 
 
@@ -62,29 +145,453 @@ pub trait AbstractNodePreFilter<Conf : AbstractProcessConfiguration> {
 
 /** 
  * Filter than can be applied on the evaluation of a specific step.
+ * The unique identifier and the depth of the node from which the step is fired are also provided.
+ * 
+ * Stateful step filters may keep data for each node (e.g., along the path that leads to it) 
+ * by relying on the unique identifiers of nodes and on the "on_step_to_new_node" and "on_node_children_processed" notifications.
  * **/
  pub trait AbstractStepFilter<Conf : AbstractProcessConfiguration> {
 
     fn as_any(&self) -> &dyn Any;
 
+    /** 
+     * Describes the filter (see the same method of [AbstractNodePreFilter](AbstractNodePreFilter)).
+     * **/
+    fn describe(&self) -> String {
+        std::any::type_name::<Self>().to_string()
+    }
+
     fn apply_filter(
-        &self,
+        &mut self,
         context_and_param : &Conf::ContextAndParameterization,
         global_state : &Conf::MutablePersistentState,
         parent_node : &Conf::DomainSpecificNode,
+        parent_node_id : u32,
+        parent_depth : u32,
         step : &Conf::DomainSpecificStep
     ) -> Option<Conf::FiltrationResult>;
 
+    /** 
+     * Called when a step that has not been filtered leads to a new node (i.e., not to an already memoized node).
+     * By default, nothing is done.
+     * **/
+    fn on_step_to_new_node(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _parent_node_id : u32,
+        _step : &Conf::DomainSpecificStep,
+        _new_node_id : u32
+    ) {}
+
+    /** 
+     * Called when no more steps are to be fired from a node, 
+     * i.e., when all its children have been processed, when it has none or when it has been filtered.
+     * This is not the case of nodes that have been deferred (or some steps of which have been deferred)
+     * until the exploration is resumed from them.
+     * Data that stateful filters keep for that node may then be freed.
+     * By default, nothing is done.
+     * **/
+    fn on_node_children_processed(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _node_id : u32
+    ) {}
+
+    /** 
+     * Called when the process starts (see the same method of [AbstractNodePreFilter](AbstractNodePreFilter)).
+     * **/
+    fn on_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _initial_global_state : &Conf::MutablePersistentState
+    ) {}
+
+    /** 
+     * Called when the process resumes (see the same method of [AbstractNodePreFilter](AbstractNodePreFilter)).
+     * **/
+    fn on_resume(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {}
+
+    /** 
+     * Called when the process terminates (see the same method of [AbstractNodePreFilter](AbstractNodePreFilter)).
+     * **/
+    fn on_terminate(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {}
+
+}
+
+
+
+
+
+/** 
+ * Filter than can be applied on a transition (an edge of the graph) once the step has been fired
+ * i.e., after the successor node has been computed and before it is memoized.
+ * The filter is thus provided with the parent node, the step and the child node,
+ * so that it can express conditions on the transition itself.
+ * The depth of the parent node is also provided.
+ * **/
+pub trait AbstractEdgeFilter<Conf : AbstractProcessConfiguration> {
+
+    fn as_any(&self) -> &dyn Any;
+
+    /** 
+     * Describes the filter (see the same method of [AbstractNodePreFilter](AbstractNodePreFilter)).
+     * **/
+    fn describe(&self) -> String {
+        std::any::type_name::<Self>().to_string()
+    }
+
+    fn apply_filter(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState,
+        parent_node : &Conf::DomainSpecificNode,
+        parent_depth : u32,
+        step : &Conf::DomainSpecificStep,
+        child_node : &Conf::DomainSpecificNode
+    ) -> Option<Conf::FiltrationResult>;
+
+    /** 
+     * Called when the process starts (see the same method of [AbstractNodePreFilter](AbstractNodePreFilter)).
+     * **/
+    fn on_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _initial_global_state : &Conf::MutablePersistentState
+    ) {}
+
+    /** 
+     * Called when the process resumes (see the same method of [AbstractNodePreFilter](AbstractNodePreFilter)).
+     * **/
+    fn on_resume(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {}
+
+    /** 
+     * Called when the process terminates (see the same method of [AbstractNodePreFilter](AbstractNodePreFilter)).
+     * **/
+    fn on_terminate(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {}
+
+}
+
+
+
+
+
+impl<Conf : AbstractProcessConfiguration> AbstractNodePreFilter<Conf> for Box<dyn AbstractNodePreFilter<Conf>> {
+
+    fn as_any(&self) -> &dyn Any {
+        (**self).as_any()
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+
+    fn apply_filter(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState,
+        node : &Conf::DomainSpecificNode,
+        depth : u32
+    ) -> Option<Conf::FiltrationResult> {
+        (**self).apply_filter(context_and_param,global_state,node,depth)
+    }
+
+    fn on_initialize(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        initial_global_state : &Conf::MutablePersistentState
+    ) {
+        (**self).on_initialize(context_and_param,initial_global_state)
+    }
+
+    fn on_resume(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState
+    ) {
+        (**self).on_resume(context_and_param,global_state)
+    }
+
+    fn on_terminate(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState
+    ) {
+        (**self).on_terminate(context_and_param,global_state)
+    }
+
+}
+
+impl<Conf : AbstractProcessConfiguration> AbstractNodePostFilter<Conf> for Box<dyn AbstractNodePostFilter<Conf>> {
+
+    fn as_any(&self) -> &dyn Any {
+        (**self).as_any()
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+
+    fn apply_filter(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState,
+        node : &Conf::DomainSpecificNode,
+        depth : u32,
+        next_steps : &[Conf::DomainSpecificStep]
+    ) -> Option<Conf::FiltrationResult> {
+        (**self).apply_filter(context_and_param,global_state,node,depth,next_steps)
+    }
+
+    fn on_initialize(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        initial_global_state : &Conf::MutablePersistentState
+    ) {
+        (**self).on_initialize(context_and_param,initial_global_state)
+    }
+
+    fn on_resume(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState
+    ) {
+        (**self).on_resume(context_and_param,global_state)
+    }
+
+    fn on_terminate(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState
+    ) {
+        (**self).on_terminate(context_and_param,global_state)
+    }
+
+}
+
+impl<Conf : AbstractProcessConfiguration> AbstractStepFilter<Conf> for Box<dyn AbstractStepFilter<Conf>> {
+
+    fn as_any(&self) -> &dyn Any {
+        (**self).as_any()
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+
+    fn apply_filter(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState,
+        parent_node : &Conf::DomainSpecificNode,
+        parent_node_id : u32,
+        parent_depth : u32,
+        step : &Conf::DomainSpecificStep
+    ) -> Option<Conf::FiltrationResult> {
+        (**self).apply_filter(context_and_param,global_state,parent_node,parent_node_id,parent_depth,step)
+    }
+
+    fn on_step_to_new_node(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        new_node_id : u32
+    ) {
+        (**self).on_step_to_new_node(context_and_param,parent_node_id,step,new_node_id)
+    }
+
+    fn on_node_children_processed(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        node_id : u32
+    ) {
+        (**self).on_node_children_processed(context_and_param,node_id)
+    }
+
+    fn on_initialize(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        initial_global_state : &Conf::MutablePersistentState
+    ) {
+        (**self).on_initialize(context_and_param,initial_global_state)
+    }
+
+    fn on_resume(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState
+    ) {
+        (**self).on_resume(context_and_param,global_state)
+    }
+
+    fn on_terminate(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState
+    ) {
+        (**self).on_terminate(context_and_param,global_state)
+    }
+
+}
+
+impl<Conf : AbstractProcessConfiguration> AbstractEdgeFilter<Conf> for Box<dyn AbstractEdgeFilter<Conf>> {
+
+    fn as_any(&self) -> &dyn Any {
+        (**self).as_any()
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+
+    fn apply_filter(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState,
+        parent_node : &Conf::DomainSpecificNode,
+        parent_depth : u32,
+        step : &Conf::DomainSpecificStep,
+        child_node : &Conf::DomainSpecificNode
+    ) -> Option<Conf::FiltrationResult> {
+        (**self).apply_filter(context_and_param,global_state,parent_node,parent_depth,step,child_node)
+    }
+
+    fn on_initialize(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        initial_global_state : &Conf::MutablePersistentState
+    ) {
+        (**self).on_initialize(context_and_param,initial_global_state)
+    }
+
+    fn on_resume(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState
+    ) {
+        (**self).on_resume(context_and_param,global_state)
+    }
+
+    fn on_terminate(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState
+    ) {
+        (**self).on_terminate(context_and_param,global_state)
+    }
+
+}
+
+
+
+
+
+/** 
+ * The outcome of the application of the filters of a given kind on a node, step or edge.
+ * 
+ * If no filter is activated, there are no filtration results.
+ * Otherwise, what is filtered is deferred rather than dropped
+ * if all the filters that have been activated are deferring filters.
+ * **/
+pub struct FiltrationOutcome<FiltrationResult> {
+    pub filtration_results : Vec<FiltrationResult>,
+    pub is_deferred : bool
 }
 
+impl<FiltrationResult> FiltrationOutcome<FiltrationResult> {
 
+    pub fn is_filtered(&self) -> bool {
+        !self.filtration_results.is_empty()
+    }
+
+}
+
+
+
+/** 
+ * Applies filters that are all of the same kind, skipping those that are disabled
+ * and recording statistics on the applications of the others
+ * (the durations of the applications are only measured if "measure_durations" is set).
+ * **/
+fn apply_managed_filters<Filter : ?Sized, FiltrationResult>(
+    filters : &mut [Box<Filter>],
+    filters_info : &mut [ManagedFilterInfo],
+    depth : u32,
+    collect_all_filtration_results : bool,
+    measure_durations : bool,
+    apply : impl Fn(&mut Box<Filter>) -> Option<FiltrationResult>
+) -> FiltrationOutcome<FiltrationResult> {
+    let mut filtration_results = vec![];
+    let mut all_activated_filters_defer = true;
+    for (filter,filter_info) in filters.iter_mut().zip(filters_info.iter_mut()) {
+        if !filter_info.enabled {
+            continue;
+        }
+        let opt_result = if measure_durations {
+            let start = Instant::now();
+            let opt_result = apply(filter);
+            filter_info.statistics.record_duration(start.elapsed());
+            opt_result
+        } else {
+            apply(filter)
+        };
+        filter_info.statistics.record_application(opt_result.is_some(), depth);
+        if let Some(res) = opt_result {
+            filtration_results.push(res);
+            all_activated_filters_defer &= filter_info.defers;
+            if !collect_all_filtration_results {
+                break;
+            }
+        }
+    }
+    FiltrationOutcome {
+        is_deferred : all_activated_filters_defer && !filtration_results.is_empty(),
+        filtration_results
+    }
+}
 
 
 
+/** 
+ * Manages the filters of the process.
+ * 
+ * By default, filters of the same kind are applied in order and the first result that is yielded is returned.
+ * With "collect_all_filtration_results", all the filters are applied and all the yielded results are returned,
+ * so that all the reasons for which a node or step is filtered are known.
+ * 
+ * Each filter has a name (filters that are passed to "new" are named after their kind and position).
+ * Filters can be enabled or disabled by name, they can also be made to defer what they filter instead of dropping it,
+ * and statistics on their applications are recorded (see [ManagedFilterInfo](ManagedFilterInfo)).
+ * Measuring the durations of the applications requires reading the clock twice per application,
+ * hence it is disabled by default and must be enabled with "set_filter_timing".
+ * **/
 pub struct GenericFiltersManager<Conf : AbstractProcessConfiguration> {
     node_pre_filters : Vec<Box<dyn AbstractNodePreFilter<Conf>>>,
     node_post_filters : Vec<Box<dyn AbstractNodePostFilter<Conf>>>,
-    step_filters : Vec<Box<dyn AbstractStepFilter<Conf>>>
+    step_filters : Vec<Box<dyn AbstractStepFilter<Conf>>>,
+    edge_filters : Vec<Box<dyn AbstractEdgeFilter<Conf>>>,
+    node_pre_filters_info : Vec<ManagedFilterInfo>,
+    node_post_filters_info : Vec<ManagedFilterInfo>,
+    step_filters_info : Vec<ManagedFilterInfo>,
+    edge_filters_info : Vec<ManagedFilterInfo>,
+    collect_all_filtration_results : bool,
+    measure_filter_durations : bool
 }
 
 
@@ -93,9 +600,47 @@ impl<Conf : 'static + AbstractProcessConfiguration> GenericFiltersManager<Conf>
     pub fn new(
         node_pre_filters : Vec<Box<dyn AbstractNodePreFilter<Conf>>>,
         node_post_filters : Vec<Box<dyn AbstractNodePostFilter<Conf>>>,
-        step_filters : Vec<Box<dyn AbstractStepFilter<Conf>>>
+        step_filters : Vec<Box<dyn AbstractStepFilter<Conf>>>,
+        edge_filters : Vec<Box<dyn AbstractEdgeFilter<Conf>>>,
+        collect_all_filtration_results : bool
     ) -> Self {
-        Self { node_pre_filters, node_post_filters, step_filters }
+        let default_infos = |descriptions : Vec<String>, kind : FilterKind| -> Vec<ManagedFilterInfo> {
+            descriptions.into_iter().enumerate().map(
+                |(x,description)| ManagedFilterInfo::new(format!("{}{}", kind, x), kind, description)
+            ).collect()
+        };
+        Self { 
+            node_pre_filters_info : default_infos(node_pre_filters.iter().map(|x| x.describe()).collect(), FilterKind::NodePre),
+            node_post_filters_info : default_infos(node_post_filters.iter().map(|x| x.describe()).collect(), FilterKind::NodePost),
+            step_filters_info : default_infos(step_filters.iter().map(|x| x.describe()).collect(), FilterKind::Step),
+            edge_filters_info : default_infos(edge_filters.iter().map(|x| x.describe()).collect(), FilterKind::Edge),
+            node_pre_filters, 
+            node_post_filters, 
+            step_filters, 
+            edge_filters, 
+            collect_all_filtration_results,
+            measure_filter_durations : false
+        }
+    }
+
+    pub fn add_node_pre_filter(&mut self, name : &str, filter : Box<dyn AbstractNodePreFilter<Conf>>) {
+        self.node_pre_filters_info.push(ManagedFilterInfo::new(name.to_string(), FilterKind::NodePre, filter.describe()));
+        self.node_pre_filters.push(filter);
+    }
+
+    pub fn add_node_post_filter(&mut self, name : &str, filter : Box<dyn AbstractNodePostFilter<Conf>>) {
+        self.node_post_filters_info.push(ManagedFilterInfo::new(name.to_string(), FilterKind::NodePost, filter.describe()));
+        self.node_post_filters.push(filter);
+    }
+
+    pub fn add_step_filter(&mut self, name : &str, filter : Box<dyn AbstractStepFilter<Conf>>) {
+        self.step_filters_info.push(ManagedFilterInfo::new(name.to_string(), FilterKind::Step, filter.describe()));
+        self.step_filters.push(filter);
+    }
+
+    pub fn add_edge_filter(&mut self, name : &str, filter : Box<dyn AbstractEdgeFilter<Conf>>) {
+        self.edge_filters_info.push(ManagedFilterInfo::new(name.to_string(), FilterKind::Edge, filter.describe()));
+        self.edge_filters.push(filter);
     }
 
     pub fn get_node_pre_filters(&self) -> &Vec<Box<dyn AbstractNodePreFilter<Conf>>> {
@@ -110,59 +655,274 @@ impl<Conf : 'static + AbstractProcessConfiguration> GenericFiltersManager<Conf>
         &self.step_filters
     }
 
+    pub fn get_edge_filters(&self) -> &Vec<Box<dyn AbstractEdgeFilter<Conf>>> {
+        &self.edge_filters
+    }
+
+    pub fn collects_all_filtration_results(&self) -> bool {
+        self.collect_all_filtration_results
+    }
+
+    /** 
+     * Enables or disables the measure of the durations of the applications of the filters.
+     * **/
+    pub fn set_filter_timing(&mut self, measure_filter_durations : bool) {
+        self.measure_filter_durations = measure_filter_durations;
+    }
+
+    pub fn measures_filter_durations(&self) -> bool {
+        self.measure_filter_durations
+    }
+
+    pub fn get_mut_node_pre_filters(&mut self) -> &mut [Box<dyn AbstractNodePreFilter<Conf>>] {
+        &mut self.node_pre_filters
+    }
+
+    pub fn get_mut_node_post_filters(&mut self) -> &mut [Box<dyn AbstractNodePostFilter<Conf>>] {
+        &mut self.node_post_filters
+    }
+
+    pub fn get_mut_step_filters(&mut self) -> &mut [Box<dyn AbstractStepFilter<Conf>>] {
+        &mut self.step_filters
+    }
+
+    pub fn get_mut_edge_filters(&mut self) -> &mut [Box<dyn AbstractEdgeFilter<Conf>>] {
+        &mut self.edge_filters
+    }
+
+    /** 
+     * Returns the information (name, kind, whether it is enabled and statistics) on all the filters,
+     * ordered by kind (node pre filters, node post filters, step filters and edge filters) and then by order of application.
+     * **/
+    pub fn get_filters_info(&self) -> Vec<&ManagedFilterInfo> {
+        self.node_pre_filters_info.iter()
+            .chain(self.node_post_filters_info.iter())
+            .chain(self.step_filters_info.iter())
+            .chain(self.edge_filters_info.iter())
+            .collect()
+    }
+
+    /** 
+     * Enables or disables all the filters with the given name.
+     * Returns false if there are no such filters.
+     * **/
+    pub fn set_filter_enabled(&mut self, name : &str, enabled : bool) -> bool {
+        let mut found = false;
+        for filter_info in self.node_pre_filters_info.iter_mut()
+            .chain(self.node_post_filters_info.iter_mut())
+            .chain(self.step_filters_info.iter_mut())
+            .chain(self.edge_filters_info.iter_mut()) {
+            if filter_info.name == name {
+                filter_info.enabled = enabled;
+                found = true;
+            }
+        }
+        found
+    }
+
+    /** 
+     * Makes the filters with the given name defer (or no longer defer) what they filter.
+     * Returns false if there are no such filters.
+     * **/
+    pub fn set_filter_deferring(&mut self, name : &str, defers : bool) -> bool {
+        let mut found = false;
+        for filter_info in self.node_pre_filters_info.iter_mut()
+            .chain(self.node_post_filters_info.iter_mut())
+            .chain(self.step_filters_info.iter_mut())
+            .chain(self.edge_filters_info.iter_mut()) {
+            if filter_info.name == name {
+                filter_info.defers = defers;
+                found = true;
+            }
+        }
+        found
+    }
+
+    /** 
+     * Notifies all the filters that the process starts
+     * and resets the statistics on their applications.
+     * **/
+    pub fn initialize_filters(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        initial_global_state : &Conf::MutablePersistentState
+    ) {
+        for filter in &mut self.node_pre_filters {
+            filter.on_initialize(context_and_param,initial_global_state);
+        }
+        for filter in &mut self.node_post_filters {
+            filter.on_initialize(context_and_param,initial_global_state);
+        }
+        for filter in &mut self.step_filters {
+            filter.on_initialize(context_and_param,initial_global_state);
+        }
+        for filter in &mut self.edge_filters {
+            filter.on_initialize(context_and_param,initial_global_state);
+        }
+        for filter_info in self.node_pre_filters_info.iter_mut()
+            .chain(self.node_post_filters_info.iter_mut())
+            .chain(self.step_filters_info.iter_mut())
+            .chain(self.edge_filters_info.iter_mut()) {
+            filter_info.statistics = FilterActivationStatistics::default();
+        }
+    }
+
+    /** 
+     * Notifies all the filters that the process resumes from its unexplored frontier
+     * but keeps the statistics on their applications, so that these cover all the passes of the exploration.
+     * **/
+    pub fn resume_filters(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState
+    ) {
+        for filter in &mut self.node_pre_filters {
+            filter.on_resume(context_and_param,global_state);
+        }
+        for filter in &mut self.node_post_filters {
+            filter.on_resume(context_and_param,global_state);
+        }
+        for filter in &mut self.step_filters {
+            filter.on_resume(context_and_param,global_state);
+        }
+        for filter in &mut self.edge_filters {
+            filter.on_resume(context_and_param,global_state);
+        }
+    }
+
+    /** 
+     * Notifies the step filters that a step fired from the node "parent_node_id" leads to the new node "new_node_id".
+     * **/
+    pub fn notify_step_to_new_node(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        new_node_id : u32
+    ) {
+        for filter in &mut self.step_filters {
+            filter.on_step_to_new_node(context_and_param,parent_node_id,step,new_node_id);
+        }
+    }
+
+    /** 
+     * Notifies the step filters that no more steps are to be fired from the node "node_id".
+     * **/
+    pub fn notify_node_children_processed(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        node_id : u32
+    ) {
+        for filter in &mut self.step_filters {
+            filter.on_node_children_processed(context_and_param,node_id);
+        }
+    }
+
+    /** 
+     * Notifies all the filters that the process terminates.
+     * **/
+    pub fn terminate_filters(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState
+    ) {
+        for filter in &mut self.node_pre_filters {
+            filter.on_terminate(context_and_param,global_state);
+        }
+        for filter in &mut self.node_post_filters {
+            filter.on_terminate(context_and_param,global_state);
+        }
+        for filter in &mut self.step_filters {
+            filter.on_terminate(context_and_param,global_state);
+        }
+        for filter in &mut self.edge_filters {
+            filter.on_terminate(context_and_param,global_state);
+        }
+    }
+
+    /** 
+     * Returns the yielded filtration results (none if the node is not filtered) and whether the node is deferred.
+     * **/
     pub fn apply_node_pre_filters(
-        &self,
+        &mut self,
         context_and_param : &Conf::ContextAndParameterization,
         global_state : &Conf::MutablePersistentState,
-        node : &Conf::DomainSpecificNode
-    ) -> Option<Conf::FiltrationResult> {
-        for filter in &self.node_pre_filters {
-            match filter.apply_filter(context_and_param,global_state,node) {
-                None => {},
-                Some( res) => {
-                    return Some(res);
-                }
-            }
-        }
-        None
+        node : &Conf::DomainSpecificNode,
+        depth : u32
+    ) -> FiltrationOutcome<Conf::FiltrationResult> {
+        apply_managed_filters(
+            &mut self.node_pre_filters,
+            &mut self.node_pre_filters_info,
+            depth,
+            self.collect_all_filtration_results,
+            self.measure_filter_durations,
+            |filter| filter.apply_filter(context_and_param,global_state,node,depth)
+        )
     }
 
+    /** 
+     * Returns the yielded filtration results (none if the node is not filtered) and whether the node is deferred.
+     * **/
     pub fn apply_node_post_filters(
-        &self,
+        &mut self,
         context_and_param : &Conf::ContextAndParameterization,
         global_state : &Conf::MutablePersistentState,
         node : &Conf::DomainSpecificNode,
+        depth : u32,
         next_steps : &[Conf::DomainSpecificStep]
-    ) -> Option<Conf::FiltrationResult> {
-        for filter in &self.node_post_filters {
-            match filter.apply_filter(context_and_param,global_state,node,next_steps) {
-                None => {},
-                Some( res) => {
-                    return Some(res);
-                }
-            }
-        }
-        None
+    ) -> FiltrationOutcome<Conf::FiltrationResult> {
+        apply_managed_filters(
+            &mut self.node_post_filters,
+            &mut self.node_post_filters_info,
+            depth,
+            self.collect_all_filtration_results,
+            self.measure_filter_durations,
+            |filter| filter.apply_filter(context_and_param,global_state,node,depth,next_steps)
+        )
     }
 
+    /** 
+     * Returns the yielded filtration results (none if the step is not filtered) and whether the step is deferred.
+     * **/
     pub fn apply_step_filters(
-        &self,
+        &mut self,
         context_and_param : &Conf::ContextAndParameterization,
         global_state : &Conf::MutablePersistentState,
         parent_node : &Conf::DomainSpecificNode,
+        parent_node_id : u32,
+        parent_depth : u32,
         step : &Conf::DomainSpecificStep
-    ) -> Option<Conf::FiltrationResult> {
-        for filter in &self.step_filters {
-            match filter.apply_filter(context_and_param,global_state,parent_node,step) {
-                None => {},
-                Some( res) => {
-                    return Some(res);
-                }
-            }
-        }
-        None
+    ) -> FiltrationOutcome<Conf::FiltrationResult> {
+        apply_managed_filters(
+            &mut self.step_filters,
+            &mut self.step_filters_info,
+            parent_depth,
+            self.collect_all_filtration_results,
+            self.measure_filter_durations,
+            |filter| filter.apply_filter(context_and_param,global_state,parent_node,parent_node_id,parent_depth,step)
+        )
     }
 
-}
-
+    /** 
+     * Returns the yielded filtration results (none if the transition is not filtered) and whether the step is deferred.
+     * **/
+    pub fn apply_edge_filters(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        global_state : &Conf::MutablePersistentState,
+        parent_node : &Conf::DomainSpecificNode,
+        parent_depth : u32,
+        step : &Conf::DomainSpecificStep,
+        child_node : &Conf::DomainSpecificNode
+    ) -> FiltrationOutcome<Conf::FiltrationResult> {
+        apply_managed_filters(
+            &mut self.edge_filters,
+            &mut self.edge_filters_info,
+            parent_depth,
+            self.collect_all_filtration_results,
+            self.measure_filter_durations,
+            |filter| filter.apply_filter(context_and_param,global_state,parent_node,parent_depth,step,child_node)
+        )
+    }
 
+}
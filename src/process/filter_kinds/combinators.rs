@@ -0,0 +1,464 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::any::Any;
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::filter::{AbstractEdgeFilter, AbstractNodePostFilter, AbstractNodePreFilter, AbstractStepFilter};
+
+
+
+/** 
+ * Combinator that is activated if all its filters are activated, yielding the result of the first one.
+ * 
+ * The filters are applied in order and this stops at the first filter that is not activated :
+ * the filters that follow it are then not applied (which matters for stateful filters).
+ * An empty combinator is never activated.
+ * 
+ * Like the other combinators of this module, it implements the four kinds of filters 
+ * provided that the filters it combines do so.
+ * **/
+pub struct AllOf<Filter> {
+    pub filters : Vec<Filter>
+}
+
+impl<Filter> AllOf<Filter> {
+    pub fn new(filters: Vec<Filter>) -> Self {
+        Self { filters }
+    }
+}
+
+
+
+/** 
+ * Combinator that is activated if any of its filters is activated, yielding the result of the first such filter.
+ * 
+ * The filters are applied in order and this stops at the first filter that is activated :
+ * the filters that follow it are then not applied (which matters for stateful filters).
+ * An empty combinator is never activated.
+ * **/
+pub struct AnyOf<Filter> {
+    pub filters : Vec<Filter>
+}
+
+impl<Filter> AnyOf<Filter> {
+    pub fn new(filters: Vec<Filter>) -> Self {
+        Self { filters }
+    }
+}
+
+
+
+/** 
+ * Combinator that is activated if its filter is not activated, yielding a supplied result.
+ * **/
+pub struct Not<Filter,ResultSupplier> {
+    pub filter : Filter,
+    pub result_supplier : ResultSupplier
+}
+
+impl<Filter,ResultSupplier> Not<Filter,ResultSupplier> {
+    pub fn new(filter: Filter, result_supplier: ResultSupplier) -> Self {
+        Self { filter, result_supplier }
+    }
+}
+
+
+
+/** 
+ * Combinator that behaves as its filter only if a predicate holds and otherwise is not activated.
+ * The predicate has the same arguments as the "apply_filter" method of the kind of filter that is considered.
+ * **/
+pub struct When<Predicate,Filter> {
+    pub predicate : Predicate,
+    pub filter : Filter
+}
+
+impl<Predicate,Filter> When<Predicate,Filter> {
+    pub fn new(predicate: Predicate, filter: Filter) -> Self {
+        Self { predicate, filter }
+    }
+}
+
+
+
+/** 
+ * Combinator that behaves as its filter but transforms the result it yields.
+ * **/
+pub struct Map<Filter,Mapping> {
+    pub filter : Filter,
+    pub mapping : Mapping
+}
+
+impl<Filter,Mapping> Map<Filter,Mapping> {
+    pub fn new(filter: Filter, mapping: Mapping) -> Self {
+        Self { filter, mapping }
+    }
+}
+
+
+
+
+// The four kinds of filters only differ by the arguments of "apply_filter" (after the context and the global state)
+// and by the notifications they receive.
+// Hence each combinator is implemented once, by a macro that is instantiated for each kind of filter
+// with the name of the trait, the macro forwarding its notifications and the specific arguments of "apply_filter".
+
+macro_rules! impl_combinator_for_all_filter_kinds {
+    ($impl_combinator:ident) => {
+        $impl_combinator!(
+            AbstractNodePreFilter; 
+            forward_filter_notifications;
+            node : &Conf::DomainSpecificNode, 
+            depth : u32
+        );
+        $impl_combinator!(
+            AbstractNodePostFilter; 
+            forward_filter_notifications;
+            node : &Conf::DomainSpecificNode, 
+            depth : u32, 
+            next_steps : &[Conf::DomainSpecificStep]
+        );
+        $impl_combinator!(
+            AbstractStepFilter; 
+            forward_step_filter_notifications;
+            parent_node : &Conf::DomainSpecificNode, 
+            parent_node_id : u32, 
+            parent_depth : u32, 
+            step : &Conf::DomainSpecificStep
+        );
+        $impl_combinator!(
+            AbstractEdgeFilter; 
+            forward_filter_notifications;
+            parent_node : &Conf::DomainSpecificNode, 
+            parent_depth : u32, 
+            step : &Conf::DomainSpecificStep, 
+            child_node : &Conf::DomainSpecificNode
+        );
+    };
+}
+
+/// "on_initialize", "on_resume" and "on_terminate" notify either each of the combined filters or the single wrapped filter
+macro_rules! forward_filter_notifications {
+    (filters) => {
+        fn on_initialize(
+            &mut self,
+            context_and_param : &Conf::ContextAndParameterization,
+            initial_global_state : &Conf::MutablePersistentState
+        ) {
+            for filter in &mut self.filters {
+                filter.on_initialize(context_and_param,initial_global_state);
+            }
+        }
+
+        fn on_resume(
+            &mut self,
+            context_and_param : &Conf::ContextAndParameterization,
+            global_state : &Conf::MutablePersistentState
+        ) {
+            for filter in &mut self.filters {
+                filter.on_resume(context_and_param,global_state);
+            }
+        }
+
+        fn on_terminate(
+            &mut self,
+            context_and_param : &Conf::ContextAndParameterization,
+            global_state : &Conf::MutablePersistentState
+        ) {
+            for filter in &mut self.filters {
+                filter.on_terminate(context_and_param,global_state);
+            }
+        }
+    };
+    (filter) => {
+        fn on_initialize(
+            &mut self,
+            context_and_param : &Conf::ContextAndParameterization,
+            initial_global_state : &Conf::MutablePersistentState
+        ) {
+            self.filter.on_initialize(context_and_param,initial_global_state);
+        }
+
+        fn on_resume(
+            &mut self,
+            context_and_param : &Conf::ContextAndParameterization,
+            global_state : &Conf::MutablePersistentState
+        ) {
+            self.filter.on_resume(context_and_param,global_state);
+        }
+
+        fn on_terminate(
+            &mut self,
+            context_and_param : &Conf::ContextAndParameterization,
+            global_state : &Conf::MutablePersistentState
+        ) {
+            self.filter.on_terminate(context_and_param,global_state);
+        }
+    };
+}
+
+
+
+/// step filters are also notified of the steps leading to new nodes and of the nodes whose children have all been processed
+macro_rules! forward_step_filter_notifications {
+    (filters) => {
+        forward_filter_notifications!(filters);
+
+        fn on_step_to_new_node(
+            &mut self,
+            context_and_param : &Conf::ContextAndParameterization,
+            parent_node_id : u32,
+            step : &Conf::DomainSpecificStep,
+            new_node_id : u32
+        ) {
+            for filter in &mut self.filters {
+                filter.on_step_to_new_node(context_and_param,parent_node_id,step,new_node_id);
+            }
+        }
+
+        fn on_node_children_processed(
+            &mut self,
+            context_and_param : &Conf::ContextAndParameterization,
+            node_id : u32
+        ) {
+            for filter in &mut self.filters {
+                filter.on_node_children_processed(context_and_param,node_id);
+            }
+        }
+    };
+    (filter) => {
+        forward_filter_notifications!(filter);
+
+        fn on_step_to_new_node(
+            &mut self,
+            context_and_param : &Conf::ContextAndParameterization,
+            parent_node_id : u32,
+            step : &Conf::DomainSpecificStep,
+            new_node_id : u32
+        ) {
+            self.filter.on_step_to_new_node(context_and_param,parent_node_id,step,new_node_id);
+        }
+
+        fn on_node_children_processed(
+            &mut self,
+            context_and_param : &Conf::ContextAndParameterization,
+            node_id : u32
+        ) {
+            self.filter.on_node_children_processed(context_and_param,node_id);
+        }
+    };
+}
+
+
+
+macro_rules! impl_all_of {
+    ($filter_trait:ident; $forward_notifications:ident; $($arg:ident : $arg_type:ty),*) => {
+        impl<Conf, Filter> $filter_trait<Conf> for AllOf<Filter> 
+            where 
+                Conf : AbstractProcessConfiguration, 
+                Filter : 'static + $filter_trait<Conf> 
+        {
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn describe(&self) -> String {
+                format!("all of ({})", self.filters.iter().map(|filter| filter.describe()).collect::<Vec<String>>().join(", "))
+            }
+
+            fn apply_filter(
+                &mut self,
+                context_and_param : &Conf::ContextAndParameterization,
+                global_state : &Conf::MutablePersistentState,
+                $($arg : $arg_type),*
+            ) -> Option<Conf::FiltrationResult> {
+                let mut first_result = None;
+                for filter in &mut self.filters {
+                    match filter.apply_filter(context_and_param,global_state,$($arg),*) {
+                        None => {
+                            return None;
+                        },
+                        Some(res) => {
+                            first_result.get_or_insert(res);
+                        }
+                    }
+                }
+                first_result
+            }
+
+            $forward_notifications!(filters);
+
+        }
+    };
+}
+
+impl_combinator_for_all_filter_kinds!(impl_all_of);
+
+
+
+macro_rules! impl_any_of {
+    ($filter_trait:ident; $forward_notifications:ident; $($arg:ident : $arg_type:ty),*) => {
+        impl<Conf, Filter> $filter_trait<Conf> for AnyOf<Filter> 
+            where 
+                Conf : AbstractProcessConfiguration, 
+                Filter : 'static + $filter_trait<Conf> 
+        {
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn describe(&self) -> String {
+                format!("any of ({})", self.filters.iter().map(|filter| filter.describe()).collect::<Vec<String>>().join(", "))
+            }
+
+            fn apply_filter(
+                &mut self,
+                context_and_param : &Conf::ContextAndParameterization,
+                global_state : &Conf::MutablePersistentState,
+                $($arg : $arg_type),*
+            ) -> Option<Conf::FiltrationResult> {
+                self.filters.iter_mut().find_map(|filter| filter.apply_filter(context_and_param,global_state,$($arg),*))
+            }
+
+            $forward_notifications!(filters);
+
+        }
+    };
+}
+
+impl_combinator_for_all_filter_kinds!(impl_any_of);
+
+
+
+macro_rules! impl_not {
+    ($filter_trait:ident; $forward_notifications:ident; $($arg:ident : $arg_type:ty),*) => {
+        impl<Conf, Filter, ResultSupplier> $filter_trait<Conf> for Not<Filter,ResultSupplier> 
+            where 
+                Conf : AbstractProcessConfiguration, 
+                Filter : 'static + $filter_trait<Conf>,
+                ResultSupplier : 'static + Fn() -> Conf::FiltrationResult
+        {
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn describe(&self) -> String {
+                format!("not ({})", self.filter.describe())
+            }
+
+            fn apply_filter(
+                &mut self,
+                context_and_param : &Conf::ContextAndParameterization,
+                global_state : &Conf::MutablePersistentState,
+                $($arg : $arg_type),*
+            ) -> Option<Conf::FiltrationResult> {
+                match self.filter.apply_filter(context_and_param,global_state,$($arg),*) {
+                    None => Some((self.result_supplier)()),
+                    Some(_) => None
+                }
+            }
+
+            $forward_notifications!(filter);
+
+        }
+    };
+}
+
+impl_combinator_for_all_filter_kinds!(impl_not);
+
+
+
+macro_rules! impl_when {
+    ($filter_trait:ident; $forward_notifications:ident; $($arg:ident : $arg_type:ty),*) => {
+        impl<Conf, Predicate, Filter> $filter_trait<Conf> for When<Predicate,Filter> 
+            where 
+                Conf : AbstractProcessConfiguration, 
+                Predicate : 'static + Fn(
+                    &Conf::ContextAndParameterization,
+                    &Conf::MutablePersistentState,
+                    $($arg_type),*
+                ) -> bool,
+                Filter : 'static + $filter_trait<Conf>
+        {
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn describe(&self) -> String {
+                format!("conditionally ({})", self.filter.describe())
+            }
+
+            fn apply_filter(
+                &mut self,
+                context_and_param : &Conf::ContextAndParameterization,
+                global_state : &Conf::MutablePersistentState,
+                $($arg : $arg_type),*
+            ) -> Option<Conf::FiltrationResult> {
+                if (self.predicate)(context_and_param,global_state,$($arg),*) {
+                    self.filter.apply_filter(context_and_param,global_state,$($arg),*)
+                } else {
+                    None
+                }
+            }
+
+            $forward_notifications!(filter);
+
+        }
+    };
+}
+
+impl_combinator_for_all_filter_kinds!(impl_when);
+
+
+
+macro_rules! impl_map {
+    ($filter_trait:ident; $forward_notifications:ident; $($arg:ident : $arg_type:ty),*) => {
+        impl<Conf, Filter, Mapping> $filter_trait<Conf> for Map<Filter,Mapping> 
+            where 
+                Conf : AbstractProcessConfiguration, 
+                Filter : 'static + $filter_trait<Conf>,
+                Mapping : 'static + Fn(Conf::FiltrationResult) -> Conf::FiltrationResult
+        {
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn describe(&self) -> String {
+                self.filter.describe()
+            }
+
+            fn apply_filter(
+                &mut self,
+                context_and_param : &Conf::ContextAndParameterization,
+                global_state : &Conf::MutablePersistentState,
+                $($arg : $arg_type),*
+            ) -> Option<Conf::FiltrationResult> {
+                self.filter.apply_filter(context_and_param,global_state,$($arg),*).map(&self.mapping)
+            }
+
+            $forward_notifications!(filter);
+
+        }
+    };
+}
+
+impl_combinator_for_all_filter_kinds!(impl_map);
@@ -0,0 +1,69 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::any::Any;
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::filter::AbstractNodePostFilter;
+
+
+
+/** 
+ * Node post filter that is activated on nodes from which strictly more than "max_children" steps may be fired.
+ * The filtration result is built from the number of such steps.
+ * **/
+pub struct MaxChildrenFilter<ResultBuilder> {
+    pub max_children : u32,
+    pub result_builder : ResultBuilder
+}
+
+impl<ResultBuilder> MaxChildrenFilter<ResultBuilder> {
+    pub fn new(max_children: u32, result_builder: ResultBuilder) -> Self {
+        Self { max_children, result_builder }
+    }
+}
+
+impl<Conf, ResultBuilder> AbstractNodePostFilter<Conf> for MaxChildrenFilter<ResultBuilder> 
+    where 
+        Conf : AbstractProcessConfiguration, 
+        ResultBuilder : 'static + Fn(u32) -> Conf::FiltrationResult
+{
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn describe(&self) -> String {
+        format!("max children {}", self.max_children)
+    }
+
+    fn apply_filter(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState,
+        _node : &Conf::DomainSpecificNode,
+        _depth : u32,
+        next_steps : &[Conf::DomainSpecificStep]
+    ) -> Option<Conf::FiltrationResult> {
+        let num_children = next_steps.len() as u32;
+        if num_children > self.max_children {
+            Some((self.result_builder)(num_children))
+        } else {
+            None
+        }
+    }
+
+}
@@ -0,0 +1,104 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::any::Any;
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::filter::{AbstractNodePreFilter, AbstractStepFilter};
+
+
+
+/** 
+ * Filter that bounds the depth of the explored nodes.
+ * 
+ * As a node pre filter, it is activated on nodes with a depth strictly greater than "max_depth".
+ * As a step filter, it is activated on steps fired from nodes with a depth greater or equal to "max_depth"
+ * (so that nodes deeper than "max_depth" are not even computed).
+ * 
+ * The filtration result is built from the depth of the filtered node (resp. of the node from which the step is fired).
+ * **/
+pub struct MaxDepthFilter<ResultBuilder> {
+    pub max_depth : u32,
+    pub result_builder : ResultBuilder
+}
+
+impl<ResultBuilder> MaxDepthFilter<ResultBuilder> {
+    pub fn new(max_depth: u32, result_builder: ResultBuilder) -> Self {
+        Self { max_depth, result_builder }
+    }
+}
+
+impl<Conf, ResultBuilder> AbstractNodePreFilter<Conf> for MaxDepthFilter<ResultBuilder> 
+    where 
+        Conf : AbstractProcessConfiguration, 
+        ResultBuilder : 'static + Fn(u32) -> Conf::FiltrationResult
+{
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn describe(&self) -> String {
+        format!("max depth {}", self.max_depth)
+    }
+
+    fn apply_filter(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState,
+        _node : &Conf::DomainSpecificNode,
+        depth : u32
+    ) -> Option<Conf::FiltrationResult> {
+        if depth > self.max_depth {
+            Some((self.result_builder)(depth))
+        } else {
+            None
+        }
+    }
+
+}
+
+impl<Conf, ResultBuilder> AbstractStepFilter<Conf> for MaxDepthFilter<ResultBuilder> 
+    where 
+        Conf : AbstractProcessConfiguration, 
+        ResultBuilder : 'static + Fn(u32) -> Conf::FiltrationResult
+{
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn describe(&self) -> String {
+        format!("max depth {}", self.max_depth)
+    }
+
+    fn apply_filter(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState,
+        _parent_node : &Conf::DomainSpecificNode,
+        _parent_node_id : u32,
+        parent_depth : u32,
+        _step : &Conf::DomainSpecificStep
+    ) -> Option<Conf::FiltrationResult> {
+        if parent_depth >= self.max_depth {
+            Some((self.result_builder)(parent_depth))
+        } else {
+            None
+        }
+    }
+
+}
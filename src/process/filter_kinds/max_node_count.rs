@@ -0,0 +1,85 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::any::Any;
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::filter::AbstractNodePreFilter;
+
+
+
+/** 
+ * Node pre filter that bounds the number of explored nodes.
+ * 
+ * It counts the nodes on which it is applied and is activated on all nodes beyond the first "max_node_count" ones.
+ * The count is reset when the process starts but not when it resumes from its unexplored frontier,
+ * so that the bound applies to all the passes of the exploration.
+ * The filtration result is built from the number of nodes counted so far.
+ * **/
+pub struct MaxNodeCountFilter<ResultBuilder> {
+    pub max_node_count : u32,
+    pub result_builder : ResultBuilder,
+    node_count : u32
+}
+
+impl<ResultBuilder> MaxNodeCountFilter<ResultBuilder> {
+    pub fn new(max_node_count: u32, result_builder: ResultBuilder) -> Self {
+        Self { max_node_count, result_builder, node_count : 0 }
+    }
+
+    pub fn get_node_count(&self) -> u32 {
+        self.node_count
+    }
+}
+
+impl<Conf, ResultBuilder> AbstractNodePreFilter<Conf> for MaxNodeCountFilter<ResultBuilder> 
+    where 
+        Conf : AbstractProcessConfiguration, 
+        ResultBuilder : 'static + Fn(u32) -> Conf::FiltrationResult
+{
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn describe(&self) -> String {
+        format!("max node count {}", self.max_node_count)
+    }
+
+    fn apply_filter(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState,
+        _node : &Conf::DomainSpecificNode,
+        _depth : u32
+    ) -> Option<Conf::FiltrationResult> {
+        self.node_count += 1;
+        if self.node_count > self.max_node_count {
+            Some((self.result_builder)(self.node_count))
+        } else {
+            None
+        }
+    }
+
+    fn on_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _initial_global_state : &Conf::MutablePersistentState
+    ) {
+        self.node_count = 0;
+    }
+
+}
@@ -0,0 +1,137 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::filter::AbstractStepFilter;
+
+
+
+/** 
+ * Step filter that bounds the number of steps of a certain kind along a path (e.g., the number of loop unfoldings).
+ * 
+ * Steps of that kind are identified via "is_of_kind".
+ * The filter counts, for each node (identified by its unique identifier), 
+ * the number of steps of that kind along the path that leads to it :
+ * the count of a new node is that of its parent node, plus one if the step is of that kind.
+ * Nodes that have not been counted (such as the initial node) have a count of 0.
+ * With memoization, a node that is reached again along another path keeps the count of the path along which it was first reached.
+ * The count of a node is forgotten once all its children have been processed.
+ * The counts are reset when the process starts but are kept when it resumes from its unexplored frontier,
+ * so that the nodes and steps that have been deferred are resumed with their counts.
+ * 
+ * The filter is activated on steps of that kind that are fired from nodes 
+ * for which that number is already greater or equal to "max_count".
+ * The filtration result is built from the number of steps of that kind that there would be after firing the step.
+ * **/
+pub struct MaxStepKindCountFilter<KindPredicate,ResultBuilder> {
+    pub max_count : u32,
+    pub is_of_kind : KindPredicate,
+    pub result_builder : ResultBuilder,
+    counts : HashMap<u32,u32>
+}
+
+impl<KindPredicate,ResultBuilder> MaxStepKindCountFilter<KindPredicate,ResultBuilder> {
+    pub fn new(
+        max_count: u32, 
+        is_of_kind: KindPredicate, 
+        result_builder: ResultBuilder
+    ) -> Self {
+        Self { max_count, is_of_kind, result_builder, counts : HashMap::new() }
+    }
+
+    /** 
+     * Returns the number of steps of that kind along the path that leads to the node with the given identifier.
+     * **/
+    pub fn get_count(&self, node_id : u32) -> u32 {
+        self.counts.get(&node_id).cloned().unwrap_or(0)
+    }
+
+    /** 
+     * Returns the number of nodes for which a count is currently kept.
+     * **/
+    pub fn get_num_counted_nodes(&self) -> usize {
+        self.counts.len()
+    }
+}
+
+impl<Conf, KindPredicate, ResultBuilder> AbstractStepFilter<Conf> for MaxStepKindCountFilter<KindPredicate,ResultBuilder> 
+    where 
+        Conf : AbstractProcessConfiguration, 
+        KindPredicate : 'static + Fn(&Conf::DomainSpecificStep) -> bool,
+        ResultBuilder : 'static + Fn(u32) -> Conf::FiltrationResult
+{
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn describe(&self) -> String {
+        format!("max step kind count {}", self.max_count)
+    }
+
+    fn apply_filter(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState,
+        _parent_node : &Conf::DomainSpecificNode,
+        parent_node_id : u32,
+        _parent_depth : u32,
+        step : &Conf::DomainSpecificStep
+    ) -> Option<Conf::FiltrationResult> {
+        if !(self.is_of_kind)(step) {
+            return None;
+        }
+        let count = self.get_count(parent_node_id);
+        if count >= self.max_count {
+            Some((self.result_builder)(count + 1))
+        } else {
+            None
+        }
+    }
+
+    fn on_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _initial_global_state : &Conf::MutablePersistentState
+    ) {
+        self.counts.clear();
+    }
+
+    fn on_step_to_new_node(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        step : &Conf::DomainSpecificStep,
+        new_node_id : u32
+    ) {
+        let count = self.get_count(parent_node_id) + if (self.is_of_kind)(step) {1} else {0};
+        if count > 0 {
+            self.counts.insert(new_node_id, count);
+        }
+    }
+
+    fn on_node_children_processed(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        node_id : u32
+    ) {
+        self.counts.remove(&node_id);
+    }
+
+}
@@ -0,0 +1,126 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::any::Any;
+use std::time::{Duration, Instant};
+
+use crate::process::config::AbstractProcessConfiguration;
+use crate::process::filter::{AbstractNodePreFilter, AbstractStepFilter};
+
+
+
+/** 
+ * Filter that bounds the wall-clock duration of the exploration.
+ * 
+ * The clock starts when the process starts (or, if the filter is used outside of a process, the first time it is applied).
+ * It is not restarted when the process resumes from its unexplored frontier.
+ * Afterwards, once "timeout" has elapsed, it is activated on every node (as a node pre filter)
+ * or on every step (as a step filter) on which it is applied.
+ * The filtration result is built from the elapsed duration.
+ * **/
+pub struct TimeoutFilter<ResultBuilder> {
+    pub timeout : Duration,
+    pub result_builder : ResultBuilder,
+    start : Option<Instant>
+}
+
+impl<ResultBuilder> TimeoutFilter<ResultBuilder> {
+    pub fn new(timeout: Duration, result_builder: ResultBuilder) -> Self {
+        Self { timeout, result_builder, start : None }
+    }
+
+    /** 
+     * Returns the elapsed duration if the timeout has been reached.
+     * **/
+    fn check_timeout(&mut self) -> Option<Duration> {
+        let elapsed = self.start.get_or_insert_with(Instant::now).elapsed();
+        if elapsed >= self.timeout {
+            Some(elapsed)
+        } else {
+            None
+        }
+    }
+}
+
+impl<Conf, ResultBuilder> AbstractNodePreFilter<Conf> for TimeoutFilter<ResultBuilder> 
+    where 
+        Conf : AbstractProcessConfiguration, 
+        ResultBuilder : 'static + Fn(Duration) -> Conf::FiltrationResult
+{
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn describe(&self) -> String {
+        format!("timeout {:?}", self.timeout)
+    }
+
+    fn apply_filter(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState,
+        _node : &Conf::DomainSpecificNode,
+        _depth : u32
+    ) -> Option<Conf::FiltrationResult> {
+        self.check_timeout().map(&self.result_builder)
+    }
+
+    fn on_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _initial_global_state : &Conf::MutablePersistentState
+    ) {
+        self.start = Some(Instant::now());
+    }
+
+}
+
+impl<Conf, ResultBuilder> AbstractStepFilter<Conf> for TimeoutFilter<ResultBuilder> 
+    where 
+        Conf : AbstractProcessConfiguration, 
+        ResultBuilder : 'static + Fn(Duration) -> Conf::FiltrationResult
+{
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn describe(&self) -> String {
+        format!("timeout {:?}", self.timeout)
+    }
+
+    fn apply_filter(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState,
+        _parent_node : &Conf::DomainSpecificNode,
+        _parent_node_id : u32,
+        _parent_depth : u32,
+        _step : &Conf::DomainSpecificStep
+    ) -> Option<Conf::FiltrationResult> {
+        self.check_timeout().map(&self.result_builder)
+    }
+
+    fn on_initialize(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _initial_global_state : &Conf::MutablePersistentState
+    ) {
+        self.start = Some(Instant::now());
+    }
+
+}
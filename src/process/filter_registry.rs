@@ -0,0 +1,438 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::num::IntErrorKind;
+use std::time::Duration;
+
+use super::config::AbstractProcessConfiguration;
+use super::filter::{AbstractEdgeFilter, AbstractNodePostFilter, AbstractNodePreFilter, AbstractStepFilter, GenericFiltersManager};
+
+
+
+/** 
+ * The types of the parameters of the filters that can be built from a textual specification.
+ * **/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FilterParameterKind {
+    UnsignedInteger,
+    Float,
+    Boolean,
+    /// a number followed by a unit among "ms", "s", "m" and "h" (e.g., "500ms" or "10s")
+    Duration,
+    Text
+}
+
+impl fmt::Display for FilterParameterKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterParameterKind::UnsignedInteger => {
+                write!(f,"unsigned integer")
+            },
+            FilterParameterKind::Float => {
+                write!(f,"float")
+            },
+            FilterParameterKind::Boolean => {
+                write!(f,"boolean")
+            },
+            FilterParameterKind::Duration => {
+                write!(f,"duration")
+            },
+            FilterParameterKind::Text => {
+                write!(f,"text")
+            }
+        }
+    }
+}
+
+
+
+/** 
+ * The value of a parameter of a filter, parsed according to its declared type.
+ * **/
+#[derive(Clone, PartialEq, Debug)]
+pub enum FilterParameterValue {
+    UnsignedInteger(u64),
+    Float(f64),
+    Boolean(bool),
+    Duration(Duration),
+    Text(String)
+}
+
+/** 
+ * Why a raw value could not be parsed as a parameter value.
+ * **/
+enum ParameterParsingFailure {
+    /// the raw value is not of the expected type
+    Malformed,
+    /// the raw value is of the expected type but it cannot be represented
+    OutOfRange
+}
+
+impl FilterParameterValue {
+
+    fn parse_unsigned_integer(raw_value : &str) -> Result<u64,ParameterParsingFailure> {
+        raw_value.parse::<u64>().map_err(|e| {
+            match e.kind() {
+                IntErrorKind::PosOverflow => ParameterParsingFailure::OutOfRange,
+                _ => ParameterParsingFailure::Malformed
+            }
+        })
+    }
+
+    fn parse(kind : FilterParameterKind, raw_value : &str) -> Result<Self,ParameterParsingFailure> {
+        match kind {
+            FilterParameterKind::UnsignedInteger => {
+                Self::parse_unsigned_integer(raw_value).map(FilterParameterValue::UnsignedInteger)
+            },
+            FilterParameterKind::Float => {
+                raw_value.parse::<f64>().map(FilterParameterValue::Float).map_err(|_| ParameterParsingFailure::Malformed)
+            },
+            FilterParameterKind::Boolean => {
+                raw_value.parse::<bool>().map(FilterParameterValue::Boolean).map_err(|_| ParameterParsingFailure::Malformed)
+            },
+            FilterParameterKind::Duration => {
+                let unit_start = raw_value.find(|c : char| !c.is_ascii_digit()).ok_or(ParameterParsingFailure::Malformed)?;
+                let amount = Self::parse_unsigned_integer(&raw_value[..unit_start])?;
+                let seconds_per_unit = match &raw_value[unit_start..] {
+                    "ms" => {
+                        return Ok(FilterParameterValue::Duration(Duration::from_millis(amount)));
+                    },
+                    "s" => 1,
+                    "m" => 60,
+                    "h" => 3600,
+                    _ => {
+                        return Err(ParameterParsingFailure::Malformed);
+                    }
+                };
+                let seconds = amount.checked_mul(seconds_per_unit).ok_or(ParameterParsingFailure::OutOfRange)?;
+                Ok(FilterParameterValue::Duration(Duration::from_secs(seconds)))
+            },
+            FilterParameterKind::Text => {
+                Ok(FilterParameterValue::Text(raw_value.to_string()))
+            }
+        }
+    }
+
+    /** 
+     * Returns the value as an u32, provided it is an unsigned integer that fits.
+     * **/
+    pub fn as_u32(&self) -> Option<u32> {
+        match self {
+            FilterParameterValue::UnsignedInteger(x) => u32::try_from(*x).ok(),
+            _ => None
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            FilterParameterValue::UnsignedInteger(x) => Some(*x),
+            _ => None
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            FilterParameterValue::Float(x) => Some(*x),
+            _ => None
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            FilterParameterValue::Boolean(x) => Some(*x),
+            _ => None
+        }
+    }
+
+    pub fn as_duration(&self) -> Option<Duration> {
+        match self {
+            FilterParameterValue::Duration(x) => Some(*x),
+            _ => None
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            FilterParameterValue::Text(x) => Some(x),
+            _ => None
+        }
+    }
+
+}
+
+
+
+/** 
+ * A filter built by a factory of the [GenericFiltersRegistry](GenericFiltersRegistry), of any of the four kinds of filters.
+ * **/
+pub enum BuiltFilter<Conf : AbstractProcessConfiguration> {
+    NodePre(Box<dyn AbstractNodePreFilter<Conf>>),
+    NodePost(Box<dyn AbstractNodePostFilter<Conf>>),
+    Step(Box<dyn AbstractStepFilter<Conf>>),
+    Edge(Box<dyn AbstractEdgeFilter<Conf>>)
+}
+
+
+
+/** 
+ * Builds a filter from the values of its parameters.
+ * The values are given in the order in which the parameters are declared and are guaranteed to be of the declared types.
+ * Returns an error message if the values are not suitable (e.g., if they are out of range).
+ * **/
+pub type FilterFactory<Conf> = Box<dyn Fn(&[FilterParameterValue]) -> Result<BuiltFilter<Conf>,String>>;
+
+struct RegisteredFilterFactory<Conf : AbstractProcessConfiguration> {
+    parameters : Vec<(String,FilterParameterKind)>,
+    factory : FilterFactory<Conf>
+}
+
+
+
+/** 
+ * Errors that may occur when building filters from a textual specification.
+ * **/
+#[derive(Clone, PartialEq, Debug)]
+pub enum FilterSpecificationError {
+    /// an entry of the specification is not of the form "name" or "name=value,...,value"
+    MalformedEntry(String),
+    /// no factory is registered under this name
+    UnknownFilter(String),
+    /// the same filter is specified more than once
+    DuplicateFilter(String),
+    WrongNumberOfParameters {
+        filter : String,
+        expected : usize,
+        found : usize
+    },
+    /// a value could not be parsed as the type of the corresponding parameter
+    BadParameter {
+        filter : String,
+        parameter : String,
+        expected : FilterParameterKind,
+        value : String
+    },
+    /// a value is of the type of the corresponding parameter but it is too large to be represented
+    OutOfRangeParameter {
+        filter : String,
+        parameter : String,
+        value : String
+    },
+    /// the factory of the filter rejected the values of its parameters
+    InvalidParameters {
+        filter : String,
+        reason : String
+    }
+}
+
+impl fmt::Display for FilterSpecificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterSpecificationError::MalformedEntry(entry) => {
+                write!(f,"malformed filter specification entry '{}'", entry)
+            },
+            FilterSpecificationError::UnknownFilter(filter) => {
+                write!(f,"unknown filter '{}'", filter)
+            },
+            FilterSpecificationError::DuplicateFilter(filter) => {
+                write!(f,"filter '{}' is specified more than once", filter)
+            },
+            FilterSpecificationError::WrongNumberOfParameters { filter, expected, found } => {
+                write!(f,"filter '{}' expects {} parameters but {} were given", filter, expected, found)
+            },
+            FilterSpecificationError::BadParameter { filter, parameter, expected, value } => {
+                write!(f,"parameter '{}' of filter '{}' expects a value of type {} but got '{}'", parameter, filter, expected, value)
+            },
+            FilterSpecificationError::OutOfRangeParameter { filter, parameter, value } => {
+                write!(f,"value '{}' of parameter '{}' of filter '{}' is out of range", value, parameter, filter)
+            },
+            FilterSpecificationError::InvalidParameters { filter, reason } => {
+                write!(f,"invalid parameters for filter '{}' : {}", filter, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FilterSpecificationError {}
+
+
+
+/** 
+ * Registry of filter factories, each registered under a name along with its typed parameters.
+ * 
+ * It allows building a [GenericFiltersManager](GenericFiltersManager) from a textual specification
+ * such as "max_depth=10;max_loop=3;timeout=30s;no_deadlock" i.e.,
+ * a list of entries separated by ";", each entry consisting of the name of a filter
+ * followed, if it has parameters, by "=" and the values of its parameters separated by ",".
+ * 
+ * Each filter is added to the manager under the name with which it is specified,
+ * so that it can then be enabled or disabled by name.
+ * **/
+pub struct GenericFiltersRegistry<Conf : AbstractProcessConfiguration> {
+    factories : BTreeMap<String,RegisteredFilterFactory<Conf>>
+}
+
+impl<Conf : AbstractProcessConfiguration> Default for GenericFiltersRegistry<Conf> {
+    fn default() -> Self {
+        Self { 
+            factories : BTreeMap::new() 
+        }
+    }
+}
+
+impl<Conf : 'static + AbstractProcessConfiguration> GenericFiltersRegistry<Conf> {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /** 
+     * Registers a filter factory under the given name.
+     * The parameters are given as pairs of a parameter name and of a parameter type.
+     * Replaces any factory previously registered under the same name.
+     * **/
+    pub fn register_filter(
+        &mut self,
+        name : &str,
+        parameters : Vec<(&str,FilterParameterKind)>,
+        factory : FilterFactory<Conf>
+    ) {
+        let parameters = parameters.into_iter().map(|(param_name,kind)| (param_name.to_string(),kind)).collect();
+        self.factories.insert(
+            name.to_string(), 
+            RegisteredFilterFactory { parameters, factory }
+        );
+    }
+
+    /** 
+     * Describes the filters that can be specified, one per line, e.g., "max_depth=<depth:unsigned integer>".
+     * **/
+    pub fn describe_registered_filters(&self) -> String {
+        self.factories.iter().map(|(name,registered)| {
+            if registered.parameters.is_empty() {
+                name.clone()
+            } else {
+                let parameters = registered.parameters.iter()
+                    .map(|(param_name,kind)| format!("<{}:{}>", param_name, kind))
+                    .collect::<Vec<String>>();
+                format!("{}={}", name, parameters.join(","))
+            }
+        }).collect::<Vec<String>>().join("\n")
+    }
+
+    /** 
+     * Builds a filters manager from a textual specification.
+     * The specification is entirely validated before the filters are built.
+     * **/
+    pub fn build_filters_manager(
+        &self,
+        specification : &str,
+        collect_all_filtration_results : bool
+    ) -> Result<GenericFiltersManager<Conf>,FilterSpecificationError> {
+        let mut filters_manager = GenericFiltersManager::new(vec![],vec![],vec![],vec![],collect_all_filtration_results);
+        self.add_filters_from_specification(&mut filters_manager, specification)?;
+        Ok(filters_manager)
+    }
+
+    /** 
+     * Adds the filters that are specified textually to an existing filters manager.
+     * If the specification is invalid, no filter is added.
+     * **/
+    pub fn add_filters_from_specification(
+        &self,
+        filters_manager : &mut GenericFiltersManager<Conf>,
+        specification : &str
+    ) -> Result<(),FilterSpecificationError> {
+        let mut parsed_entries : Vec<(&str,&RegisteredFilterFactory<Conf>,Vec<FilterParameterValue>)> = vec![];
+        for entry in specification.split(';').map(|x| x.trim()).filter(|x| !x.is_empty()) {
+            let (filter_name,raw_values) = match entry.split_once('=') {
+                None => {
+                    (entry,vec![])
+                },
+                Some((filter_name,raw_values)) => {
+                    (filter_name.trim(),raw_values.split(',').map(|x| x.trim()).collect())
+                }
+            };
+            if filter_name.is_empty() || raw_values.iter().any(|x| x.is_empty()) {
+                return Err(FilterSpecificationError::MalformedEntry(entry.to_string()));
+            }
+            let registered = self.factories.get(filter_name).ok_or_else(
+                || FilterSpecificationError::UnknownFilter(filter_name.to_string())
+            )?;
+            if parsed_entries.iter().any(|(x,_,_)| *x == filter_name) {
+                return Err(FilterSpecificationError::DuplicateFilter(filter_name.to_string()));
+            }
+            if raw_values.len() != registered.parameters.len() {
+                return Err(
+                    FilterSpecificationError::WrongNumberOfParameters { 
+                        filter : filter_name.to_string(), 
+                        expected : registered.parameters.len(), 
+                        found : raw_values.len() 
+                    }
+                );
+            }
+            let mut values = vec![];
+            for ((param_name,kind),raw_value) in registered.parameters.iter().zip(raw_values) {
+                let value = FilterParameterValue::parse(*kind, raw_value).map_err(
+                    |failure| match failure {
+                        ParameterParsingFailure::Malformed => FilterSpecificationError::BadParameter { 
+                            filter : filter_name.to_string(), 
+                            parameter : param_name.clone(), 
+                            expected : *kind, 
+                            value : raw_value.to_string() 
+                        },
+                        ParameterParsingFailure::OutOfRange => FilterSpecificationError::OutOfRangeParameter { 
+                            filter : filter_name.to_string(), 
+                            parameter : param_name.clone(), 
+                            value : raw_value.to_string() 
+                        }
+                    }
+                )?;
+                values.push(value);
+            }
+            parsed_entries.push((filter_name,registered,values));
+        }
+        let mut built_filters = vec![];
+        for (filter_name,registered,values) in parsed_entries {
+            let built_filter = (registered.factory)(&values).map_err(
+                |reason| FilterSpecificationError::InvalidParameters { 
+                    filter : filter_name.to_string(), 
+                    reason 
+                }
+            )?;
+            built_filters.push((filter_name,built_filter));
+        }
+        for (filter_name,built_filter) in built_filters {
+            match built_filter {
+                BuiltFilter::NodePre(filter) => {
+                    filters_manager.add_node_pre_filter(filter_name, filter);
+                },
+                BuiltFilter::NodePost(filter) => {
+                    filters_manager.add_node_post_filter(filter_name, filter);
+                },
+                BuiltFilter::Step(filter) => {
+                    filters_manager.add_step_filter(filter_name, filter);
+                },
+                BuiltFilter::Edge(filter) => {
+                    filters_manager.add_edge_filter(filter_name, filter);
+                }
+            }
+        }
+        Ok(())
+    }
+
+}
@@ -0,0 +1,146 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+
+
+/** 
+ * The kinds of filters.
+ * **/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FilterKind {
+    NodePre,
+    NodePost,
+    Step,
+    Edge
+}
+
+impl fmt::Display for FilterKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterKind::NodePre => {
+                write!(f,"NodePreFilter")
+            },
+            FilterKind::NodePost => {
+                write!(f,"NodePostFilter")
+            },
+            FilterKind::Step => {
+                write!(f,"StepFilter")
+            },
+            FilterKind::Edge => {
+                write!(f,"EdgeFilter")
+            }
+        }
+    }
+}
+
+
+
+/** 
+ * Statistics on the applications of a filter during a process.
+ * "activation_depths" associates to each depth the number of activations of the filter
+ * on nodes at that depth (or on steps and edges fired from nodes at that depth).
+ * "total_duration" remains zero unless the filters manager measures the durations of the applications.
+ * **/
+#[derive(Clone, Default, Debug)]
+pub struct FilterActivationStatistics {
+    pub num_applications : u64,
+    pub num_activations : u64,
+    pub total_duration : Duration,
+    pub activation_depths : BTreeMap<u32,u64>
+}
+
+impl FilterActivationStatistics {
+
+    pub(crate) fn record_application(
+        &mut self, 
+        is_activated : bool, 
+        depth : u32
+    ) {
+        self.num_applications += 1;
+        if is_activated {
+            self.num_activations += 1;
+            *self.activation_depths.entry(depth).or_insert(0) += 1;
+        }
+    }
+
+    pub(crate) fn record_duration(&mut self, duration : Duration) {
+        self.total_duration += duration;
+    }
+
+}
+
+impl fmt::Display for FilterActivationStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "activated {} times out of {} applications in {:?}",
+            self.num_activations,
+            self.num_applications,
+            self.total_duration
+        )?;
+        if let (Some(min_depth),Some(max_depth)) = (self.activation_depths.keys().next(),self.activation_depths.keys().last()) {
+            write!(f," at depths {} to {}", min_depth, max_depth)?;
+        }
+        Ok(())
+    }
+}
+
+
+
+/** 
+ * Information on a filter that is managed by the [GenericFiltersManager](crate::process::filter::GenericFiltersManager).
+ * Filters that are disabled are not applied.
+ * Filters that defer do not permanently drop what they filter but keep it in the unexplored frontier of the process
+ * (see [DeferredFrontier](crate::process::deferred_frontier::DeferredFrontier)).
+ * **/
+#[derive(Clone, Debug)]
+pub struct ManagedFilterInfo {
+    pub name : String,
+    pub kind : FilterKind,
+    pub description : String,
+    pub enabled : bool,
+    pub defers : bool,
+    pub statistics : FilterActivationStatistics
+}
+
+impl ManagedFilterInfo {
+    pub(crate) fn new(name: String, kind: FilterKind, description: String) -> Self {
+        Self { 
+            name, 
+            kind, 
+            description,
+            enabled : true, 
+            defers : false,
+            statistics : FilterActivationStatistics::default() 
+        }
+    }
+}
+
+impl fmt::Display for ManagedFilterInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,"{} '{}' ({})", self.kind, self.name, self.description)?;
+        if !self.enabled {
+            write!(f," (disabled)")?;
+        }
+        if self.defers {
+            write!(f," (deferring)")?;
+        }
+        write!(f," : {}", self.statistics)
+    }
+}
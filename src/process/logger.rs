@@ -19,6 +19,7 @@ use std::{any::Any, slice::IterMut};
 use crate::{process::config::AbstractProcessConfiguration, queue::{priorities::GenericProcessPriorities, strategy::QueueSearchStrategy}};
 
 use super::filter::GenericFiltersManager;
+use super::filter_statistics::ManagedFilterInfo;
 
 pub trait AbstractProcessLogger<Conf : AbstractProcessConfiguration> {
 
@@ -57,7 +58,8 @@ pub trait AbstractProcessLogger<Conf : AbstractProcessConfiguration> {
      * Notifies the logger that a new step has been processed.
      * This is done separately from "log_new_node" because
      * processing a new step does not necessarily cause a new node to be added.
-     * Indeed, if memoization is used, it may cycle back to an already known node.
+     * Indeed, if memoization is used, it may cycle back to an already known node
+     * (in which case "log_memo_hit" is notified right after).
      * **/
     fn log_new_step(
         &mut self,
@@ -68,6 +70,50 @@ pub trait AbstractProcessLogger<Conf : AbstractProcessConfiguration> {
         target_node : &Conf::DomainSpecificNode
     );
 
+    /** 
+     * Notifies the logger that a step that has just been processed (and notified via "log_new_step")
+     * leads to an already known node, memoized under "target_node_id".
+     * 
+     * This is only relevant when memoization is used.
+     * By default, nothing is done.
+     * **/
+    fn log_memo_hit(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _origin_node_id : u32,
+        _step : &Conf::DomainSpecificStep,
+        _target_node_id : u32
+    ) {}
+
+    /** 
+     * Notifies the logger that a node that has just been added (and notified via "log_new_node" and "log_new_step")
+     * under "new_node_id" is an already known node, memoized under "memoized_node_id",
+     * which is expanded again because it is now reached at a smaller depth "new_depth".
+     * 
+     * This is only relevant when depth-aware memoization is used.
+     * By default, nothing is done.
+     * **/
+    fn log_node_reexpanded(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _memoized_node_id : u32,
+        _new_node_id : u32,
+        _new_depth : u32
+    ) {}
+
+    /** 
+     * Notifies the logger of the number of nodes that are memoized
+     * once a new node has been memoized (and the memo possibly pruned or swept).
+     * 
+     * This is only relevant when memoization is used.
+     * By default, nothing is done.
+     * **/
+    fn log_memo_size(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _num_memoized_nodes : usize
+    ) {}
+
     /** 
      * Notifies the logger that all steps that could be fired from a given node
      * have been processed.
@@ -89,9 +135,10 @@ pub trait AbstractProcessLogger<Conf : AbstractProcessConfiguration> {
     );
 
     /** 
-     * Notifies the logger that a filter has yielded a "FiltrationResult"
+     * Notifies the logger that a node filter has yielded a "FiltrationResult"
      * and therefore prevented the exploration of parts of the graph structure
      * that correspond to successors of a given node (here identified by "parent_node_id").
+     * By default, filtrations of steps and edges are also notified via this method.
      * **/
     fn log_filtered(
         &mut self,
@@ -101,9 +148,95 @@ pub trait AbstractProcessLogger<Conf : AbstractProcessConfiguration> {
         filtration_result : &Conf::FiltrationResult
     );
 
+    /** 
+     * Notifies the logger that a step filter has yielded a "FiltrationResult" on the "step"
+     * that was to be fired from the node identified by "parent_node_id".
+     * The step is then not fired.
+     * By default, this is notified as any other filtration (see "log_filtered").
+     * **/
+    fn log_step_filtered(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        _step : &Conf::DomainSpecificStep,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        self.log_filtered(
+            context_and_param,
+            parent_node_id,
+            filtration_result_id,
+            filtration_result
+        );
+    }
+
+    /** 
+     * Notifies the logger that an edge filter has yielded a "FiltrationResult" on the transition
+     * from the node identified by "parent_node_id" to the "child_node" it reaches via "step".
+     * The child node is then discarded (it is neither memoized nor explored further).
+     * By default, this is notified as any other filtration (see "log_filtered").
+     * **/
+    fn log_edge_filtered(
+        &mut self,
+        context_and_param : &Conf::ContextAndParameterization,
+        parent_node_id : u32,
+        _step : &Conf::DomainSpecificStep,
+        _child_node : &Conf::DomainSpecificNode,
+        filtration_result_id : u32,
+        filtration_result : &Conf::FiltrationResult
+    ) {
+        self.log_filtered(
+            context_and_param,
+            parent_node_id,
+            filtration_result_id,
+            filtration_result
+        );
+    }
+
+    /** 
+     * Notifies the logger that what has been filtered is deferred i.e., kept in the unexplored frontier of the process.
+     * This is either the node identified by "node_id" (if "step" is None)
+     * or a step that may be fired from that node.
+     * Does nothing by default.
+     * **/
+    fn log_deferred(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _node_id : u32,
+        _step : Option<&Conf::DomainSpecificStep>
+    ) {}
+
+    /** 
+     * Provides the logger, right before the termination of the process, with a summary of the filters
+     * i.e., for each filter, whether it is enabled and statistics on its applications.
+     * Does nothing by default.
+     * **/
+    fn log_filters_summary(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _filters_info : &[&ManagedFilterInfo]
+    ) {}
+
+    /** 
+     * Notifies the logger that the process, which has terminated (see "log_terminate_process"),
+     * resumes its exploration from its unexplored frontier i.e., from what has been deferred by filters.
+     * 
+     * This marks the boundary between two passes of the exploration.
+     * It is not followed by "log_initialize" : the events that follow extend the same graph
+     * (with the same node identifiers) and end with another "log_terminate_process".
+     * The filters summary that precedes that termination covers all the passes.
+     * Does nothing by default.
+     * **/
+    fn log_resume(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _global_state : &Conf::MutablePersistentState
+    ) {}
+
     /** 
      * Notifies the logger that the process has terminated.
      * Carries the information of the final global state.
+     * This is notified once per pass of the exploration (see "log_resume").
      * **/
     fn log_terminate_process(
         &mut self,
@@ -185,6 +318,61 @@ pub(crate) fn loggers_new_step<Conf : AbstractProcessConfiguration>(
 
 
 
+pub(crate) fn loggers_memo_hit<Conf : AbstractProcessConfiguration>(
+    loggers_iter : IterMut<'_, Box< dyn AbstractProcessLogger<Conf>>>,
+    context_and_param : &Conf::ContextAndParameterization,
+    origin_node_id : u32,
+    step : &Conf::DomainSpecificStep,
+    target_node_id : u32
+) {
+    for logger in loggers_iter {
+        logger.log_memo_hit(
+            context_and_param,
+            origin_node_id,
+            step,
+            target_node_id
+        );
+    }
+}
+
+
+
+
+
+pub(crate) fn loggers_node_reexpanded<Conf : AbstractProcessConfiguration>(
+    loggers_iter : IterMut<'_, Box< dyn AbstractProcessLogger<Conf>>>,
+    context_and_param : &Conf::ContextAndParameterization,
+    memoized_node_id : u32,
+    new_node_id : u32,
+    new_depth : u32
+) {
+    for logger in loggers_iter {
+        logger.log_node_reexpanded(
+            context_and_param,
+            memoized_node_id,
+            new_node_id,
+            new_depth
+        );
+    }
+}
+
+pub(crate) fn loggers_memo_size<Conf : AbstractProcessConfiguration>(
+    loggers_iter : IterMut<'_, Box< dyn AbstractProcessLogger<Conf>>>,
+    context_and_param : &Conf::ContextAndParameterization,
+    num_memoized_nodes : usize
+) {
+    for logger in loggers_iter {
+        logger.log_memo_size(
+            context_and_param,
+            num_memoized_nodes
+        );
+    }
+}
+
+
+
+
+
 pub(crate) fn loggers_notify_last_child_step_of_node_processed<Conf : AbstractProcessConfiguration>(
     loggers_iter : IterMut<'_, Box< dyn AbstractProcessLogger<Conf>>>,
     context_and_param : &Conf::ContextAndParameterization,
@@ -235,6 +423,107 @@ pub(crate) fn loggers_filtered<Conf : AbstractProcessConfiguration>(
 
 
 
+pub(crate) fn loggers_step_filtered<Conf : AbstractProcessConfiguration>(
+    loggers_iter : IterMut<'_, Box< dyn AbstractProcessLogger<Conf>>>,
+    context_and_param : &Conf::ContextAndParameterization,
+    parent_node_id : u32,
+    step : &Conf::DomainSpecificStep,
+    filtration_result_id : u32,
+    filtration_result : &Conf::FiltrationResult
+) {
+    for logger in loggers_iter {
+        logger.log_step_filtered(
+            context_and_param,
+            parent_node_id,
+            step,
+            filtration_result_id,
+            filtration_result
+        );
+    }
+}
+
+
+
+
+
+pub(crate) fn loggers_edge_filtered<Conf : AbstractProcessConfiguration>(
+    loggers_iter : IterMut<'_, Box< dyn AbstractProcessLogger<Conf>>>,
+    context_and_param : &Conf::ContextAndParameterization,
+    parent_node_id : u32,
+    step : &Conf::DomainSpecificStep,
+    child_node : &Conf::DomainSpecificNode,
+    filtration_result_id : u32,
+    filtration_result : &Conf::FiltrationResult
+) {
+    for logger in loggers_iter {
+        logger.log_edge_filtered(
+            context_and_param,
+            parent_node_id,
+            step,
+            child_node,
+            filtration_result_id,
+            filtration_result
+        );
+    }
+}
+
+
+
+
+
+pub(crate) fn loggers_deferred<Conf : AbstractProcessConfiguration>(
+    loggers_iter : IterMut<'_, Box< dyn AbstractProcessLogger<Conf>>>,
+    context_and_param : &Conf::ContextAndParameterization,
+    node_id : u32,
+    step : Option<&Conf::DomainSpecificStep>
+) {
+    for logger in loggers_iter {
+        logger.log_deferred(
+            context_and_param,
+            node_id,
+            step
+        );
+    }
+}
+
+
+
+
+
+pub(crate) fn loggers_filters_summary<Conf : AbstractProcessConfiguration>(
+    loggers_iter : IterMut<'_, Box< dyn AbstractProcessLogger<Conf>>>,
+    context_and_param : &Conf::ContextAndParameterization,
+    filters_info : &[&ManagedFilterInfo]
+) {
+    for logger in loggers_iter {
+        logger.log_filters_summary(
+            context_and_param,
+            filters_info
+        );
+    }
+}
+
+
+
+
+
+pub(crate) fn loggers_resume<Conf : AbstractProcessConfiguration>(
+    loggers_iter : IterMut<'_, Box< dyn AbstractProcessLogger<Conf>>>,
+    context_and_param : &Conf::ContextAndParameterization,
+    global_state : &Conf::MutablePersistentState
+) {
+    for logger in loggers_iter {
+        logger.log_resume(
+            context_and_param,
+            global_state
+        );
+    }
+}
+
+
+
+
+
 pub(crate) fn loggers_terminate_process<Conf : AbstractProcessConfiguration>(
     loggers_iter : IterMut<'_, Box< dyn AbstractProcessLogger<Conf>>>,
     context_and_param : &Conf::ContextAndParameterization,
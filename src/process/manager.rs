@@ -14,10 +14,10 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 
-use crate::process::config::AbstractProcessConfiguration;
+use crate::process::config::{AbstractNodeKind, AbstractProcessConfiguration};
 use crate::process::logger::AbstractProcessLogger;
 use crate::queue::delegate::ProcessQueueDelegate;
 use crate::queue::memorized_node::MemorizedNode;
@@ -28,10 +28,12 @@ use crate::queue::strategy::QueueSearchStrategy;
 use crate::process::persistent_state::AbstractProcessMutablePersistentState;
 use crate::process::handler::AbstractAlgorithmOperationHandler;
 
+use super::deferred_frontier::{DeferredFrontier, DeferredNode, DeferredStep, UnexploredFrontierReport};
 use super::filter::GenericFiltersManager;
 use super::identifier::UniqueIdentifierGenerator;
 use super::logger::*;
-use super::node_memoizer::NodeMemoizer;
+use super::memo_persistence::{MemoExplorationTracker, NodeMemoPersistence};
+use super::node_memoizer::{NodeMemoCheck, NodeMemoizationOptions, NodeMemoizationReport, NodeMemoizer};
 
 
 
@@ -49,6 +51,15 @@ pub(crate) struct ProcessManagerInternalStateManager<Conf : AbstractProcessConfi
     pub node_has_processed_child_tracker : HashSet<u32>,
     /// for memoizing nodes and exploring the process as a graph instead of a tree
     pub node_memoizer : NodeMemoizer<Conf>,
+    /// the nodes and steps deferred by filters, from which exploration may later be resumed
+    pub deferred_frontier : DeferredFrontier<Conf>,
+    /// the nodes some steps of which have been deferred during the current pass of the exploration
+    /// the processing of their children is only complete once these steps are resumed
+    /// so the loggers and the filters are not notified of it in this pass
+    pub parents_of_deferred_steps : HashSet<u32>,
+    /// upon resuming, the child nodes yielded by the steps that have been deferred by edge filters
+    /// identified by their parent node id and their id as potential step from their parent
+    pub resumed_child_nodes : HashMap<(u32,u32),Conf::DomainSpecificNode>,
 }
 
 impl<Conf: AbstractProcessConfiguration> ProcessManagerInternalStateManager<Conf> {
@@ -60,7 +71,10 @@ impl<Conf: AbstractProcessConfiguration> ProcessManagerInternalStateManager<Conf
             initial_node_if_not_yet_started : Some(initial_node), 
             identifier_generator : UniqueIdentifierGenerator::default(),
             node_has_processed_child_tracker : HashSet::new(),
-            node_memoizer 
+            node_memoizer,
+            deferred_frontier : DeferredFrontier::default(),
+            parents_of_deferred_steps : HashSet::new(),
+            resumed_child_nodes : HashMap::new()
         }
     }
 }
@@ -81,7 +95,11 @@ pub struct GenericProcessManager<Conf : AbstractProcessConfiguration> {
     // ***
     pub loggers : Vec<Box< dyn AbstractProcessLogger<Conf>>>,
     // ***
-    internal_state : ProcessManagerInternalStateManager<Conf>
+    internal_state : ProcessManagerInternalStateManager<Conf>,
+    // ***
+    memo_persistence : Option<NodeMemoPersistence<Conf>>,
+    memo_exploration_tracker : Option<MemoExplorationTracker>,
+    memo_persistence_error : Option<std::io::Error>
 }
 
 
@@ -94,7 +112,7 @@ impl<Conf : 'static + AbstractProcessConfiguration> GenericProcessManager<Conf>
         priorities: GenericProcessPriorities<Conf::Priorities>,
         filters_manager : GenericFiltersManager<Conf>,
         loggers : Vec<Box< dyn AbstractProcessLogger<Conf>>>,
-        is_memoized : bool,
+        memoization_options : Option<NodeMemoizationOptions<Conf>>,
         initial_node : Conf::DomainSpecificNode
     ) -> GenericProcessManager<Conf> {
         let initial_global_state = Conf::MutablePersistentState::get_initial_state(
@@ -103,7 +121,7 @@ impl<Conf : 'static + AbstractProcessConfiguration> GenericProcessManager<Conf>
         );
         let internal_state = ProcessManagerInternalStateManager::new(
             initial_node, 
-            NodeMemoizer::new(is_memoized)
+            NodeMemoizer::new(memoization_options)
         );
         GenericProcessManager{
             context_and_param,
@@ -111,14 +129,102 @@ impl<Conf : 'static + AbstractProcessConfiguration> GenericProcessManager<Conf>
             global_state : initial_global_state,
             filters_manager,
             loggers,
-            internal_state
+            internal_state,
+            memo_persistence : None,
+            memo_exploration_tracker : None,
+            memo_persistence_error : None
+        }
+    }
+
+    /** 
+     * Loads the memoized nodes from the file of the persistence (if it exists)
+     * and registers the persistence so that the memo is saved into that file when the process terminates.
+     * 
+     * This requires memoization to be active and the process not to have been started yet.
+     * Returns the number of nodes that have been loaded.
+     * **/
+    pub fn set_memo_persistence(
+        &mut self,
+        memo_persistence : NodeMemoPersistence<Conf>
+    ) -> std::io::Result<usize> {
+        if !self.internal_state.node_memoizer.is_memoized() {
+            return Err(std::io::Error::other("memo persistence requires memoization to be active"));
+        }
+        if !self.internal_state.node_memoizer.keeps_nodes() {
+            return Err(std::io::Error::other("memo persistence requires the memo to keep nodes and not fingerprints"));
+        }
+        if self.internal_state.initial_node_if_not_yet_started.is_none() {
+            return Err(std::io::Error::other("memo persistence must be set before the process starts"));
         }
+        let loaded_nodes = memo_persistence.load()?;
+        let num_loaded_nodes = loaded_nodes.len();
+        for (loaded_node,loaded_node_depth) in loaded_nodes {
+            let loaded_node_id = self.internal_state.identifier_generator.get_next();
+            // nodes from a previous run have been fully explored from the depth at which they were expanded
+            self.internal_state.node_memoizer.memoize_new_node(&loaded_node, loaded_node_id, loaded_node_depth);
+        }
+        self.memo_persistence = Some(memo_persistence);
+        self.memo_exploration_tracker = Some(MemoExplorationTracker::default());
+        Ok(num_loaded_nodes)
+    }
+
+    /** 
+     * Returns the error that occurred when the memo was last saved by its persistence, if any.
+     * This includes the case where the memo is not saved because the process has been terminated
+     * (by its global state) before its exploration was completed.
+     * **/
+    pub fn get_memo_persistence_error(&self) -> Option<&std::io::Error> {
+        self.memo_persistence_error.as_ref()
+    }
+
+    /** 
+     * Reports on the memoization of nodes, in particular on whether or not
+     * subsumption checks are performed (they are disabled if only fingerprints of nodes are memoized).
+     * After the process has terminated, this includes the final number of memoized nodes.
+     * **/
+    pub fn get_memoization_report(&self) -> NodeMemoizationReport {
+        self.internal_state.node_memoizer.get_report()
+    }
+
+    /** 
+     * Gives access to the filters, e.g., to retrieve the data that stateful filters report after the process has terminated.
+     * **/
+    pub fn get_filters_manager(&self) -> &GenericFiltersManager<Conf> {
+        &self.filters_manager
+    }
+
+    /** 
+     * Enables or disables the filters with the given name (see [GenericFiltersManager](GenericFiltersManager)).
+     * Returns false if there are no such filters.
+     * **/
+    pub fn set_filter_enabled(&mut self, filter_name : &str, enabled : bool) -> bool {
+        self.filters_manager.set_filter_enabled(filter_name, enabled)
+    }
+
+    /** 
+     * Enables or disables the measure of the durations of the applications of the filters (disabled by default).
+     * **/
+    pub fn set_filter_timing(&mut self, measure_filter_durations : bool) {
+        self.filters_manager.set_filter_timing(measure_filter_durations)
     }
 
     pub fn get_logger(&self, logger_id : usize) -> Option<&dyn AbstractProcessLogger<Conf>> {
         self.loggers.get(logger_id).map(|x| &**x)
     }
 
+    /** 
+     * Returns the first logger of the given type, if any.
+     * This allows retrieving what a logger has collected (e.g., the explored graph built by an "ExploredGraphBuilder")
+     * once the process has terminated, regardless of its position among the loggers.
+     * **/
+    pub fn get_logger_of_type<Logger : 'static>(&self) -> Option<&Logger> {
+        self.loggers.iter().find_map(|x| x.as_any().downcast_ref::<Logger>())
+    }
+
+    /** 
+     * Runs the process until termination.
+     * Returns false if the process had already been started.
+     * **/
     pub fn start_process(
         &mut self
     ) -> bool {
@@ -127,6 +233,11 @@ impl<Conf : 'static + AbstractProcessConfiguration> GenericProcessManager<Conf>
             return false;
         }
 
+        self.filters_manager.initialize_filters(
+            &self.context_and_param,
+            &self.global_state
+        );
+
         loggers_initialize(
             self.loggers.iter_mut(),
             &self.context_and_param,
@@ -137,61 +248,219 @@ impl<Conf : 'static + AbstractProcessConfiguration> GenericProcessManager<Conf>
             self.internal_state.node_memoizer.is_memoized()
         );
 
+        // the nodes restored from the memo persistence are the only ones memoized before the initial node
+        if self.memo_persistence.is_some() {
+            for (restored_node,restored_entry) in self.internal_state.node_memoizer.get_memoized_nodes() {
+                loggers_new_node(
+                    self.loggers.iter_mut(),
+                    &self.context_and_param, 
+                    restored_entry.id, 
+                    restored_node
+                );
+            }
+        }
+
         let initial_node = self.internal_state.initial_node_if_not_yet_started.take().unwrap();
         
         let warrants_termination = {
             let new_node_id = self.internal_state.identifier_generator.get_next();
             self.pre_process_new_node(
                 &initial_node,
-                new_node_id
+                new_node_id,
+                0
             );
             self.process_new_node_and_check_termination(
                 initial_node,
-                new_node_id
+                new_node_id,
+                0
             )
         };
 
-        if !warrants_termination {
+        let warrants_termination = warrants_termination || self.process_queued_steps();
 
-            'process_step_loop : while let Some(
-                (step_to_process,mut opt_parent_node)
-            ) = self.delegate.extract_from_queue() {
-                
-                {
-                    // this is isolated to avoid borrow checker problems
-
-                    let parent_node =
-                    opt_parent_node.as_mut().unwrap_or_else(|| self.delegate.get_mut_memorized_node(step_to_process.parent_node_id));
-                    
-                    // we will process the step that may be fired from the parent node
-                    // in any case, we update the parent node's remainign to process childrens
-                    parent_node.remaining_child_steps_ids_to_process.remove(&step_to_process.id_as_potential_step_from_parent);
+        self.terminate_process(warrants_termination);
+
+        // the process has terminated
+        true 
+    }
+
+    /** 
+     * Resumes the exploration from the unexplored frontier i.e., from the nodes and steps that have been deferred by filters
+     * (see [GenericFiltersManager](GenericFiltersManager) and [DeferredFrontier](DeferredFrontier)).
+     * Filters may be relaxed or disabled beforehand, so that what has been deferred can be explored further.
+     * 
+     * The part of the graph that has already been explored is not explored again:
+     * deferred nodes and steps are resumed as described in [DeferredNode](DeferredNode) and [DeferredStep](DeferredStep).
+     * The filters are notified via "on_resume" and keep their statistics, while the loggers are notified via "log_resume".
+     * As for "start_process", upon termination, the loggers are notified of the filters summary and of the termination.
+     * 
+     * Returns false if the process has not yet been started, if it has been terminated before all the queued steps were processed
+     * or if the unexplored frontier is empty.
+     * **/
+    pub fn resume_from_deferred_frontier(
+        &mut self
+    ) -> bool {
+
+        if self.internal_state.initial_node_if_not_yet_started.is_some() 
+            || self.delegate.has_pending_steps()
+            || self.internal_state.deferred_frontier.is_empty() {
+            return false;
+        }
+
+        let deferred_frontier = std::mem::take(&mut self.internal_state.deferred_frontier);
+        // the parents of the steps that are resumed are notified once all these steps are processed
+        // unless some are deferred again
+        self.internal_state.parents_of_deferred_steps.clear();
+
+        self.filters_manager.resume_filters(
+            &self.context_and_param,
+            &self.global_state
+        );
+
+        loggers_resume(
+            self.loggers.iter_mut(),
+            &self.context_and_param,
+            &self.global_state
+        );
+
+        let mut warrants_termination = false;
+        for deferred_node in deferred_frontier.nodes {
+            if warrants_termination {
+                // what has not been resumed remains in the unexplored frontier
+                self.internal_state.deferred_frontier.nodes.push(deferred_node);
+                continue;
+            }
+            let DeferredNode { node_id, node, depth, next_steps } = deferred_node;
+            warrants_termination = match next_steps {
+                Some(next_steps) => {
+                    // the node has been deferred by node post filters after its next steps were collected
+                    self.global_state.warrants_termination_of_the_process(&self.context_and_param)
+                    || self.process_next_steps_and_check_termination(node, node_id, depth, next_steps)
+                },
+                None => {
+                    self.process_new_node_and_check_termination(node, node_id, depth)
                 }
+            };
+        }
 
-                // we need an immutable reference to the parent node
-                // but it may be under self.delegate
-                // so then when calling "self.process_step_and_check_termination(step_to_process,parent_node)"
-                // we run into borrow checker problem
-                // for now the solution is to clone the node even though not ideal
-                let parent_node_clone = match opt_parent_node {
-                    None => {
-                        self.delegate.get_memorized_node(step_to_process.parent_node_id).clone()
-                    },
-                    Some(x) => {
-                        x
-                    }
-                };
-
-                let warrants_termination_inner = self.process_step_and_check_termination(
-                    step_to_process,
-                    &parent_node_clone
+        if warrants_termination {
+            for deferred_step in &deferred_frontier.steps {
+                self.internal_state.parents_of_deferred_steps.insert(deferred_step.parent_node_id);
+            }
+            self.internal_state.deferred_frontier.steps.extend(deferred_frontier.steps);
+        } else {
+            // the deferred steps are enqueued again, grouped by parent node
+            // the child nodes of the steps deferred by edge filters are kept aside so that these steps are not fired again
+            self.internal_state.resumed_child_nodes.clear();
+            let mut deferred_steps_by_parent = BTreeMap::new();
+            for deferred_step in deferred_frontier.steps {
+                let (parent_node,to_enqueue) = deferred_steps_by_parent.entry(deferred_step.parent_node_id).or_insert_with(
+                    || (MemorizedNode::new(deferred_step.parent_node, deferred_step.parent_depth, HashSet::new()), vec![])
                 );
-                if warrants_termination_inner {
-                    break 'process_step_loop;
+                let id_as_potential_step_from_parent = (to_enqueue.len() as u32) + 1;
+                parent_node.remaining_child_steps_ids_to_process.insert(id_as_potential_step_from_parent);
+                if let Some(child_node) = deferred_step.child_node {
+                    self.internal_state.resumed_child_nodes.insert(
+                        (deferred_step.parent_node_id,id_as_potential_step_from_parent), 
+                        child_node
+                    );
                 }
+                to_enqueue.push(
+                    EnqueuedStep::new(
+                        deferred_step.parent_node_id, 
+                        id_as_potential_step_from_parent, 
+                        deferred_step.step
+                    )
+                );
+            }
+            for (parent_node_id,(parent_node,to_enqueue)) in deferred_steps_by_parent {
+                self.delegate.enqueue_new_steps(
+                    parent_node,
+                    parent_node_id,
+                    to_enqueue
+                );
             }
+            warrants_termination = self.process_queued_steps();
+        }
 
+        self.terminate_process(warrants_termination);
+        true
+    }
+
+    /** 
+     * Reports on the unexplored frontier i.e., on the nodes and steps that have been deferred by filters.
+     * **/
+    pub fn get_unexplored_frontier_report(&self) -> UnexploredFrontierReport {
+        self.internal_state.deferred_frontier.get_report()
+    }
+
+    /** 
+     * Gives access to the nodes and steps that have been deferred by filters.
+     * **/
+    pub fn get_deferred_frontier(&self) -> &DeferredFrontier<Conf> {
+        &self.internal_state.deferred_frontier
+    }
+
+    /** 
+     * Processes the steps in the queue until it is empty or until the process warrants termination.
+     * Returns true in the latter case.
+     * **/
+    fn process_queued_steps(&mut self) -> bool {
+        while let Some(
+            (step_to_process,mut opt_parent_node)
+        ) = self.delegate.extract_from_queue() {
+            
+            {
+                // this is isolated to avoid borrow checker problems
+
+                let parent_node =
+                opt_parent_node.as_mut().unwrap_or_else(|| self.delegate.get_mut_memorized_node(step_to_process.parent_node_id));
+                
+                // we will process the step that may be fired from the parent node
+                // in any case, we update the parent node's remainign to process childrens
+                parent_node.remaining_child_steps_ids_to_process.remove(&step_to_process.id_as_potential_step_from_parent);
+            }
+
+            // we need an immutable reference to the parent node
+            // but it may be under self.delegate
+            // so then when calling "self.process_step_and_check_termination(step_to_process,parent_node)"
+            // we run into borrow checker problem
+            // for now the solution is to clone the node even though not ideal
+            let parent_node_clone = match opt_parent_node {
+                None => {
+                    self.delegate.get_memorized_node(step_to_process.parent_node_id).clone()
+                },
+                Some(x) => {
+                    x
+                }
+            };
+
+            let warrants_termination_inner = self.process_step_and_check_termination(
+                step_to_process,
+                &parent_node_clone
+            );
+            if warrants_termination_inner {
+                return true;
+            }
         }
+        false
+    }
+
+    /** 
+     * Notifies the filters and the loggers of the termination of the process and saves the memo if it is persisted.
+     * The memo is not saved if the global state has warranted termination before the exploration was completed.
+     * **/
+    fn terminate_process(&mut self, warrants_termination : bool) {
+        self.filters_manager.terminate_filters(
+            &self.context_and_param,
+            &self.global_state
+        );
+
+        loggers_filters_summary(
+            self.loggers.iter_mut(),
+            &self.context_and_param,
+            &self.filters_manager.get_filters_info()
+        );
 
         loggers_terminate_process(
             self.loggers.iter_mut(),
@@ -199,8 +468,23 @@ impl<Conf : 'static + AbstractProcessConfiguration> GenericProcessManager<Conf>
             &self.global_state
         );
 
-        // the process has terminated successfully
-        true 
+        // the fully explored memoized nodes are saved for a warm start of later runs
+        if let (Some(memo_persistence),Some(memo_exploration_tracker)) = (&self.memo_persistence,&self.memo_exploration_tracker) {
+            self.memo_persistence_error = if warrants_termination {
+                Some(std::io::Error::other("the process has been terminated before its exploration was completed so the memo is not saved"))
+            } else {
+                let deferred_frontier = &self.internal_state.deferred_frontier;
+                let partially_explored = memo_exploration_tracker.get_partially_explored_nodes(
+                    deferred_frontier.nodes.iter().map(|x| x.node_id)
+                        .chain(deferred_frontier.steps.iter().map(|x| x.parent_node_id))
+                );
+                memo_persistence.save(
+                    self.internal_state.node_memoizer.get_memoized_nodes().into_iter()
+                        .filter(|(_,memoized_entry)| !partially_explored.contains(&memoized_entry.id))
+                        .map(|(memoized_node,memoized_entry)| (memoized_node,memoized_entry.depth))
+                ).err()
+            };
+        }
     }
 
     
@@ -211,43 +495,54 @@ impl<Conf : 'static + AbstractProcessConfiguration> GenericProcessManager<Conf>
         parent_node : &MemorizedNode<Conf::DomainSpecificNode>
     ) -> bool {
         let mut step_to_process = step_to_process;
-        // apply the step filters
-        let warrants_termination = match self.filters_manager.apply_step_filters(
-            &self.context_and_param,
-            &self.global_state,
-            &parent_node.domain_specific_node,
-            &step_to_process.domain_specific_step
-        ) {
-            Some(filtration_result) => {
+        let parent_node_id = step_to_process.parent_node_id;
+        let id_as_potential_step_from_parent = step_to_process.id_as_potential_step_from_parent;
+        // a step that has been deferred by edge filters has already been fired before it was deferred
+        // so, upon resuming, we reuse the child node it yielded rather than firing it again
+        let opt_already_fired_child_node = self.internal_state.resumed_child_nodes.remove(
+            &(parent_node_id,id_as_potential_step_from_parent)
+        );
+        let warrants_termination = if let Some(successor_node) = opt_already_fired_child_node {
+            self.process_transition_and_check_termination(
+                step_to_process,
+                parent_node,
+                successor_node
+            )
+        } else {
+            // apply the step filters
+            let step_filtration_outcome = self.filters_manager.apply_step_filters(
+                &self.context_and_param,
+                &self.global_state,
+                &parent_node.domain_specific_node,
+                parent_node_id,
+                parent_node.depth,
+                &step_to_process.domain_specific_step
+            );
+            if step_filtration_outcome.is_filtered() {
                 // here, a filter was activated
                 // this means that we won't explore further the successors from this specific step
                 // ***
-                // below we notify the loggers
-                let filtration_result_id = self.internal_state.identifier_generator.get_next();
-                loggers_filtered(
-                    self.loggers.iter_mut(), 
-                    &self.context_and_param,
-                    step_to_process.parent_node_id,
-                    filtration_result_id, 
-                    &filtration_result
-                );
-                // and we update the global state
-                self.global_state.update_on_filtered(
-                    &self.context_and_param,
+                // below we notify the loggers and update the global state
+                let warrants_termination = self.handle_step_filtration_results_and_check_termination(
+                    parent_node_id,
                     &parent_node.domain_specific_node,
-                    &filtration_result
+                    &step_to_process.domain_specific_step,
+                    step_filtration_outcome.filtration_results,
+                    step_filtration_outcome.is_deferred
                 );
-                // the filtration may warrant process termination
-                self.global_state.warrants_termination_of_the_process(&self.context_and_param)
-            },
-            None => {
+                // unless the step is deferred
+                if step_filtration_outcome.is_deferred {
+                    self.defer_step(
+                        parent_node_id,
+                        parent_node,
+                        step_to_process.domain_specific_step,
+                        None
+                    );
+                }
+                warrants_termination
+            } else {
                 // here there are no filter that prevent the firing of the step
                 // ***
-                // because we can process it, this means that the parent node of the step (from which the step is fired)
-                // is guaranteed to have at least one child
-                // thus we update the tracker
-                self.internal_state.node_has_processed_child_tracker.insert(step_to_process.id_as_potential_step_from_parent);
-                // ***
                 // processing the step yields a successor node
                 // thus we process it to get the successor node
                 let successor_node = Conf::AlgorithmOperationHandler::process_new_step(
@@ -256,72 +551,354 @@ impl<Conf : 'static + AbstractProcessConfiguration> GenericProcessManager<Conf>
                     &parent_node.domain_specific_node,
                     &mut step_to_process.domain_specific_step
                 );
-                // now, if the memoization option is active,
-                // we check if this node has already been reached previously
-                // and return the id of the successor node
-                let (successor_node_id,check_termination) = match self.internal_state.node_memoizer.check_memo(&successor_node) {
-                    Some(memoized_node_id) => {
-                        // here the sucessor node is already known and memoized, so we return its unique id
-                        // also because the global state is not updated, termination is not warranted
-                        (memoized_node_id,false)
-                    },
-                    None => {
-                        // here the successor node is entirely new
-                        // so we create a new unique identifier
-                        let new_node_id = self.internal_state.identifier_generator.get_next();
-                        // we pre-process the new node
-                        self.pre_process_new_node(
-                            &successor_node,
-                            new_node_id
-                        );
-                        // here the fact that we have a new node
-                        // requires us to check termination
-                        (new_node_id,true)
-                    },
-                };
-                // now that we have the "successor_node_id", we can log the new step
-                loggers_new_step(
-                    self.loggers.iter_mut(),
-                    &self.context_and_param,
-                    step_to_process.parent_node_id,
-                    &step_to_process.domain_specific_step,
-                    successor_node_id,
-                    &successor_node
-                );
-                // ***
-                // and we propagate "warrants_termination"
-                if check_termination {
-                    // here we process the new node further
-                    // and incidentally check termination
-                    self.process_new_node_and_check_termination(
-                        successor_node,
-                        successor_node_id
-                    )
-                } else {
-                    false
-                }
+                self.process_transition_and_check_termination(
+                    step_to_process,
+                    parent_node,
+                    successor_node
+                )
             }
         };
         // ***
         if parent_node.remaining_child_steps_ids_to_process.is_empty() {
             let parent_had_at_least_one_processed_child = self.internal_state.node_has_processed_child_tracker.remove(
-                &step_to_process.id_as_potential_step_from_parent
+                &id_as_potential_step_from_parent
             );
             if !parent_had_at_least_one_processed_child {
                 // for the HCS queue to know the node id'ed by parent_id is terminal
                 self.delegate.queue_set_last_reached_has_no_child();
             }
-            loggers_notify_last_child_step_of_node_processed(
-                self.loggers.iter_mut(),
-                &self.context_and_param,
-                step_to_process.parent_node_id
-            )
+            // if some of the steps of the parent node have been deferred, its last child step is yet to be processed
+            // this is notified once (and if) these steps are resumed
+            if !self.internal_state.parents_of_deferred_steps.contains(&parent_node_id) {
+                self.filters_manager.notify_node_children_processed(
+                    &self.context_and_param,
+                    parent_node_id
+                );
+                loggers_notify_last_child_step_of_node_processed(
+                    self.loggers.iter_mut(),
+                    &self.context_and_param,
+                    parent_node_id
+                )
+            }
         }
         // and we propagate "warrants_termination"
         warrants_termination
     }
 
 
+    /** 
+     * Processes the transition from the parent node to the successor node yielded by firing the step.
+     * We separate this code from "process_step_and_check_termination"
+     * so that steps deferred by edge filters can be resumed without being fired again.
+     * **/
+    fn process_transition_and_check_termination(
+        &mut self,
+        step_to_process : EnqueuedStep<Conf::DomainSpecificStep>,
+        parent_node : &MemorizedNode<Conf::DomainSpecificNode>,
+        successor_node : Conf::DomainSpecificNode
+    ) -> bool {
+        // we apply the edge filters on the transition from the parent node to the successor node
+        let edge_filtration_outcome = self.filters_manager.apply_edge_filters(
+            &self.context_and_param,
+            &self.global_state,
+            &parent_node.domain_specific_node,
+            parent_node.depth,
+            &step_to_process.domain_specific_step,
+            &successor_node
+        );
+        if edge_filtration_outcome.is_filtered() {
+            // here, an edge filter was activated
+            // so the successor node is discarded before memoization
+            // ***
+            // below we notify the loggers and update the global state
+            let warrants_termination = self.handle_edge_filtration_results_and_check_termination(
+                step_to_process.parent_node_id,
+                &parent_node.domain_specific_node,
+                &step_to_process.domain_specific_step,
+                &successor_node,
+                edge_filtration_outcome.filtration_results,
+                edge_filtration_outcome.is_deferred
+            );
+            // if the transition is deferred, it is kept so that the edge filters are applied to it again upon resuming the exploration
+            if edge_filtration_outcome.is_deferred {
+                self.defer_step(
+                    step_to_process.parent_node_id,
+                    parent_node,
+                    step_to_process.domain_specific_step,
+                    Some(successor_node)
+                );
+            }
+            warrants_termination
+        } else {
+            // because the transition is not filtered, this means that the parent node of the step (from which the step is fired)
+            // is guaranteed to have at least one child
+            // thus we update the tracker
+            self.internal_state.node_has_processed_child_tracker.insert(step_to_process.id_as_potential_step_from_parent);
+            // now, if the memoization option is active,
+            // we check if this node has already been reached previously
+            // and return the id of the successor node
+            let successor_depth = parent_node.depth + 1;
+            let (successor_node_id,is_new_node,opt_reexpanded_node_id) = match self.internal_state.node_memoizer.check_memo(
+                &successor_node,
+                successor_depth
+            ) {
+                NodeMemoCheck::Memoized(memoized_node_id) => {
+                    // here the sucessor node is already known and memoized, so we return its unique id
+                    (memoized_node_id,false,None)
+                },
+                NodeMemoCheck::NotMemoized => {
+                    // here the successor node is entirely new
+                    // so we create a new unique identifier
+                    let new_node_id = self.internal_state.identifier_generator.get_next();
+                    // we pre-process the new node
+                    self.pre_process_new_node(
+                        &successor_node,
+                        new_node_id,
+                        successor_depth
+                    );
+                    (new_node_id,true,None)
+                },
+                NodeMemoCheck::MemoizedDeeper(memoized_node_id) => {
+                    // here the successor node is already known but it is now reached at a smaller depth
+                    // so we expand it again, as a new node with a new unique identifier
+                    let new_node_id = self.internal_state.identifier_generator.get_next();
+                    self.pre_process_new_node(
+                        &successor_node,
+                        new_node_id,
+                        successor_depth
+                    );
+                    (new_node_id,true,Some(memoized_node_id))
+                }
+            };
+            if let Some(memo_exploration_tracker) = &mut self.memo_exploration_tracker {
+                memo_exploration_tracker.add_edge(step_to_process.parent_node_id, successor_node_id);
+            }
+            // now that we have the "successor_node_id", we can log the new step
+            loggers_new_step(
+                self.loggers.iter_mut(),
+                &self.context_and_param,
+                step_to_process.parent_node_id,
+                &step_to_process.domain_specific_step,
+                successor_node_id,
+                &successor_node
+            );
+            if let Some(reexpanded_node_id) = opt_reexpanded_node_id {
+                loggers_node_reexpanded(
+                    self.loggers.iter_mut(),
+                    &self.context_and_param,
+                    reexpanded_node_id,
+                    successor_node_id,
+                    successor_depth
+                );
+            }
+            // ***
+            // and we propagate "warrants_termination"
+            if is_new_node {
+                // the step filters may keep data for the new node
+                self.filters_manager.notify_step_to_new_node(
+                    &self.context_and_param,
+                    step_to_process.parent_node_id,
+                    &step_to_process.domain_specific_step,
+                    successor_node_id
+                );
+                // here we process the new node further
+                // and incidentally check termination
+                self.process_new_node_and_check_termination(
+                    successor_node,
+                    successor_node_id,
+                    successor_depth
+                )
+            } else {
+                // here the step cycles back to a memoized node
+                // we notify the loggers of this revisit
+                loggers_memo_hit(
+                    self.loggers.iter_mut(),
+                    &self.context_and_param,
+                    step_to_process.parent_node_id,
+                    &step_to_process.domain_specific_step,
+                    successor_node_id
+                );
+                // and we update the global state
+                self.global_state.update_on_memo_hit(
+                    &self.context_and_param,
+                    &parent_node.domain_specific_node,
+                    &step_to_process.domain_specific_step,
+                    &successor_node
+                );
+                // the revisit may warrant process termination
+                self.global_state.warrants_termination_of_the_process(&self.context_and_param)
+            }
+        }
+    }
+
+
+    /** 
+     * Once node filters have been activated on a given node,
+     * each filtration result is notified to the loggers (with its own unique identifier)
+     * and used to update the global state.
+     * Unless the filtration is deferred, the exploration from that node is incomplete for good.
+     * Returns true if the updated global state warrants termination.
+     * **/
+    fn handle_filtration_results_and_check_termination(
+        &mut self,
+        node_id : u32,
+        node : &Conf::DomainSpecificNode,
+        filtration_results : Vec<Conf::FiltrationResult>,
+        is_deferred : bool
+    ) -> bool {
+        if let (false,Some(memo_exploration_tracker)) = (is_deferred,&mut self.memo_exploration_tracker) {
+            memo_exploration_tracker.add_filtered_node(node_id);
+        }
+        for filtration_result in filtration_results {
+            let filtration_result_id = self.internal_state.identifier_generator.get_next();
+            loggers_filtered(
+                self.loggers.iter_mut(), 
+                &self.context_and_param,
+                node_id,
+                filtration_result_id, 
+                &filtration_result
+            );
+            self.global_state.update_on_filtered(
+                &self.context_and_param,
+                node,
+                &filtration_result
+            );
+        }
+        // the filtrations may warrant process termination
+        self.global_state.warrants_termination_of_the_process(&self.context_and_param)
+    }
+
+
+    /** 
+     * Same as "handle_filtration_results_and_check_termination" but for filtration results
+     * yielded by step filters on a step fired from the parent node.
+     * **/
+    fn handle_step_filtration_results_and_check_termination(
+        &mut self,
+        parent_node_id : u32,
+        parent_node : &Conf::DomainSpecificNode,
+        step : &Conf::DomainSpecificStep,
+        filtration_results : Vec<Conf::FiltrationResult>,
+        is_deferred : bool
+    ) -> bool {
+        if let (false,Some(memo_exploration_tracker)) = (is_deferred,&mut self.memo_exploration_tracker) {
+            memo_exploration_tracker.add_filtered_node(parent_node_id);
+        }
+        for filtration_result in filtration_results {
+            let filtration_result_id = self.internal_state.identifier_generator.get_next();
+            loggers_step_filtered(
+                self.loggers.iter_mut(), 
+                &self.context_and_param,
+                parent_node_id,
+                step,
+                filtration_result_id, 
+                &filtration_result
+            );
+            self.global_state.update_on_filtered(
+                &self.context_and_param,
+                parent_node,
+                &filtration_result
+            );
+        }
+        // the filtrations may warrant process termination
+        self.global_state.warrants_termination_of_the_process(&self.context_and_param)
+    }
+
+
+    /** 
+     * Same as "handle_filtration_results_and_check_termination" but for filtration results
+     * yielded by edge filters on the transition from the parent node to the child node via the step.
+     * **/
+    fn handle_edge_filtration_results_and_check_termination(
+        &mut self,
+        parent_node_id : u32,
+        parent_node : &Conf::DomainSpecificNode,
+        step : &Conf::DomainSpecificStep,
+        child_node : &Conf::DomainSpecificNode,
+        filtration_results : Vec<Conf::FiltrationResult>,
+        is_deferred : bool
+    ) -> bool {
+        if let (false,Some(memo_exploration_tracker)) = (is_deferred,&mut self.memo_exploration_tracker) {
+            memo_exploration_tracker.add_filtered_node(parent_node_id);
+        }
+        for filtration_result in filtration_results {
+            let filtration_result_id = self.internal_state.identifier_generator.get_next();
+            loggers_edge_filtered(
+                self.loggers.iter_mut(), 
+                &self.context_and_param,
+                parent_node_id,
+                step,
+                child_node,
+                filtration_result_id, 
+                &filtration_result
+            );
+            self.global_state.update_on_filtered(
+                &self.context_and_param,
+                parent_node,
+                &filtration_result
+            );
+        }
+        // the filtrations may warrant process termination
+        self.global_state.warrants_termination_of_the_process(&self.context_and_param)
+    }
+
+
+    /** 
+     * Keeps a node that has been filtered by deferring filters in the unexplored frontier
+     * (with its next steps if these have already been collected).
+     * **/
+    fn defer_node(
+        &mut self,
+        node_id : u32,
+        node : Conf::DomainSpecificNode,
+        depth : u32,
+        next_steps : Option<Vec<Conf::DomainSpecificStep>>
+    ) {
+        // as far as the queue is concerned, the node has no children until the exploration is resumed
+        // the filters and loggers are however not notified of it, as the node may have children once resumed
+        self.delegate.queue_set_last_reached_has_no_child();
+        loggers_deferred(
+            self.loggers.iter_mut(),
+            &self.context_and_param,
+            node_id,
+            None
+        );
+        self.internal_state.deferred_frontier.nodes.push(
+            DeferredNode { node_id, node, depth, next_steps }
+        );
+    }
+
+
+    /** 
+     * Keeps a step that has been filtered by deferring filters in the unexplored frontier
+     * (with the child node it yielded if it has already been fired).
+     * **/
+    fn defer_step(
+        &mut self,
+        parent_node_id : u32,
+        parent_node : &MemorizedNode<Conf::DomainSpecificNode>,
+        step : Conf::DomainSpecificStep,
+        child_node : Option<Conf::DomainSpecificNode>
+    ) {
+        self.internal_state.parents_of_deferred_steps.insert(parent_node_id);
+        loggers_deferred(
+            self.loggers.iter_mut(),
+            &self.context_and_param,
+            parent_node_id,
+            Some(&step)
+        );
+        self.internal_state.deferred_frontier.steps.push(
+            DeferredStep { 
+                parent_node_id, 
+                parent_node : parent_node.domain_specific_node.clone(), 
+                parent_depth : parent_node.depth, 
+                step,
+                child_node
+            }
+        );
+    }
+
+
     /** 
      * We preprocess the new node that it to be considered.
      * We separate this code from "process_new_node_and_check_termination"
@@ -332,9 +909,49 @@ impl<Conf : 'static + AbstractProcessConfiguration> GenericProcessManager<Conf>
     fn pre_process_new_node(
         &mut self,
         new_node : &Conf::DomainSpecificNode,
-        new_node_id : u32) {
+        new_node_id : u32,
+        new_node_depth : u32) {
         // we notify the memoizer of the new node (actually memoizes only if the memoizer is active)
-        self.internal_state.node_memoizer.memoize_new_node(new_node,new_node_id);
+        let subsumed_nodes_ids = self.internal_state.node_memoizer.memoize_new_node(new_node,new_node_id,new_node_depth);
+        // with the sweep-line option, the memoized nodes behind the progress front are periodically forgotten
+        // the front consists of the new node and of the nodes that still have steps to process
+        if self.internal_state.node_memoizer.is_sweep_due() {
+            let new_node_progress = new_node.get_progress_measure();
+            let frontier_min_progress = match self.delegate.get_memorized_nodes_min_progress_measure() {
+                Some(memorized_min_progress) => memorized_min_progress.min(new_node_progress),
+                None => new_node_progress
+            };
+            self.internal_state.node_memoizer.sweep(frontier_min_progress);
+        }
+        // with antichain memoization, the nodes subsumed by the new one may have their remaining steps pruned
+        if self.internal_state.node_memoizer.prunes_queued_steps_of_subsumed_nodes() {
+            for subsumed_node_id in subsumed_nodes_ids {
+                // the exploration from the subsumed node is left to the new node
+                if let Some(memo_exploration_tracker) = &mut self.memo_exploration_tracker {
+                    memo_exploration_tracker.add_edge(subsumed_node_id, new_node_id);
+                }
+                if self.delegate.remove_memorized_node_and_queued_steps(subsumed_node_id).is_some() {
+                    // the remaining steps of the subsumed node won't be processed
+                    // so, from the point of view of the filters and loggers, its last child step has been processed
+                    self.filters_manager.notify_node_children_processed(
+                        &self.context_and_param,
+                        subsumed_node_id
+                    );
+                    loggers_notify_last_child_step_of_node_processed(
+                        self.loggers.iter_mut(),
+                        &self.context_and_param,
+                        subsumed_node_id
+                    );
+                }
+            }
+        }
+        if self.internal_state.node_memoizer.is_memoized() {
+            loggers_memo_size(
+                self.loggers.iter_mut(),
+                &self.context_and_param,
+                self.internal_state.node_memoizer.get_num_memoized_nodes()
+            );
+        }
         // we notify the loggers of the new node
         loggers_new_node(
             self.loggers.iter_mut(),
@@ -353,7 +970,8 @@ impl<Conf : 'static + AbstractProcessConfiguration> GenericProcessManager<Conf>
     fn process_new_node_and_check_termination(
         &mut self,
         new_node : Conf::DomainSpecificNode,
-        new_node_id : u32
+        new_node_id : u32,
+        new_node_depth : u32
     ) -> bool {
         // updating the global state may warrant termination
         if self.global_state.warrants_termination_of_the_process(&self.context_and_param) {
@@ -364,130 +982,148 @@ impl<Conf : 'static + AbstractProcessConfiguration> GenericProcessManager<Conf>
         // so we process the new node further
         // ***
         // we apply the node pre filters
-        let (has_no_children,warrants_termination) = match self.filters_manager.apply_node_pre_filters(
+        let node_pre_filtration_outcome = self.filters_manager.apply_node_pre_filters(
             &self.context_and_param,
             &self.global_state,
-            &new_node
-        ) {
-            Some(filtration_result) => {
-                // here, a filter was activated
-                // this means that we won't explore further the successors from this specific node
-                // ***
-                // below we notify the loggers of the filtration
-                let filtration_result_id = self.internal_state.identifier_generator.get_next();
-                loggers_filtered(
-                    self.loggers.iter_mut(), 
-                    &self.context_and_param,
-                    new_node_id,
-                    filtration_result_id, 
-                    &filtration_result
-                );
-                // and we update the global state
-                self.global_state.update_on_filtered(
-                    &self.context_and_param,
-                    &new_node,
-                    &filtration_result
-                );
-                // the filtration may warrant process termination
-                let warrants_termination = self.global_state.warrants_termination_of_the_process(&self.context_and_param);
-                // ***
-                (true,warrants_termination)
-            },
-            None => {
-                // here no node pre filters were activated
-                // so we can collect the next steps that may be fired from that node
-                let next_steps = Conf::AlgorithmOperationHandler::collect_next_steps(
-                    &self.context_and_param,
-                    &mut self.global_state,
-                    &new_node
-                );
-                // we update the global state
-                self.global_state.update_on_next_steps_collected_reached(
-                    &self.context_and_param, 
-                    &new_node, 
-                    &next_steps,
-                );
-                // we apply the node post filters
-                match self.filters_manager.apply_node_post_filters(
-                    &self.context_and_param,
-                    &self.global_state,
-                    &new_node,
-                    &next_steps
-                ) {
-                    Some(filtration_result) => {
-                        // here, a filter was activated
-                        // this means that we won't explore further the successors from this specific node
-                        // ***
-                        // below we notify the loggers of the filtration
-                        let filtration_result_id = self.internal_state.identifier_generator.get_next();
-                        loggers_filtered(
-                            self.loggers.iter_mut(), 
-                            &self.context_and_param,
-                            new_node_id,
-                            filtration_result_id, 
-                            &filtration_result
-                        );
-                        // and we update the global state
-                        self.global_state.update_on_filtered(
-                            &self.context_and_param,
-                            &new_node,
-                            &filtration_result
-                        );
-                        // the filtration may warrant process termination
-                        let warrants_termination = self.global_state.warrants_termination_of_the_process(&self.context_and_param);
-                        // ***
-                        (true,warrants_termination)
-                    },
-                    None => {
-                        let warrants_termination = false;
-                        // here no node post filters were activated
-                        // this means we can enqueue all these next steps
-                        // if there are any
-                        let has_no_children = if next_steps.is_empty() {
-                            true
-                        } else {
-                            let mut to_enqueue = vec![];
-                            let mut max_id_of_child = 0;
-                            for domain_specific_step in next_steps {
-                                max_id_of_child += 1;
-                                to_enqueue.push( 
-                                    EnqueuedStep::new(
-                                        new_node_id, 
-                                        max_id_of_child, 
-                                        domain_specific_step
-                                    )
-                                );
-                            }
-                            let remaining_ids_to_process : HashSet<u32> = HashSet::from_iter((1..(max_id_of_child+1)).collect::<Vec<u32>>().iter().cloned() );
-                            let memorized_node = MemorizedNode::new(
-                                new_node,
-                                remaining_ids_to_process
-                            );
-                            self.delegate.enqueue_new_steps(
-                                memorized_node,
-                                new_node_id,
-                                to_enqueue
-                            );
-                            false
-                        };
-                        (has_no_children,warrants_termination)
-                    }
-                }
+            &new_node,
+            new_node_depth
+        );
+        if node_pre_filtration_outcome.is_filtered() {
+            // here, a filter was activated
+            // this means that we won't explore further the successors from this specific node
+            // ***
+            // below we notify the loggers of the filtration and update the global state
+            let warrants_termination = self.handle_filtration_results_and_check_termination(
+                new_node_id,
+                &new_node,
+                node_pre_filtration_outcome.filtration_results,
+                node_pre_filtration_outcome.is_deferred
+            );
+            // unless the node is deferred, it has no children
+            if node_pre_filtration_outcome.is_deferred {
+                self.defer_node(new_node_id, new_node, new_node_depth, None);
+            } else {
+                self.notify_node_without_children(new_node_id);
             }
-        };
-        if has_no_children {
-            // the node does not have any children : it is a terminal node
-            // notifies the queue
-            self.delegate.queue_set_last_reached_has_no_child();
-            // notifies the loggers
-            loggers_notify_node_without_children(
-                self.loggers.iter_mut(),
+            // ***
+            warrants_termination
+        } else {
+            // here no node pre filters were activated
+            // so we can collect the next steps that may be fired from that node
+            let next_steps = Conf::AlgorithmOperationHandler::collect_next_steps(
                 &self.context_and_param,
-                new_node_id
+                &mut self.global_state,
+                &new_node
+            );
+            // we update the global state
+            self.global_state.update_on_next_steps_collected_reached(
+                &self.context_and_param, 
+                &new_node, 
+                &next_steps,
             );
+            self.process_next_steps_and_check_termination(
+                new_node,
+                new_node_id,
+                new_node_depth,
+                next_steps
+            )
         }
-        // and we propagate "warrants_termination"
-        warrants_termination
     }
 
-}
\ No newline at end of file
+
+    /** 
+     * Applies the node post filters on the next steps that have been collected from a node and enqueues them.
+     * We separate this code from "process_new_node_and_check_termination"
+     * so that nodes deferred by node post filters can be resumed without collecting their next steps again.
+     * **/
+    fn process_next_steps_and_check_termination(
+        &mut self,
+        new_node : Conf::DomainSpecificNode,
+        new_node_id : u32,
+        new_node_depth : u32,
+        next_steps : Vec<Conf::DomainSpecificStep>
+    ) -> bool {
+        // we apply the node post filters
+        let node_post_filtration_outcome = self.filters_manager.apply_node_post_filters(
+            &self.context_and_param,
+            &self.global_state,
+            &new_node,
+            new_node_depth,
+            &next_steps
+        );
+        if node_post_filtration_outcome.is_filtered() {
+            // here, a filter was activated
+            // this means that we won't explore further the successors from this specific node
+            // ***
+            // below we notify the loggers of the filtration and update the global state
+            let warrants_termination = self.handle_filtration_results_and_check_termination(
+                new_node_id,
+                &new_node,
+                node_post_filtration_outcome.filtration_results,
+                node_post_filtration_outcome.is_deferred
+            );
+            // unless the node is deferred (with its next steps), it has no children
+            if node_post_filtration_outcome.is_deferred {
+                self.defer_node(new_node_id, new_node, new_node_depth, Some(next_steps));
+            } else {
+                self.notify_node_without_children(new_node_id);
+            }
+            // ***
+            warrants_termination
+        } else {
+            // here no node post filters were activated
+            // this means we can enqueue all these next steps
+            // if there are any
+            if next_steps.is_empty() {
+                self.notify_node_without_children(new_node_id);
+            } else {
+                let mut to_enqueue = vec![];
+                let mut max_id_of_child = 0;
+                for domain_specific_step in next_steps {
+                    max_id_of_child += 1;
+                    to_enqueue.push( 
+                        EnqueuedStep::new(
+                            new_node_id, 
+                            max_id_of_child, 
+                            domain_specific_step
+                        )
+                    );
+                }
+                let remaining_ids_to_process : HashSet<u32> = HashSet::from_iter((1..(max_id_of_child+1)).collect::<Vec<u32>>().iter().cloned() );
+                let memorized_node = MemorizedNode::new(
+                    new_node,
+                    new_node_depth,
+                    remaining_ids_to_process
+                );
+                self.delegate.enqueue_new_steps(
+                    memorized_node,
+                    new_node_id,
+                    to_enqueue
+                );
+            }
+            false
+        }
+    }
+
+
+    /** 
+     * Notifies the queue, the filters and the loggers that a node does not have any children : it is a terminal node.
+     * This is not the case of deferred nodes, which may have children once the exploration is resumed.
+     * **/
+    fn notify_node_without_children(&mut self, node_id : u32) {
+        // notifies the queue
+        self.delegate.queue_set_last_reached_has_no_child();
+        // notifies the filters
+        self.filters_manager.notify_node_children_processed(
+            &self.context_and_param,
+            node_id
+        );
+        // notifies the loggers
+        loggers_notify_node_without_children(
+            self.loggers.iter_mut(),
+            &self.context_and_param,
+            node_id
+        );
+    }
+
+}
@@ -0,0 +1,195 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::PathBuf;
+
+use super::config::AbstractProcessConfiguration;
+
+
+
+/** 
+ * Serialization of domain-specific nodes into bytes and deserialization back from bytes.
+ * This is required to save the memoized nodes to a file and to load them back.
+ * **/
+pub trait AbstractNodeSerializer<Conf : AbstractProcessConfiguration> {
+
+    fn serialize_node(&self, node : &Conf::DomainSpecificNode) -> Vec<u8>;
+
+    /** 
+     * Returns None if the bytes do not encode a node.
+     * **/
+    fn deserialize_node(&self, bytes : &[u8]) -> Option<Conf::DomainSpecificNode>;
+
+}
+
+
+
+/** 
+ * Persistence of the memoized nodes across distinct runs of a process.
+ * 
+ * The memo is loaded from the file (if it exists) before the process starts
+ * and saved into that same file once the process terminates.
+ * This allows a warm start of the process : 
+ * any node that is included in a node memoized during a previous run is not explored again.
+ * 
+ * Only the memoized nodes whose descendants have all been explored are saved, that is,
+ * the nodes from which no node or step has been filtered (directly or via any of their successors).
+ * Nothing is saved if the process has been terminated (by its global state) before its exploration was completed.
+ * 
+ * Nodes that are loaded from the file are given new unique identifiers
+ * and are memoized at the depth at which they had been expanded,
+ * so that, with depth-aware memoization, they are expanded again if they are reached at a smaller depth.
+ * Once the process starts, loggers are notified of these restored nodes via "log_new_node" (right after "log_initialize")
+ * so that steps targeting them via "log_new_step" refer to known nodes.
+ * Unlike the nodes reached during the current run, restored nodes do not update the global state.
+ * 
+ * The file consists of a sequence of nodes, each encoded
+ * as its depth and its length in bytes (both as little endian u32) followed by its serialization.
+ * **/
+pub struct NodeMemoPersistence<Conf : AbstractProcessConfiguration> {
+    pub file_path : PathBuf,
+    pub serializer : Box<dyn AbstractNodeSerializer<Conf>>
+}
+
+impl<Conf : AbstractProcessConfiguration> NodeMemoPersistence<Conf> {
+
+    pub fn new(
+        file_path: PathBuf, 
+        serializer: Box<dyn AbstractNodeSerializer<Conf>>
+    ) -> Self {
+        Self { file_path, serializer }
+    }
+
+    /** 
+     * Returns the nodes saved in the file, with their depths, or an empty vector if there is no such file.
+     * A file that ends in the middle of a node is reported as an "UnexpectedEof" error.
+     * **/
+    pub(crate) fn load(&self) -> std::io::Result<Vec<(Conf::DomainSpecificNode,u32)>> {
+        let file = match File::open(&self.file_path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Ok(vec![]);
+            },
+            Err(e) => {
+                return Err(e);
+            }
+        };
+        let mut reader = BufReader::new(file);
+        let mut nodes = vec![];
+        let mut depth_bytes = [0u8;4];
+        let mut length_bytes = [0u8;4];
+        loop {
+            match reader.read_exact(&mut depth_bytes) {
+                Ok(_) => {},
+                Err(e) if e.kind() == ErrorKind::UnexpectedEof => {
+                    break;
+                },
+                Err(e) => {
+                    return Err(e);
+                }
+            }
+            reader.read_exact(&mut length_bytes)?;
+            // the length is read from the file so it is not trusted for a preallocation
+            let node_length = u32::from_le_bytes(length_bytes) as u64;
+            let mut node_bytes = vec![];
+            if (&mut reader).take(node_length).read_to_end(&mut node_bytes)? as u64 != node_length {
+                return Err(
+                    std::io::Error::new(ErrorKind::UnexpectedEof, "the memo file ends in the middle of a node")
+                );
+            }
+            match self.serializer.deserialize_node(&node_bytes) {
+                Some(node) => {
+                    nodes.push((node,u32::from_le_bytes(depth_bytes)));
+                },
+                None => {
+                    return Err(
+                        std::io::Error::new(ErrorKind::InvalidData, "could not deserialize a memoized node")
+                    );
+                }
+            }
+        }
+        Ok(nodes)
+    }
+
+    pub(crate) fn save<'a>(
+        &self, 
+        nodes : impl Iterator<Item = (&'a Conf::DomainSpecificNode,u32)>
+    ) -> std::io::Result<()> where Conf::DomainSpecificNode : 'a {
+        let mut writer = BufWriter::new(File::create(&self.file_path)?);
+        for (node,depth) in nodes {
+            let node_bytes = self.serializer.serialize_node(node);
+            writer.write_all(&depth.to_le_bytes())?;
+            writer.write_all(&(node_bytes.len() as u32).to_le_bytes())?;
+            writer.write_all(&node_bytes)?;
+        }
+        writer.flush()
+    }
+
+}
+
+
+
+/** 
+ * Keeps track of the nodes whose descendants have not all been explored,
+ * so that only fully explored nodes are saved by the [NodeMemoPersistence](NodeMemoPersistence).
+ * 
+ * For that purpose, it records, for each node, the nodes from which it is reached (including via memoized nodes).
+ * With antichain memoization, a node that is subsumed by a new node is recorded as reaching it,
+ * given that the exploration of its remaining steps is left to the new node.
+ * **/
+#[derive(Default)]
+pub(crate) struct MemoExplorationTracker {
+    /// for each node, the nodes from which it is reached
+    predecessors : HashMap<u32,Vec<u32>>,
+    /// the nodes from which the exploration has been cut by filters
+    filtered_nodes_ids : HashSet<u32>
+}
+
+impl MemoExplorationTracker {
+
+    pub fn add_edge(&mut self, origin_node_id : u32, target_node_id : u32) {
+        self.predecessors.entry(target_node_id).or_default().push(origin_node_id);
+    }
+
+    pub fn add_filtered_node(&mut self, node_id : u32) {
+        self.filtered_nodes_ids.insert(node_id);
+    }
+
+    /** 
+     * Returns the identifiers of the nodes from which the exploration has been cut
+     * (by filters that do not defer or by those given as "deferring_nodes_ids")
+     * and of all the nodes from which these can be reached.
+     * **/
+    pub fn get_partially_explored_nodes(
+        &self, 
+        deferring_nodes_ids : impl Iterator<Item = u32>
+    ) -> HashSet<u32> {
+        let mut partially_explored : HashSet<u32> = HashSet::new();
+        let mut to_visit : Vec<u32> = self.filtered_nodes_ids.iter().cloned().chain(deferring_nodes_ids).collect();
+        while let Some(node_id) = to_visit.pop() {
+            if partially_explored.insert(node_id) {
+                if let Some(predecessors) = self.predecessors.get(&node_id) {
+                    to_visit.extend(predecessors.iter().cloned());
+                }
+            }
+        }
+        partially_explored
+    }
+
+}
@@ -15,10 +15,16 @@ limitations under the License.
 */
 
 pub mod config;
+pub mod deferred_frontier;
 pub mod filter;
+pub mod filter_kinds;
+pub mod filter_registry;
+pub mod filter_statistics;
 pub mod handler;
 pub mod identifier;
 pub mod logger;
 pub mod manager;
+pub mod memo_persistence;
 pub mod node_memoizer;
-pub mod persistent_state;
\ No newline at end of file
+pub mod persistent_state;
+pub mod rendering;
\ No newline at end of file
@@ -15,56 +15,539 @@ limitations under the License.
 */
 
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 
 use super::config::{AbstractNodeKind, AbstractProcessConfiguration};
 
 
 
+/** 
+ * Options of the memoization of nodes.
+ * 
+ * When nodes are memoized, a newly reached node that is included (see "is_included_for_memoization")
+ * in an already memoized node is merged into the latter.
+ * 
+ * With the "antichain" option, the converse is also considered:
+ * when a newly reached node includes already memoized nodes, these weaker nodes are removed from the memo
+ * so that the set of memoized nodes is kept minimal.
+ * In that case, the steps that are still queued from these subsumed nodes 
+ * may also be pruned (with the "prune_queued_steps_of_subsumed_nodes" option)
+ * given that their successors are expected to be covered by those of the subsuming node.
+ * 
+ * Because several memoized nodes may include a newly reached node,
+ * the "subsumption_resolution" option determines which one is selected.
+ * 
+ * Note that inclusion is checked by comparing the newly reached node with each memoized node in turn.
+ * With the antichain option, this is done both when looking the node up and when memoizing it,
+ * so that the cost of reaching a node is linear in the number of memoized nodes
+ * (and that of the whole exploration quadratic).
+ * 
+ * With the "depth_aware" option, the smallest depth at which each memoized node has been expanded is kept.
+ * A node that is reached again at a strictly smaller depth is then expanded again, under a new identifier.
+ * This is required for the completeness of depth-bounded searches 
+ * (e.g., DFS with a filter on the depth) in which a node may first be reached (and cut off) deep in the graph.
+ * 
+ * The "storage" option determines whether the memo keeps the nodes themselves or only their fingerprints
+ * (see [NodeMemoStorage](NodeMemoStorage)).
+ * 
+ * With the "sweep_line_period" option, the memo is garbage collected as in the sweep-line method.
+ * This relies on the progress measure of the nodes (see "get_progress_measure"), 
+ * which never decreases from a node to its successors.
+ * Each time the given number of nodes has been memoized, the memoized nodes 
+ * whose progress measure is strictly smaller than that of all the nodes that still have steps to process are forgotten,
+ * given that they can never be reached again.
+ * **/
+pub struct NodeMemoizationOptions<Conf : AbstractProcessConfiguration> {
+    pub antichain : bool,
+    pub prune_queued_steps_of_subsumed_nodes : bool,
+    pub subsumption_resolution : MemoizationSubsumptionResolution<Conf>,
+    pub depth_aware : bool,
+    pub storage : NodeMemoStorage,
+    pub sweep_line_period : Option<u32>
+}
+
+impl<Conf : AbstractProcessConfiguration> NodeMemoizationOptions<Conf> {
+    pub fn new(
+        antichain: bool, 
+        prune_queued_steps_of_subsumed_nodes: bool,
+        subsumption_resolution : MemoizationSubsumptionResolution<Conf>,
+        depth_aware : bool,
+        storage : NodeMemoStorage,
+        sweep_line_period : Option<u32>
+    ) -> Self {
+        Self { 
+            antichain, 
+            prune_queued_steps_of_subsumed_nodes, 
+            subsumption_resolution, 
+            depth_aware, 
+            storage, 
+            sweep_line_period 
+        }
+    }
+}
+
+impl<Conf : AbstractProcessConfiguration> std::default::Default for NodeMemoizationOptions<Conf> {
+    fn default() -> Self {
+        Self { 
+            antichain: false, 
+            prune_queued_steps_of_subsumed_nodes: false, 
+            subsumption_resolution: MemoizationSubsumptionResolution::OldestIdentifier,
+            depth_aware: false,
+            storage: NodeMemoStorage::Nodes,
+            sweep_line_period: None
+        }
+    }
+}
+
+
+
+/** 
+ * What the memo keeps of each memoized node.
+ * 
+ * Keeping the nodes themselves allows checking the inclusion of newly reached nodes in memoized nodes
+ * (see "is_included_for_memoization").
+ * 
+ * Keeping only 64 or 128 bits fingerprints of the nodes (i.e., hash compaction) 
+ * greatly reduces the memory footprint of the memo, in particular for large nodes.
+ * However, only exact matching of nodes is then possible, 
+ * so that subsumption checks (and thus the antichain and subsumption resolution options) are disabled.
+ * Also, distinct nodes with the same fingerprint are wrongly considered to be the same,
+ * although the probability of such collisions is negligible (especially with 128 bits).
+ * **/
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum NodeMemoStorage {
+    Nodes,
+    Fingerprints64,
+    Fingerprints128
+}
+
+impl fmt::Display for NodeMemoStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeMemoStorage::Nodes => {
+                write!(f,"Nodes")
+            },
+            NodeMemoStorage::Fingerprints64 => {
+                write!(f,"Fingerprints64")
+            },
+            NodeMemoStorage::Fingerprints128 => {
+                write!(f,"Fingerprints128")
+            }
+        }
+    }
+}
+
+
+
+/** 
+ * Information about the memoization of nodes during a process.
+ * **/
+pub struct NodeMemoizationReport {
+    pub is_memoized : bool,
+    pub storage : NodeMemoStorage,
+    pub subsumption_checks_enabled : bool,
+    pub num_memoized_nodes : usize,
+    pub num_swept_nodes : usize
+}
+
+impl fmt::Display for NodeMemoizationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_memoized {
+            return write!(f,"no memoization");
+        }
+        write!(f,"memoization of {} ({} memoized)", self.storage, self.num_memoized_nodes)?;
+        if self.num_swept_nodes > 0 {
+            write!(f,", {} swept", self.num_swept_nodes)?;
+        }
+        if !self.subsumption_checks_enabled {
+            write!(f,", subsumption checks disabled (exact matching only)")?;
+        }
+        Ok(())
+    }
+}
+
+
+
+/** 
+ * Policy for selecting a memoized node among all those that include a newly reached node.
+ * Whatever the policy, the selection does not depend on the iteration order of the memo,
+ * so that the explored graph is the same from one run to another.
+ * 
+ * With "MostSpecific", the comparator orders memoized nodes from the most specific to the least specific
+ * (i.e., the selected node is the one that compares as Ordering::Less to all the others).
+ * Ties are resolved by selecting the node with the oldest identifier.
+ * **/
+pub enum MemoizationSubsumptionResolution<Conf : AbstractProcessConfiguration> {
+    OldestIdentifier,
+    NewestIdentifier,
+    MostSpecific(NodeSpecificityComparator<Conf>)
+}
+
+pub type NodeSpecificityComparator<Conf> = Box<
+    dyn Fn(
+        &<Conf as AbstractProcessConfiguration>::DomainSpecificNode,
+        &<Conf as AbstractProcessConfiguration>::DomainSpecificNode
+    ) -> Ordering
+>;
+
+impl<Conf : AbstractProcessConfiguration> MemoizationSubsumptionResolution<Conf> {
+
+    /** 
+     * Returns true if the candidate is to be selected over the currently selected memoized node.
+     * **/
+    fn prefers(
+        &self,
+        candidate : (&Conf::DomainSpecificNode,u32),
+        selected : (&Conf::DomainSpecificNode,u32)
+    ) -> bool {
+        match self {
+            MemoizationSubsumptionResolution::OldestIdentifier => {
+                candidate.1 < selected.1
+            },
+            MemoizationSubsumptionResolution::NewestIdentifier => {
+                candidate.1 > selected.1
+            },
+            MemoizationSubsumptionResolution::MostSpecific(comparator) => {
+                match comparator(candidate.0,selected.0) {
+                    Ordering::Less => true,
+                    Ordering::Greater => false,
+                    Ordering::Equal => candidate.1 < selected.1
+                }
+            }
+        }
+    }
+
+}
+
+
+
+
+/** 
+ * What is kept in the memo for each memoized node.
+ * **/
+pub(crate) struct NodeMemoEntry {
+    /// the unique identifier of the memoized node
+    pub id : u32,
+    /// the smallest depth at which the memoized node has been expanded
+    pub depth : u32,
+    /// the progress measure of the memoized node
+    pub progress : u64
+}
+
+/** 
+ * Result of looking up a newly reached node in the memo.
+ * **/
+pub(crate) enum NodeMemoCheck {
+    /// no memoized node includes the newly reached node
+    NotMemoized,
+    /// the newly reached node is included in the memoized node with the given id
+    Memoized(u32),
+    /// with the depth-aware option, the newly reached node is only included in memoized nodes 
+    /// that were expanded at a greater depth, the selected one having the given id 
+    MemoizedDeeper(u32)
+}
+
+/** 
+ * The memo itself, depending on the storage option.
+ * **/
+pub(crate) enum NodeMemo<Conf : AbstractProcessConfiguration> {
+    Nodes(HashMap<Conf::DomainSpecificNode,NodeMemoEntry>),
+    Fingerprints64(HashMap<u64,NodeMemoEntry>),
+    Fingerprints128(HashMap<u128,NodeMemoEntry>)
+}
+
+impl<Conf : AbstractProcessConfiguration> NodeMemo<Conf> {
+
+    fn new(storage : NodeMemoStorage) -> Self {
+        match storage {
+            NodeMemoStorage::Nodes => NodeMemo::Nodes(hashmap!{}),
+            NodeMemoStorage::Fingerprints64 => NodeMemo::Fingerprints64(hashmap!{}),
+            NodeMemoStorage::Fingerprints128 => NodeMemo::Fingerprints128(hashmap!{})
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            NodeMemo::Nodes(memo) => memo.len(),
+            NodeMemo::Fingerprints64(memo) => memo.len(),
+            NodeMemo::Fingerprints128(memo) => memo.len()
+        }
+    }
+
+    fn fingerprint_64(node : &Conf::DomainSpecificNode) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        node.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn fingerprint_128(node : &Conf::DomainSpecificNode) -> u128 {
+        // the upper bits are obtained from a differently salted hasher
+        let mut salted_hasher = DefaultHasher::new();
+        u64::MAX.hash(&mut salted_hasher);
+        node.hash(&mut salted_hasher);
+        ((salted_hasher.finish() as u128) << 64) | (Self::fingerprint_64(node) as u128)
+    }
+
+    fn get_exact_match(&self, node : &Conf::DomainSpecificNode) -> Option<&NodeMemoEntry> {
+        match self {
+            NodeMemo::Nodes(memo) => memo.get(node),
+            NodeMemo::Fingerprints64(memo) => memo.get(&Self::fingerprint_64(node)),
+            NodeMemo::Fingerprints128(memo) => memo.get(&Self::fingerprint_128(node))
+        }
+    }
+
+    /** 
+     * Forgets the memoized nodes with a progress measure strictly smaller than the given one.
+     * Returns the number of forgotten nodes.
+     * **/
+    fn sweep(&mut self, min_progress : u64) -> usize {
+        let initial_len = self.len();
+        match self {
+            NodeMemo::Nodes(memo) => memo.retain(|_,entry| entry.progress >= min_progress),
+            NodeMemo::Fingerprints64(memo) => memo.retain(|_,entry| entry.progress >= min_progress),
+            NodeMemo::Fingerprints128(memo) => memo.retain(|_,entry| entry.progress >= min_progress)
+        }
+        initial_len - self.len()
+    }
+
+    fn insert(&mut self, node : &Conf::DomainSpecificNode, entry : NodeMemoEntry) {
+        match self {
+            NodeMemo::Nodes(memo) => {
+                memo.insert(node.clone(),entry);
+            },
+            NodeMemo::Fingerprints64(memo) => {
+                memo.insert(Self::fingerprint_64(node),entry);
+            },
+            NodeMemo::Fingerprints128(memo) => {
+                memo.insert(Self::fingerprint_128(node),entry);
+            }
+        }
+    }
+
+}
+
+
+
+/** 
+ * Keeps track of the garbage collection of the memo with the sweep-line option.
+ * **/
+#[derive(Default)]
+pub(crate) struct SweepLineTracker {
+    /// number of nodes memoized since the last sweep
+    num_memoized_since_last_sweep : u32,
+    /// total number of nodes that have been forgotten
+    num_swept_nodes : usize
+}
+
+
+
 pub(crate) enum NodeMemoizer<Conf : AbstractProcessConfiguration> {
-    Memoizing(HashMap<Conf::DomainSpecificNode,u32>),
+    Memoizing(NodeMemo<Conf>,NodeMemoizationOptions<Conf>,SweepLineTracker),
     NotMemoizing
 }
 
 impl<Conf : AbstractProcessConfiguration> NodeMemoizer<Conf> {
 
-    pub fn new(is_memoized : bool) -> Self {
-        if is_memoized {
-            Self::Memoizing(hashmap!{})
-        } else {
-            Self::NotMemoizing
+    pub fn new(memoization_options : Option<NodeMemoizationOptions<Conf>>) -> Self {
+        match memoization_options {
+            Some(options) => {
+                Self::Memoizing(NodeMemo::new(options.storage),options,SweepLineTracker::default())
+            },
+            None => {
+                Self::NotMemoizing
+            }
         }
     }
 
     pub fn is_memoized(&self) -> bool {
         match &self {
-            NodeMemoizer::Memoizing(_) => true,
+            NodeMemoizer::Memoizing(..) => true,
             NodeMemoizer::NotMemoizing => false
         }
     }
 
-    pub fn check_memo(&self, to_look_up : &Conf::DomainSpecificNode) -> Option<u32> {
+    /** 
+     * Returns true if the memo keeps the nodes themselves, and not only their fingerprints.
+     * **/
+    pub fn keeps_nodes(&self) -> bool {
+        matches!(self, NodeMemoizer::Memoizing(NodeMemo::Nodes(_),_,_))
+    }
+
+    pub fn get_num_memoized_nodes(&self) -> usize {
+        match &self {
+            NodeMemoizer::Memoizing(memo,_,_) => memo.len(),
+            NodeMemoizer::NotMemoizing => 0
+        }
+    }
+
+    pub fn get_report(&self) -> NodeMemoizationReport {
+        match &self {
+            NodeMemoizer::Memoizing(memo,options,sweep_line_tracker) => {
+                NodeMemoizationReport { 
+                    is_memoized: true, 
+                    storage: options.storage, 
+                    subsumption_checks_enabled: self.keeps_nodes(), 
+                    num_memoized_nodes: memo.len(),
+                    num_swept_nodes: sweep_line_tracker.num_swept_nodes
+                }
+            },
+            NodeMemoizer::NotMemoizing => {
+                NodeMemoizationReport { 
+                    is_memoized: false, 
+                    storage: NodeMemoStorage::Nodes, 
+                    subsumption_checks_enabled: false, 
+                    num_memoized_nodes: 0,
+                    num_swept_nodes: 0
+                }
+            }
+        }
+    }
+
+    pub fn prunes_queued_steps_of_subsumed_nodes(&self) -> bool {
         match &self {
-            NodeMemoizer::Memoizing(memo) => {
-                for (memoized_node, memoized_node_id) in memo {
+            NodeMemoizer::Memoizing(NodeMemo::Nodes(_),options,_) => options.antichain && options.prune_queued_steps_of_subsumed_nodes,
+            _ => false
+        }
+    }
+
+    /** 
+     * Looks up a node, reached at a given depth, in the memo.
+     * 
+     * With the depth-aware option, memoized nodes that were expanded at a greater depth
+     * are only considered if no other memoized node includes the reached node.
+     * **/
+    pub fn check_memo(&self, to_look_up : &Conf::DomainSpecificNode, depth : u32) -> NodeMemoCheck {
+        match &self {
+            NodeMemoizer::Memoizing(NodeMemo::Nodes(memo),options,_) => {
+                let mut selected : Option<(&Conf::DomainSpecificNode,u32)> = None;
+                let mut selected_deeper : Option<(&Conf::DomainSpecificNode,u32)> = None;
+                for (memoized_node, memoized_entry) in memo {
                     if to_look_up.is_included_for_memoization(memoized_node) {
-                        return Some(*memoized_node_id);
+                        let candidate = (memoized_node,memoized_entry.id);
+                        let to_update = if options.depth_aware && memoized_entry.depth > depth {
+                            &mut selected_deeper
+                        } else {
+                            &mut selected
+                        };
+                        *to_update = match *to_update {
+                            Some(current) if !options.subsumption_resolution.prefers(candidate,current) => {
+                                Some(current)
+                            },
+                            _ => {
+                                Some(candidate)
+                            }
+                        };
                     }
                 }
-                None
+                match (selected,selected_deeper) {
+                    (Some((_,memoized_node_id)),_) => NodeMemoCheck::Memoized(memoized_node_id),
+                    (None,Some((_,memoized_node_id))) => NodeMemoCheck::MemoizedDeeper(memoized_node_id),
+                    (None,None) => NodeMemoCheck::NotMemoized
+                }
+            },
+            NodeMemoizer::Memoizing(memo,options,_) => {
+                // with fingerprints, only exact matching is possible
+                match memo.get_exact_match(to_look_up) {
+                    None => NodeMemoCheck::NotMemoized,
+                    Some(memoized_entry) if options.depth_aware && memoized_entry.depth > depth => {
+                        NodeMemoCheck::MemoizedDeeper(memoized_entry.id)
+                    },
+                    Some(memoized_entry) => NodeMemoCheck::Memoized(memoized_entry.id)
+                }
             },
             NodeMemoizer::NotMemoizing => {
-                None 
+                NodeMemoCheck::NotMemoized 
             }
         }
     }
 
-    pub fn memoize_new_node(&mut self, new_node : &Conf::DomainSpecificNode, new_node_id : u32) {
+    /** 
+     * Returns the memoized nodes with their entries, which is only possible if the memo keeps the nodes themselves.
+     * **/
+    pub fn get_memoized_nodes(&self) -> Vec<(&Conf::DomainSpecificNode,&NodeMemoEntry)> {
+        match &self {
+            NodeMemoizer::Memoizing(NodeMemo::Nodes(memo),_,_) => {
+                // ordered by identifiers so that it does not depend on the iteration order of the memo
+                let mut memoized : Vec<(&Conf::DomainSpecificNode,&NodeMemoEntry)> = memo.iter().collect();
+                memoized.sort_by_key(|(_,memoized_entry)| memoized_entry.id);
+                memoized
+            },
+            _ => vec![]
+        }
+    }
+
+    /** 
+     * Memoizes a new node that is expanded at a given depth.
+     * 
+     * With the antichain option, the memoized nodes that are included in the new node
+     * (and, with the depth-aware option, that were not expanded at a smaller depth)
+     * are removed from the memo and their identifiers are returned.
+     * **/
+    pub fn memoize_new_node(
+        &mut self, 
+        new_node : &Conf::DomainSpecificNode, 
+        new_node_id : u32,
+        depth : u32
+    ) -> Vec<u32> {
+        if let NodeMemoizer::Memoizing(_,_,sweep_line_tracker) = self {
+            sweep_line_tracker.num_memoized_since_last_sweep += 1;
+        }
         match self {
-            NodeMemoizer::Memoizing(memo) => {
-                memo.insert(new_node.clone(),new_node_id);
+            NodeMemoizer::Memoizing(NodeMemo::Nodes(memo),options,_) => {
+                let mut subsumed_nodes_ids = vec![];
+                if options.antichain {
+                    let depth_aware = options.depth_aware;
+                    memo.retain(|memoized_node, memoized_entry| {
+                        if memoized_node.is_included_for_memoization(new_node) 
+                        && (!depth_aware || memoized_entry.depth >= depth) {
+                            subsumed_nodes_ids.push(memoized_entry.id);
+                            false
+                        } else {
+                            true
+                        }
+                    });
+                }
+                memo.insert(new_node.clone(),NodeMemoEntry{id:new_node_id,depth,progress:new_node.get_progress_measure()});
+                subsumed_nodes_ids.sort();
+                subsumed_nodes_ids
+            },
+            NodeMemoizer::Memoizing(memo,_,_) => {
+                memo.insert(new_node,NodeMemoEntry{id:new_node_id,depth,progress:new_node.get_progress_measure()});
+                vec![]
             },
-            NodeMemoizer::NotMemoizing => {}
+            NodeMemoizer::NotMemoizing => {
+                vec![]
+            }
+        }
+    }
+
+    /** 
+     * With the sweep-line option, returns true once the given number of nodes has been memoized since the last sweep.
+     * **/
+    pub fn is_sweep_due(&self) -> bool {
+        match &self {
+            NodeMemoizer::Memoizing(_,options,sweep_line_tracker) => {
+                match options.sweep_line_period {
+                    Some(period) => sweep_line_tracker.num_memoized_since_last_sweep >= period,
+                    None => false
+                }
+            },
+            NodeMemoizer::NotMemoizing => false
+        }
+    }
+
+    /** 
+     * Forgets the memoized nodes that are behind the progress front,
+     * i.e., whose progress measure is strictly smaller than the given minimum progress measure of the frontier.
+     * **/
+    pub fn sweep(&mut self, frontier_min_progress : u64) {
+        if let NodeMemoizer::Memoizing(memo,_,sweep_line_tracker) = self {
+            sweep_line_tracker.num_swept_nodes += memo.sweep(frontier_min_progress);
+            sweep_line_tracker.num_memoized_since_last_sweep = 0;
         }
     }
 
@@ -55,6 +55,22 @@ pub trait AbstractProcessMutablePersistentState<Conf : AbstractProcessConfigurat
         steps : &[Conf::DomainSpecificStep]
     );
 
+    /** 
+     * Updates the global state once a step leads back to an already known (memoized) node.
+     * Here "reached_node" is the node that is yielded by the step, 
+     * which is included in the memoized node (see "is_included_for_memoization").
+     * 
+     * This is only relevant when memoization is used.
+     * By default, the global state is not updated.
+     * **/
+    fn update_on_memo_hit(
+        &mut self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _parent_node : &Conf::DomainSpecificNode,
+        _step : &Conf::DomainSpecificStep,
+        _reached_node : &Conf::DomainSpecificNode
+    ) {}
+
 
     fn update_on_filtered(
         &mut self,
@@ -0,0 +1,158 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use crate::process::config::AbstractProcessConfiguration;
+
+
+
+/** 
+ * Renders domain-specific nodes, steps and filtration results so that they can be displayed by generic loggers
+ * (see the "loggers" module), which can therefore be reused across processes.
+ * 
+ * Only textual labels are required.
+ * The other renderings are optional and loggers ignore them when they are not provided:
+ * - tooltips i.e., additional text displayed when hovering over an element
+ * - style classes i.e., names with which elements can be styled in the output (e.g., CSS classes)
+ * - images of nodes, exported into files
+ * **/
+pub trait AbstractProcessLabeller<Conf : AbstractProcessConfiguration> {
+
+    fn get_node_label(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        node : &Conf::DomainSpecificNode
+    ) -> String;
+
+    fn get_step_label(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        step : &Conf::DomainSpecificStep
+    ) -> String;
+
+    fn get_filtration_result_label(
+        &self,
+        context_and_param : &Conf::ContextAndParameterization,
+        filtration_result : &Conf::FiltrationResult
+    ) -> String;
+
+    fn get_node_tooltip(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _node : &Conf::DomainSpecificNode
+    ) -> Option<String> {
+        None
+    }
+
+    fn get_step_tooltip(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _step : &Conf::DomainSpecificStep
+    ) -> Option<String> {
+        None
+    }
+
+    fn get_filtration_result_tooltip(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _filtration_result : &Conf::FiltrationResult
+    ) -> Option<String> {
+        None
+    }
+
+    fn get_node_style_class(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _node : &Conf::DomainSpecificNode
+    ) -> Option<String> {
+        None
+    }
+
+    fn get_step_style_class(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _step : &Conf::DomainSpecificStep
+    ) -> Option<String> {
+        None
+    }
+
+    fn get_filtration_result_style_class(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _filtration_result : &Conf::FiltrationResult
+    ) -> Option<String> {
+        None
+    }
+
+    /** 
+     * Draws the node, identified by "node_id", into an image file within "output_directory"
+     * and returns the path of that file.
+     * Returns None if nodes are not drawn as images (which is the default) or if drawing has failed.
+     * **/
+    fn export_node_image(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        _node : &Conf::DomainSpecificNode,
+        _node_id : u32,
+        _output_directory : &Path
+    ) -> Option<PathBuf> {
+        None
+    }
+
+}
+
+
+
+/** 
+ * Labels nodes, steps and filtration results via their implementations of Display.
+ * **/
+pub struct DisplayLabeller;
+
+impl<Conf> AbstractProcessLabeller<Conf> for DisplayLabeller 
+    where 
+        Conf : AbstractProcessConfiguration,
+        Conf::DomainSpecificNode : Display,
+        Conf::DomainSpecificStep : Display,
+        Conf::FiltrationResult : Display
+{
+
+    fn get_node_label(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        node : &Conf::DomainSpecificNode
+    ) -> String {
+        node.to_string()
+    }
+
+    fn get_step_label(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        step : &Conf::DomainSpecificStep
+    ) -> String {
+        step.to_string()
+    }
+
+    fn get_filtration_result_label(
+        &self,
+        _context_and_param : &Conf::ContextAndParameterization,
+        filtration_result : &Conf::FiltrationResult
+    ) -> String {
+        filtration_result.to_string()
+    }
+
+}
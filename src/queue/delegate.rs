@@ -71,6 +71,14 @@ impl<
         self.memorized_nodes.get(&id).unwrap()
     }
 
+    /** 
+     * Returns the minimum progress measure among the memorized nodes,
+     * i.e., the nodes from which some steps remain to be processed.
+     * **/
+    pub fn get_memorized_nodes_min_progress_measure(&self) -> Option<u64> {
+        self.memorized_nodes.values().map(|memorized_node| memorized_node.domain_specific_node.get_progress_measure()).min()
+    }
+
     pub fn extract_from_queue(&mut self) -> Option<(EnqueuedStep<DomainSpecificStep>,Option<MemorizedNode<DomainSpecificNode>>)> {
         if let Some((step,parent_has_no_more_child)) = self.process_queue.dequeue() {
             if let Some(parent_node_id) = parent_has_no_more_child {
@@ -84,6 +92,22 @@ impl<
         }
     }
 
+    /** 
+     * Forgets a memorized node and removes all the steps that remain to be fired from it.
+     * Returns the memorized node if it was still waiting for some of its steps to be fired.
+     * **/
+    pub fn remove_memorized_node_and_queued_steps(&mut self, id:u32) -> Option<MemorizedNode<DomainSpecificNode>> {
+        self.process_queue.remove_steps_of_parent(id);
+        self.memorized_nodes.remove(&id)
+    }
+
+    /** 
+     * Returns true if some memorized nodes are still waiting for some of their steps to be fired.
+     * **/
+    pub fn has_pending_steps(&self) -> bool {
+        !self.memorized_nodes.is_empty()
+    }
+
     pub fn queue_set_last_reached_has_no_child(&mut self) {
         self.process_queue.set_last_reached_has_no_child();
     }
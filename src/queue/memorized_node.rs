@@ -26,19 +26,25 @@ use crate::process::config::AbstractNodeKind;
  * Taking on such step may yield another node, which in turn may yield another set of potential next steps and so on.
  * 
  * This struct encodes one such node.
- * It carries the domain specific nature of the node.
+ * It carries the domain specific nature of the node and its depth (the initial node having depth 0).
  * And keeps track of the identifiers of which steps that may be fired from it are yet to be processed.
  * **/
  #[derive(Clone, PartialEq, Eq)]
 pub struct MemorizedNode<DomainSpecificNode : AbstractNodeKind> {
     pub domain_specific_node : DomainSpecificNode,
+    pub depth : u32,
     pub remaining_child_steps_ids_to_process : HashSet<u32>
 }
 
 impl<DomainSpecificNode : AbstractNodeKind> MemorizedNode<DomainSpecificNode> {
-    pub fn new(domain_specific_node: DomainSpecificNode, remaining_child_steps_ids_to_process: HashSet<u32>) -> Self {
+    pub fn new(
+        domain_specific_node: DomainSpecificNode, 
+        depth : u32,
+        remaining_child_steps_ids_to_process: HashSet<u32>
+    ) -> Self {
         Self { 
             domain_specific_node, 
+            depth,
             remaining_child_steps_ids_to_process
         }
     }
@@ -45,4 +45,10 @@ pub trait AbstractStepsQueue<DomainSpecificStep> {
     
     fn set_last_reached_has_no_child(&mut self);
 
+    /** 
+     * Removes all the steps that remain to be fired from a given node.
+     * Returns true if there were any such steps.
+    **/
+    fn remove_steps_of_parent(&mut self, parent_node_id : u32) -> bool;
+
 }
\ No newline at end of file
@@ -62,6 +62,12 @@ impl<DomainSpecificStep> AbstractStepsQueue<DomainSpecificStep> for DfsStepsQueu
     }
 
     fn set_last_reached_has_no_child(&mut self) {}
+
+    fn remove_steps_of_parent(&mut self, parent_node_id : u32) -> bool {
+        let initial_len = self.queue.len();
+        self.queue.retain(|(parent_id,_)| *parent_id != parent_node_id);
+        self.queue.len() != initial_len
+    }
 }
 
 
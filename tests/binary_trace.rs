@@ -0,0 +1,135 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use std::io::ErrorKind;
+
+use graph_process_manager_core::loggers::binary_trace::codec::AbstractBinaryPayloadCodec;
+use graph_process_manager_core::loggers::binary_trace::encoding::BinaryTraceError;
+use graph_process_manager_core::loggers::binary_trace::reader::BinaryTraceReader;
+use graph_process_manager_core::loggers::binary_trace::writer::BinaryTraceWriterLogger;
+use graph_process_manager_core::process::filter_kinds::max_depth::MaxDepthFilter;
+use graph_process_manager_core::process::logger::AbstractProcessLogger;
+use graph_process_manager_core::process::node_memoizer::NodeMemoizationOptions;
+use graph_process_manager_core::queue::priorities::GenericProcessPriorities;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+struct CounterCodec;
+
+fn decode_u32(bytes : &[u8]) -> Result<u32,String> {
+    let bytes : [u8;4] = bytes.try_into().map_err(|_| format!("expected 4 bytes, got {}", bytes.len()))?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+impl AbstractBinaryPayloadCodec<CounterConfig> for CounterCodec {
+    fn encode_node(&self, _context_and_param : &CounterContext, node : &CounterNode) -> Vec<u8> {
+        node.0.to_le_bytes().to_vec()
+    }
+
+    fn decode_node(&self, _context_and_param : &CounterContext, bytes : &[u8]) -> Result<CounterNode,String> {
+        decode_u32(bytes).map(CounterNode)
+    }
+
+    fn encode_step(&self, _context_and_param : &CounterContext, step : &u32) -> Vec<u8> {
+        step.to_le_bytes().to_vec()
+    }
+
+    fn decode_step(&self, _context_and_param : &CounterContext, bytes : &[u8]) -> Result<u32,String> {
+        decode_u32(bytes)
+    }
+
+    fn encode_filtration_result(&self, _context_and_param : &CounterContext, filtration_result : &String) -> Vec<u8> {
+        filtration_result.as_bytes().to_vec()
+    }
+
+    fn decode_filtration_result(&self, _context_and_param : &CounterContext, bytes : &[u8]) -> Result<String,String> {
+        String::from_utf8(bytes.to_vec()).map_err(|e| e.to_string())
+    }
+}
+
+/** 
+ * Records a run which memoizes nodes, defers steps beyond depth 2 and resumes from them.
+ * Returns the recorded trace and the events notified during the run.
+ * **/
+fn record_trace() -> (Vec<u8>,Vec<String>) {
+    let mut filters_manager = no_filters();
+    filters_manager.add_step_filter("depth", Box::new(MaxDepthFilter::new(2, |depth| format!("depth {}", depth))));
+    assert!(filters_manager.set_filter_deferring("depth", true));
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1,2], 5), QueueSearchStrategy::BFS, filters_manager, Some(NodeMemoizationOptions::default()), 0
+    );
+    manager.loggers.push(Box::new(BinaryTraceWriterLogger::new(vec![], CounterCodec)));
+    assert!(manager.start_process());
+    assert!(manager.set_filter_enabled("depth", false));
+    assert!(manager.resume_from_deferred_frontier());
+    let writer = manager.get_logger_of_type::<BinaryTraceWriterLogger<Vec<u8>,CounterCodec>>().unwrap();
+    assert!(writer.get_io_error().is_none());
+    (writer.sink.clone(), get_events_logger(&manager).events.clone())
+}
+
+fn replay_trace(trace : &[u8]) -> (Result<u64,BinaryTraceError>,Vec<String>) {
+    let mut reader = BinaryTraceReader::new(trace, CounterCodec).unwrap();
+    let mut loggers : Vec<Box<dyn AbstractProcessLogger<CounterConfig>>> = vec![Box::new(EventsLogger::default())];
+    let result = reader.replay(
+        &CounterContext::new(vec![1,2], 5),
+        &GenericProcessPriorities::new(CounterPriorities, false),
+        &no_filters(),
+        &CounterState::default(),
+        &mut loggers
+    );
+    let events = loggers[0].as_any().downcast_ref::<EventsLogger>().unwrap().events.clone();
+    (result, events)
+}
+
+#[test]
+fn replaying_a_trace_notifies_the_recorded_events() {
+    let (trace, recorded_events) = record_trace();
+    for kind in ["memo_hit", "memo_size", "step_filtered", "deferred", "resume", "summary"] {
+        assert!(recorded_events.iter().any(|x| x.starts_with(kind)), "no {} event recorded", kind);
+    }
+    let (result, replayed_events) = replay_trace(&trace);
+    // each summary event concerns a single filter, and there is a single filter
+    assert_eq!(result.unwrap(), recorded_events.len() as u64);
+    assert_eq!(replayed_events, recorded_events);
+}
+
+#[test]
+fn replaying_a_truncated_trace_stops_at_the_incomplete_event() {
+    let (trace, recorded_events) = record_trace();
+    // the last event (termination) is a single byte, so the one before it is cut
+    let (result, replayed_events) = replay_trace(&trace[..trace.len() - 2]);
+    match result {
+        Err(BinaryTraceError::Io(e)) => {
+            assert_eq!(e.kind(), ErrorKind::UnexpectedEof);
+        },
+        other => {
+            panic!("expected an unexpected end of file, got {:?}", other);
+        }
+    }
+    assert_eq!(replayed_events[..], recorded_events[..recorded_events.len() - 2]);
+}
+
+#[test]
+fn reading_something_else_than_a_trace_fails() {
+    assert!(matches!(BinaryTraceReader::new(&b"GPM"[..], CounterCodec), Err(BinaryTraceError::NotABinaryTrace)));
+    assert!(matches!(BinaryTraceReader::new(&b"not a trace"[..], CounterCodec), Err(BinaryTraceError::NotABinaryTrace)));
+    assert!(matches!(BinaryTraceReader::new(&b"GPMT\x07"[..], CounterCodec), Err(BinaryTraceError::UnsupportedVersion(7))));
+}
@@ -0,0 +1,130 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use std::any::Any;
+use std::cell::Cell;
+use std::rc::Rc;
+
+use graph_process_manager_core::process::filter::{AbstractNodePreFilter, AbstractStepFilter};
+use graph_process_manager_core::process::filter_kinds::combinators::{AllOf, AnyOf, Map, Not, When};
+
+use common::*;
+
+
+
+/** 
+ * Is activated (or not) on every node and step, counting its applications.
+ * **/
+struct ConstantFilter {
+    activated : bool,
+    num_applications : Rc<Cell<u32>>
+}
+
+impl ConstantFilter {
+    fn new(activated : bool) -> (Self,Rc<Cell<u32>>) {
+        let num_applications = Rc::new(Cell::new(0));
+        (Self { activated, num_applications : num_applications.clone() }, num_applications)
+    }
+
+    fn apply(&self) -> Option<String> {
+        self.num_applications.set(self.num_applications.get() + 1);
+        if self.activated {
+            Some(format!("constant {}", self.num_applications.get()))
+        } else {
+            None
+        }
+    }
+}
+
+impl AbstractNodePreFilter<CounterConfig> for ConstantFilter {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn apply_filter(&mut self, _context_and_param : &CounterContext, _global_state : &CounterState, _node : &CounterNode, _depth : u32) -> Option<String> {
+        self.apply()
+    }
+}
+
+impl AbstractStepFilter<CounterConfig> for ConstantFilter {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn apply_filter(&mut self, _context_and_param : &CounterContext, _global_state : &CounterState, _parent_node : &CounterNode, _parent_node_id : u32, _parent_depth : u32, _step : &u32) -> Option<String> {
+        self.apply()
+    }
+}
+
+fn apply_on_node(filter : &mut dyn AbstractNodePreFilter<CounterConfig>, value : u32) -> Option<String> {
+    filter.apply_filter(&CounterContext::new(vec![], 0), &CounterState::default(), &CounterNode(value), 0)
+}
+
+fn apply_on_step(filter : &mut dyn AbstractStepFilter<CounterConfig>, step : u32) -> Option<String> {
+    filter.apply_filter(&CounterContext::new(vec![], 0), &CounterState::default(), &CounterNode(0), 1, 0, &step)
+}
+
+#[test]
+fn all_of_stops_at_the_first_filter_that_is_not_activated() {
+    let (first,first_applications) = ConstantFilter::new(true);
+    let (second,_) = ConstantFilter::new(false);
+    let (third,third_applications) = ConstantFilter::new(true);
+    let mut all_of = AllOf::new(vec![first, second, third]);
+    assert_eq!(apply_on_node(&mut all_of, 0), None);
+    assert_eq!(first_applications.get(), 1);
+    assert_eq!(third_applications.get(), 0);
+    let mut empty : AllOf<ConstantFilter> = AllOf::new(vec![]);
+    assert_eq!(apply_on_node(&mut empty, 0), None);
+    assert_eq!(apply_on_step(&mut empty, 0), None);
+}
+
+#[test]
+fn all_of_yields_the_result_of_its_first_filter() {
+    let (first,_) = ConstantFilter::new(true);
+    let (second,second_applications) = ConstantFilter::new(true);
+    let mut all_of = AllOf::new(vec![first, second]);
+    assert_eq!(apply_on_step(&mut all_of, 0), Some("constant 1".to_string()));
+    assert_eq!(second_applications.get(), 1);
+}
+
+#[test]
+fn any_of_stops_at_the_first_filter_that_is_activated() {
+    let (first,_) = ConstantFilter::new(false);
+    let (second,_) = ConstantFilter::new(true);
+    let (third,third_applications) = ConstantFilter::new(true);
+    let mut any_of = AnyOf::new(vec![first, second, third]);
+    assert_eq!(apply_on_node(&mut any_of, 0), Some("constant 1".to_string()));
+    assert_eq!(third_applications.get(), 0);
+    let mut empty : AnyOf<ConstantFilter> = AnyOf::new(vec![]);
+    assert_eq!(apply_on_node(&mut empty, 0), None);
+}
+
+#[test]
+fn not_when_and_map_wrap_their_filter() {
+    let mut not = Not::new(NodeValueFilter(vec![1]), || "not one".to_string());
+    assert_eq!(apply_on_node(&mut not, 1), None);
+    assert_eq!(apply_on_node(&mut not, 2), Some("not one".to_string()));
+    let mut when = When::new(
+        |_ : &CounterContext, _ : &CounterState, node : &CounterNode, _ : u32| node.0 > 5, 
+        NodeValueFilter(vec![1,7])
+    );
+    assert_eq!(apply_on_node(&mut when, 1), None);
+    assert_eq!(apply_on_node(&mut when, 7), Some("value 7".to_string()));
+    let mut map = Map::new(NodeValueFilter(vec![3]), |result : String| result.to_uppercase());
+    assert_eq!(apply_on_node(&mut map, 3), Some("VALUE 3".to_string()));
+}
@@ -0,0 +1,324 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+#![allow(dead_code)]
+
+use std::any::Any;
+
+use graph_process_manager_core::process::config::{AbstractNodeKind, AbstractProcessConfiguration};
+use graph_process_manager_core::process::filter::{AbstractEdgeFilter, AbstractNodePreFilter, GenericFiltersManager};
+use graph_process_manager_core::process::filter_statistics::ManagedFilterInfo;
+use graph_process_manager_core::process::handler::AbstractAlgorithmOperationHandler;
+use graph_process_manager_core::process::logger::AbstractProcessLogger;
+use graph_process_manager_core::process::manager::GenericProcessManager;
+use graph_process_manager_core::process::node_memoizer::NodeMemoizationOptions;
+use graph_process_manager_core::process::persistent_state::AbstractProcessMutablePersistentState;
+use graph_process_manager_core::queue::priorities::{AbstractPriorities, GenericProcessPriorities};
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+
+
+/** 
+ * A toy process that counts : 
+ * from a node, each of the increments of the context can be added to its value
+ * as long as that value is smaller than the bound of the context.
+ * **/
+pub struct CounterConfig;
+
+pub struct CounterContext {
+    pub increments : Vec<u32>,
+    pub bound : u32,
+    /// the process is terminated once that many nodes have been reached
+    pub max_reached_nodes : Option<u32>
+}
+
+impl CounterContext {
+    pub fn new(increments : Vec<u32>, bound : u32) -> Self {
+        Self { increments, bound, max_reached_nodes : None }
+    }
+}
+
+/** 
+ * A node is included in another if they have the same value modulo 100 and if its value is greater.
+ * Below 100, this boils down to equality.
+ * **/
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct CounterNode(pub u32);
+
+impl AbstractNodeKind for CounterNode {
+    fn is_included_for_memoization(&self, memoized_node : &Self) -> bool {
+        self.0 % 100 == memoized_node.0 % 100 && self.0 >= memoized_node.0
+    }
+
+    fn get_progress_measure(&self) -> u64 {
+        self.0 as u64
+    }
+}
+
+impl std::fmt::Display for CounterNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{}", self.0)
+    }
+}
+
+pub struct CounterPriorities;
+
+impl AbstractPriorities<u32> for CounterPriorities {
+    fn get_priority_of_step(&self, _step : &u32) -> i32 {
+        0
+    }
+}
+
+pub struct CounterHandler;
+
+impl AbstractAlgorithmOperationHandler<CounterConfig> for CounterHandler {
+    fn process_new_step(
+        _context_and_param : &CounterContext,
+        global_state : &mut CounterState,
+        parent_node : &CounterNode,
+        step_to_process : &mut u32
+    ) -> CounterNode {
+        global_state.num_fired_steps += 1;
+        CounterNode(parent_node.0 + *step_to_process)
+    }
+
+    fn collect_next_steps(
+        context_and_param : &CounterContext,
+        global_state : &mut CounterState,
+        parent_node : &CounterNode
+    ) -> Vec<u32> {
+        global_state.collected_nodes.push(parent_node.0);
+        if parent_node.0 < context_and_param.bound {
+            context_and_param.increments.clone()
+        } else {
+            vec![]
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CounterState {
+    pub reached_nodes : Vec<u32>,
+    pub collected_nodes : Vec<u32>,
+    pub num_fired_steps : u32,
+    pub num_steps_collection_updates : u32,
+    pub num_filtered : u32
+}
+
+impl AbstractProcessMutablePersistentState<CounterConfig> for CounterState {
+    fn get_initial_state(_context_and_param : &CounterContext, _initial_node : &CounterNode) -> Self {
+        Self::default()
+    }
+
+    fn update_on_node_reached(&mut self, _context_and_param : &CounterContext, node : &CounterNode) {
+        self.reached_nodes.push(node.0);
+    }
+
+    fn update_on_next_steps_collected_reached(&mut self, _context_and_param : &CounterContext, _node : &CounterNode, _steps : &[u32]) {
+        self.num_steps_collection_updates += 1;
+    }
+
+    fn update_on_filtered(&mut self, _context_and_param : &CounterContext, _parent_node : &CounterNode, _filtration_result : &String) {
+        self.num_filtered += 1;
+    }
+
+    fn warrants_termination_of_the_process(&self, context_and_param : &CounterContext) -> bool {
+        match context_and_param.max_reached_nodes {
+            Some(max_reached_nodes) => self.reached_nodes.len() as u32 >= max_reached_nodes,
+            None => false
+        }
+    }
+}
+
+impl AbstractProcessConfiguration for CounterConfig {
+    type ContextAndParameterization = CounterContext;
+    type AlgorithmOperationHandler = CounterHandler;
+    type DomainSpecificNode = CounterNode;
+    type DomainSpecificStep = u32;
+    type Priorities = CounterPriorities;
+    type MutablePersistentState = CounterState;
+    type FiltrationResult = String;
+}
+
+
+
+/** 
+ * Filters the nodes having one of the given values.
+ * **/
+pub struct NodeValueFilter(pub Vec<u32>);
+
+impl AbstractNodePreFilter<CounterConfig> for NodeValueFilter {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn apply_filter(&mut self, _context_and_param : &CounterContext, _global_state : &CounterState, node : &CounterNode, _depth : u32) -> Option<String> {
+        if self.0.contains(&node.0) {
+            Some(format!("value {}", node.0))
+        } else {
+            None
+        }
+    }
+}
+
+
+
+/** 
+ * Filters the transitions that reach one of the given values.
+ * **/
+pub struct ChildValueEdgeFilter(pub Vec<u32>);
+
+impl AbstractEdgeFilter<CounterConfig> for ChildValueEdgeFilter {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn apply_filter(
+        &mut self, 
+        _context_and_param : &CounterContext, 
+        _global_state : &CounterState, 
+        parent_node : &CounterNode, 
+        _parent_depth : u32, 
+        _step : &u32, 
+        child_node : &CounterNode
+    ) -> Option<String> {
+        if self.0.contains(&child_node.0) {
+            Some(format!("edge {} {}", parent_node.0, child_node.0))
+        } else {
+            None
+        }
+    }
+}
+
+
+
+/** 
+ * Records every notification as a line of text.
+ * **/
+#[derive(Default)]
+pub struct EventsLogger {
+    pub events : Vec<String>
+}
+
+impl EventsLogger {
+    pub fn count_events(&self, prefix : &str) -> usize {
+        self.events.iter().filter(|x| x.starts_with(prefix)).count()
+    }
+}
+
+impl AbstractProcessLogger<CounterConfig> for EventsLogger {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(
+        &mut self,
+        _context_and_param : &CounterContext,
+        _strategy : &QueueSearchStrategy,
+        _priorities : &GenericProcessPriorities<CounterPriorities>,
+        _filters_manager : &GenericFiltersManager<CounterConfig>,
+        _initial_global_state : &CounterState,
+        _use_memoization : bool
+    ) {
+        self.events.push("initialize".to_string());
+    }
+
+    fn log_new_node(&mut self, _context_and_param : &CounterContext, new_node_id : u32, new_node : &CounterNode) {
+        self.events.push(format!("node {} {}", new_node_id, new_node.0));
+    }
+
+    fn log_new_step(&mut self, _context_and_param : &CounterContext, origin_node_id : u32, step : &u32, target_node_id : u32, _target_node : &CounterNode) {
+        self.events.push(format!("step {} {} {}", origin_node_id, step, target_node_id));
+    }
+
+    fn log_memo_hit(&mut self, _context_and_param : &CounterContext, origin_node_id : u32, _step : &u32, target_node_id : u32) {
+        self.events.push(format!("memo_hit {} {}", origin_node_id, target_node_id));
+    }
+
+    fn log_memo_size(&mut self, _context_and_param : &CounterContext, num_memoized_nodes : usize) {
+        self.events.push(format!("memo_size {}", num_memoized_nodes));
+    }
+
+    fn log_node_reexpanded(&mut self, _context_and_param : &CounterContext, memoized_node_id : u32, new_node_id : u32, new_depth : u32) {
+        self.events.push(format!("reexpanded {} {} {}", memoized_node_id, new_node_id, new_depth));
+    }
+
+    fn log_notify_last_child_step_of_node_processed(&mut self, _context_and_param : &CounterContext, parent_node_id : u32) {
+        self.events.push(format!("last_child {}", parent_node_id));
+    }
+
+    fn log_notify_node_without_children(&mut self, _context_and_param : &CounterContext, node_id : u32) {
+        self.events.push(format!("no_children {}", node_id));
+    }
+
+    fn log_filtered(&mut self, _context_and_param : &CounterContext, parent_node_id : u32, _filtration_result_id : u32, filtration_result : &String) {
+        self.events.push(format!("filtered {} {}", parent_node_id, filtration_result));
+    }
+
+    fn log_step_filtered(&mut self, _context_and_param : &CounterContext, parent_node_id : u32, step : &u32, _filtration_result_id : u32, filtration_result : &String) {
+        self.events.push(format!("step_filtered {} {} {}", parent_node_id, step, filtration_result));
+    }
+
+    fn log_edge_filtered(&mut self, _context_and_param : &CounterContext, parent_node_id : u32, step : &u32, child_node : &CounterNode, _filtration_result_id : u32, filtration_result : &String) {
+        self.events.push(format!("edge_filtered {} {} {} {}", parent_node_id, step, child_node.0, filtration_result));
+    }
+
+    fn log_deferred(&mut self, _context_and_param : &CounterContext, node_id : u32, step : Option<&u32>) {
+        self.events.push(format!("deferred {} {:?}", node_id, step));
+    }
+
+    fn log_filters_summary(&mut self, _context_and_param : &CounterContext, filters_info : &[&ManagedFilterInfo]) {
+        for filter_info in filters_info {
+            self.events.push(format!("summary {} {}", filter_info.name, filter_info.statistics.num_activations));
+        }
+    }
+
+    fn log_resume(&mut self, _context_and_param : &CounterContext, _global_state : &CounterState) {
+        self.events.push("resume".to_string());
+    }
+
+    fn log_terminate_process(&mut self, _context_and_param : &CounterContext, _global_state : &CounterState) {
+        self.events.push("terminate".to_string());
+    }
+}
+
+
+
+pub fn new_counter_manager(
+    context : CounterContext,
+    strategy : QueueSearchStrategy,
+    filters_manager : GenericFiltersManager<CounterConfig>,
+    memoization_options : Option<NodeMemoizationOptions<CounterConfig>>,
+    initial_value : u32
+) -> GenericProcessManager<CounterConfig> {
+    GenericProcessManager::new(
+        context,
+        strategy,
+        GenericProcessPriorities::new(CounterPriorities, false),
+        filters_manager,
+        vec![Box::new(EventsLogger::default())],
+        memoization_options,
+        CounterNode(initial_value)
+    )
+}
+
+pub fn no_filters() -> GenericFiltersManager<CounterConfig> {
+    GenericFiltersManager::new(vec![], vec![], vec![], vec![], false)
+}
+
+pub fn get_events_logger(manager : &GenericProcessManager<CounterConfig>) -> &EventsLogger {
+    manager.get_logger_of_type::<EventsLogger>().unwrap()
+}
@@ -0,0 +1,267 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use std::any::Any;
+
+use graph_process_manager_core::process::filter::{AbstractEdgeFilter, AbstractNodePostFilter, GenericFiltersManager};
+use graph_process_manager_core::process::filter_kinds::max_depth::MaxDepthFilter;
+use graph_process_manager_core::process::filter_kinds::max_node_count::MaxNodeCountFilter;
+use graph_process_manager_core::process::manager::GenericProcessManager;
+use graph_process_manager_core::process::node_memoizer::NodeMemoizationOptions;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+/** 
+ * Filters the nodes having a given value once their next steps are collected.
+ * **/
+struct NodeValuePostFilter(u32);
+
+impl AbstractNodePostFilter<CounterConfig> for NodeValuePostFilter {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn apply_filter(&mut self, _context_and_param : &CounterContext, _global_state : &CounterState, node : &CounterNode, _depth : u32, _next_steps : &[u32]) -> Option<String> {
+        if node.0 == self.0 {
+            Some(format!("post value {}", node.0))
+        } else {
+            None
+        }
+    }
+}
+
+/** 
+ * Filters the transitions that reach a given value.
+ * **/
+struct TargetValueEdgeFilter(u32);
+
+impl AbstractEdgeFilter<CounterConfig> for TargetValueEdgeFilter {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn apply_filter(&mut self, _context_and_param : &CounterContext, _global_state : &CounterState, _parent_node : &CounterNode, _parent_depth : u32, _step : &u32, child_node : &CounterNode) -> Option<String> {
+        if child_node.0 == self.0 {
+            Some(format!("edge to {}", child_node.0))
+        } else {
+            None
+        }
+    }
+}
+
+fn get_sorted_values(values : &[u32]) -> Vec<u32> {
+    let mut sorted = values.to_vec();
+    sorted.sort();
+    sorted.dedup();
+    sorted
+}
+
+fn run_full_exploration(increments : Vec<u32>, bound : u32) -> CounterState {
+    let mut manager = new_counter_manager(
+        CounterContext::new(increments, bound), QueueSearchStrategy::BFS, no_filters(), Some(NodeMemoizationOptions::default()), 0
+    );
+    manager.start_process();
+    std::mem::take(&mut manager.global_state)
+}
+
+fn get_values_of_full_exploration(increments : Vec<u32>, bound : u32) -> Vec<u32> {
+    get_sorted_values(&run_full_exploration(increments, bound).reached_nodes)
+}
+
+fn run_deferring(
+    filters_manager : GenericFiltersManager<CounterConfig>,
+    filter_name : &str,
+    strategy : QueueSearchStrategy
+) -> GenericProcessManager<CounterConfig> {
+    let mut filters_manager = filters_manager;
+    assert!(filters_manager.set_filter_deferring(filter_name, true));
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1,2], 6), strategy, filters_manager, Some(NodeMemoizationOptions::default()), 0
+    );
+    assert!(!manager.resume_from_deferred_frontier());
+    assert!(manager.start_process());
+    manager
+}
+
+#[test]
+fn resuming_from_deferred_frontier_completes_the_exploration() {
+    for strategy in [QueueSearchStrategy::BFS, QueueSearchStrategy::DFS] {
+        let mut filters_manager = no_filters();
+        filters_manager.add_step_filter("depth", Box::new(MaxDepthFilter::new(2, |depth| format!("depth {}", depth))));
+        let mut manager = run_deferring(filters_manager, "depth", strategy);
+        let report = manager.get_unexplored_frontier_report();
+        assert_eq!(report.num_deferred_nodes, 0);
+        assert!(report.num_deferred_steps > 0);
+        assert_eq!(report.min_depth, Some(3));
+        let num_first_pass_activations = manager.get_filters_manager().get_filters_info()[0].statistics.num_activations;
+        assert!(num_first_pass_activations > 0);
+        // the depth bound is relaxed
+        assert!(manager.set_filter_enabled("depth", false));
+        assert!(manager.resume_from_deferred_frontier());
+        assert!(manager.get_deferred_frontier().is_empty());
+        assert!(!manager.resume_from_deferred_frontier());
+        assert_eq!(get_sorted_values(&manager.global_state.reached_nodes), get_values_of_full_exploration(vec![1,2], 6));
+        // no node is reached twice
+        assert_eq!(manager.global_state.reached_nodes.len(), get_sorted_values(&manager.global_state.reached_nodes).len());
+        // the statistics of the first pass are kept
+        assert_eq!(manager.get_filters_manager().get_filters_info()[0].statistics.num_activations, num_first_pass_activations);
+        let logger = get_events_logger(&manager);
+        assert_eq!(logger.count_events("initialize"), 1);
+        assert_eq!(logger.count_events("resume"), 1);
+        assert_eq!(logger.count_events("terminate"), 2);
+        let resume_position = logger.events.iter().position(|x| x == "resume").unwrap();
+        assert_eq!(logger.events[resume_position - 1], "terminate");
+        assert_eq!(logger.events.last().unwrap(), "terminate");
+        assert_eq!(
+            logger.events[resume_position..].iter().find(|x| x.starts_with("summary")).unwrap(), 
+            &format!("summary depth {}", num_first_pass_activations)
+        );
+        // the parents of deferred steps are notified of their last child only once, after resuming
+        let last_child_events : Vec<&String> = logger.events.iter().filter(|x| x.starts_with("last_child")).collect();
+        assert_eq!(last_child_events.len(), get_sorted_strings(&last_child_events).len());
+        for deferred_event in logger.events[..resume_position].iter().filter(|x| x.starts_with("deferred")) {
+            let parent_node_id = deferred_event.split(' ').nth(1).unwrap();
+            let last_child_event = format!("last_child {}", parent_node_id);
+            assert!(!logger.events[..resume_position].contains(&last_child_event));
+            assert!(logger.events[resume_position..].contains(&last_child_event));
+        }
+    }
+}
+
+fn get_sorted_strings(values : &[&String]) -> Vec<String> {
+    let mut sorted : Vec<String> = values.iter().map(|x| x.to_string()).collect();
+    sorted.sort();
+    sorted.dedup();
+    sorted
+}
+
+#[test]
+fn deferred_nodes_are_not_notified_as_terminal() {
+    let mut filters_manager = no_filters();
+    filters_manager.add_node_pre_filter("value", Box::new(NodeValueFilter(vec![3])));
+    let mut manager = run_deferring(filters_manager, "value", QueueSearchStrategy::BFS);
+    let deferred_node_id = manager.get_deferred_frontier().nodes[0].node_id;
+    {
+        let logger = get_events_logger(&manager);
+        assert_eq!(logger.count_events(&format!("deferred {} None", deferred_node_id)), 1);
+        assert_eq!(logger.count_events(&format!("no_children {}", deferred_node_id)), 0);
+    }
+    assert!(manager.set_filter_enabled("value", false));
+    assert!(manager.resume_from_deferred_frontier());
+    let logger = get_events_logger(&manager);
+    assert!(logger.count_events(&format!("step {} ", deferred_node_id)) > 0);
+    assert_eq!(get_sorted_values(&manager.global_state.reached_nodes), get_values_of_full_exploration(vec![1,2], 6));
+}
+
+#[test]
+fn nodes_deferred_by_post_filters_are_resumed_from_their_collected_steps() {
+    let mut filters_manager = no_filters();
+    filters_manager.add_node_post_filter("post", Box::new(NodeValuePostFilter(2)));
+    let mut manager = run_deferring(filters_manager, "post", QueueSearchStrategy::DFS);
+    assert_eq!(manager.get_deferred_frontier().nodes[0].next_steps, Some(vec![1,2]));
+    assert!(manager.set_filter_enabled("post", false));
+    assert!(manager.resume_from_deferred_frontier());
+    // the steps of the deferred node are collected (and the global state updated) only once
+    let collected = &manager.global_state.collected_nodes;
+    assert_eq!(collected.len(), get_sorted_values(collected).len());
+    assert_eq!(manager.global_state.num_steps_collection_updates as usize, collected.len());
+    assert_eq!(get_sorted_values(&manager.global_state.reached_nodes), get_values_of_full_exploration(vec![1,2], 6));
+}
+
+#[test]
+fn transitions_deferred_by_edge_filters_are_not_fired_again() {
+    let mut filters_manager = no_filters();
+    filters_manager.add_edge_filter("edge", Box::new(TargetValueEdgeFilter(4)));
+    let mut manager = run_deferring(filters_manager, "edge", QueueSearchStrategy::BFS);
+    {
+        let deferred_frontier = manager.get_deferred_frontier();
+        assert_eq!(deferred_frontier.steps.len(), 2);
+        assert!(deferred_frontier.steps.iter().all(|x| x.child_node == Some(CounterNode(4))));
+    }
+    assert!(manager.set_filter_enabled("edge", false));
+    assert!(manager.resume_from_deferred_frontier());
+    // the deferred steps are not fired again
+    assert_eq!(manager.global_state.num_fired_steps, run_full_exploration(vec![1,2], 6).num_fired_steps);
+    assert_eq!(get_sorted_values(&manager.global_state.reached_nodes), get_values_of_full_exploration(vec![1,2], 6));
+    assert_eq!(manager.global_state.reached_nodes.len(), get_sorted_values(&manager.global_state.reached_nodes).len());
+}
+
+
+#[test]
+fn resuming_a_deferred_step_notifies_its_parent_once() {
+    // a single path 0 -> 1 -> 2 -> 3 in which the step from 2 is deferred by the depth bound
+    let mut filters_manager = no_filters();
+    filters_manager.add_step_filter("depth", Box::new(MaxDepthFilter::new(2, |depth| format!("depth {}", depth))));
+    assert!(filters_manager.set_filter_deferring("depth", true));
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1], 3), QueueSearchStrategy::BFS, filters_manager, None, 0
+    );
+    assert!(manager.start_process());
+    assert!(manager.set_filter_enabled("depth", false));
+    assert!(manager.resume_from_deferred_frontier());
+    assert_eq!(
+        get_events_logger(&manager).events, 
+        vec![
+            "initialize", 
+            "node 1 0", 
+            "node 2 1", "step 1 1 2", 
+            "last_child 1", 
+            "node 3 2", "step 2 1 3", 
+            "last_child 2", 
+            "step_filtered 3 1 depth 2", "deferred 3 Some(1)", 
+            "summary depth 1", 
+            "terminate", 
+            "resume", 
+            "node 5 3", "step 3 1 5", "no_children 5", 
+            "last_child 3", 
+            "summary depth 1", 
+            "terminate"
+        ]
+    );
+}
+
+type NodeCountFilter = MaxNodeCountFilter<fn(u32) -> String>;
+
+#[test]
+fn node_count_bound_spans_the_passes_of_the_exploration() {
+    // the first pass stops at depth 2 (nodes 0, 1 and 2)
+    // upon resuming, the count of nodes is not reset, so that only 2 more nodes are expanded
+    let mut filters_manager = no_filters();
+    filters_manager.add_step_filter("depth", Box::new(MaxDepthFilter::new(2, |depth| format!("depth {}", depth))));
+    let count_filter : NodeCountFilter = MaxNodeCountFilter::new(5, |count| format!("count {}", count));
+    filters_manager.add_node_pre_filter("count", Box::new(count_filter));
+    assert!(filters_manager.set_filter_deferring("depth", true));
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1], 10), QueueSearchStrategy::BFS, filters_manager, None, 0
+    );
+    assert!(manager.start_process());
+    assert_eq!(manager.global_state.reached_nodes, vec![0,1,2]);
+    assert!(manager.set_filter_enabled("depth", false));
+    assert!(manager.resume_from_deferred_frontier());
+    assert_eq!(manager.global_state.reached_nodes, vec![0,1,2,3,4,5]);
+    let count_filter = manager.get_filters_manager().get_node_pre_filters()[0].as_any()
+        .downcast_ref::<NodeCountFilter>().unwrap();
+    assert_eq!(count_filter.get_node_count(), 6);
+    let logger = get_events_logger(&manager);
+    assert_eq!(logger.count_events("filtered"), 1);
+    assert!(logger.events.iter().any(|x| x.starts_with("filtered") && x.ends_with("count 6")));
+    assert!(manager.get_deferred_frontier().is_empty());
+}
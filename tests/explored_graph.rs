@@ -0,0 +1,79 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use graph_process_manager_core::loggers::explored_graph::builder::ExploredGraphBuilder;
+use graph_process_manager_core::loggers::explored_graph::graph::ExploredGraphEdgeKind;
+use graph_process_manager_core::process::filter_kinds::max_depth::MaxDepthFilter;
+use graph_process_manager_core::process::node_memoizer::NodeMemoizationOptions;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+#[test]
+fn explored_graph_is_retrieved_from_the_loggers_by_type() {
+    let mut filters_manager = no_filters();
+    filters_manager.add_node_pre_filter("value", Box::new(NodeValueFilter(vec![3])));
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1,2], 4), QueueSearchStrategy::BFS, filters_manager, Some(NodeMemoizationOptions::default()), 0
+    );
+    // the builder is found regardless of its position among the loggers
+    manager.loggers.insert(0, Box::new(ExploredGraphBuilder::<CounterConfig>::new(true)));
+    manager.start_process();
+    let graph = manager.get_logger_of_type::<ExploredGraphBuilder<CounterConfig>>().unwrap().get_graph();
+    let initial_node = graph.get_initial_node().unwrap();
+    assert_eq!(initial_node.node, Some(CounterNode(0)));
+    let mut values : Vec<u32> = graph.nodes.values().map(|x| x.node.as_ref().unwrap().0).collect();
+    values.sort();
+    assert_eq!(values, vec![0,1,2,3,4]);
+    assert_eq!(graph.edges.len(), 6);
+    assert_eq!(graph.edges.iter().filter(|x| x.kind == ExploredGraphEdgeKind::MemoHit).count(), 2);
+    assert_eq!(graph.filtrations.len(), 1);
+    assert_eq!(graph.filtrations[0].filtration_result, "value 3");
+    let filtered_node = graph.get_node(graph.filtrations[0].node_id).unwrap();
+    assert_eq!(filtered_node.node, Some(CounterNode(3)));
+    // the filtered node is not terminal
+    assert!(!filtered_node.is_terminal);
+    let terminal_values : Vec<u32> = graph.get_terminal_nodes().map(|x| x.node.as_ref().unwrap().0).collect();
+    assert_eq!(terminal_values, vec![4]);
+    assert_eq!(graph.get_reachable_nodes(initial_node.node_id).len(), 5);
+}
+
+#[test]
+fn step_filtrations_do_not_cut_the_exploration_of_their_parent_node() {
+    let mut filters_manager = no_filters();
+    filters_manager.add_step_filter("depth", Box::new(MaxDepthFilter::new(1, |depth| format!("depth {}", depth))));
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1,2], 4), QueueSearchStrategy::BFS, filters_manager, Some(NodeMemoizationOptions::default()), 0
+    );
+    manager.loggers.push(Box::new(ExploredGraphBuilder::<CounterConfig>::new(true)));
+    manager.start_process();
+    let graph = manager.get_logger_of_type::<ExploredGraphBuilder<CounterConfig>>().unwrap().get_graph();
+    // 0 -1-> 1 and 0 -2-> 2 are discoveries, and the steps fired from 1 and 2 are filtered
+    assert_eq!(graph.edges.len(), 2);
+    assert!(graph.edges.iter().all(|x| x.kind == ExploredGraphEdgeKind::Discovery));
+    assert_eq!(graph.filtrations.len(), 4);
+    let mut filtered_steps : Vec<(u32,u32)> = graph.filtrations.iter()
+        .map(|x| (graph.get_node(x.node_id).unwrap().node.as_ref().unwrap().0, x.step.unwrap()))
+        .collect();
+    filtered_steps.sort();
+    assert_eq!(filtered_steps, vec![(1,1),(1,2),(2,1),(2,2)]);
+    assert!(graph.filtrations.iter().all(|x| x.filtration_result == "depth 1"));
+    assert_eq!(graph.get_terminal_nodes().count(), 0);
+}
@@ -0,0 +1,89 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+mod common;
+
+use graph_process_manager_core::process::filter::GenericFiltersManager;
+use graph_process_manager_core::process::filter_kinds::max_node_count::MaxNodeCountFilter;
+use graph_process_manager_core::process::filter_kinds::max_step_kind_count::MaxStepKindCountFilter;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+type StepKindCountFilter = MaxStepKindCountFilter<fn(&u32) -> bool,fn(u32) -> String>;
+
+fn new_max_step_kind_count_filter(max_count : u32, is_of_kind : fn(&u32) -> bool) -> StepKindCountFilter {
+    MaxStepKindCountFilter::new(max_count, is_of_kind, |count| format!("count {}", count))
+}
+
+fn run_with_max_step_kind_count(
+    filter : StepKindCountFilter, 
+    increments : Vec<u32>, 
+    bound : u32, 
+    strategy : QueueSearchStrategy
+) -> (CounterState,Vec<String>,usize) {
+    let filters_manager = GenericFiltersManager::new(vec![], vec![], vec![Box::new(filter)], vec![], false);
+    let mut manager = new_counter_manager(CounterContext::new(increments, bound), strategy, filters_manager, None, 0);
+    manager.start_process();
+    let num_counted_nodes = manager.get_filters_manager().get_step_filters()[0].as_any()
+        .downcast_ref::<StepKindCountFilter>().unwrap().get_num_counted_nodes();
+    let filtered = get_events_logger(&manager).events.iter().filter(|x| x.starts_with("step_filtered ")).cloned().collect();
+    (std::mem::take(&mut manager.global_state), filtered, num_counted_nodes)
+}
+
+#[test]
+fn max_step_kind_count_bounds_the_exploration() {
+    let (state,filtered,_) = run_with_max_step_kind_count(
+        new_max_step_kind_count_filter(3, |step| *step == 2), vec![2], 100, QueueSearchStrategy::DFS
+    );
+    assert_eq!(state.reached_nodes, vec![0,2,4,6]);
+    assert_eq!(filtered, vec!["step_filtered 4 2 count 4"]);
+}
+
+#[test]
+fn max_step_kind_count_counts_along_each_path_to_a_node() {
+    // without memoization, 2 is reached along 0 -1-> 1 -1-> 2 (two steps of the kind) and along 0 -2-> 2 (none)
+    // only from the former (which is the node 7) is the step 1 filtered
+    let (state,filtered,num_counted_nodes) = run_with_max_step_kind_count(
+        new_max_step_kind_count_filter(2, |step| *step == 1), vec![1,2], 4, QueueSearchStrategy::BFS
+    );
+    assert_eq!(filtered, vec!["step_filtered 7 1 count 3"]);
+    assert_eq!(state.num_filtered, 1);
+    let mut reached = state.reached_nodes.clone();
+    reached.sort();
+    assert_eq!(reached, vec![0,1,2,2,3,3,4,4,4,4,5,5]);
+    // the counts are forgotten once the children of the nodes have been processed
+    assert_eq!(num_counted_nodes, 0);
+}
+
+#[test]
+fn max_node_count_bounds_the_number_of_expanded_nodes() {
+    let filters_manager = GenericFiltersManager::new(
+        vec![Box::new(MaxNodeCountFilter::new(3, |count| format!("node count {}", count)))], 
+        vec![], 
+        vec![], 
+        vec![], 
+        false
+    );
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1], 100), QueueSearchStrategy::BFS, filters_manager, None, 0
+    );
+    manager.start_process();
+    assert_eq!(manager.global_state.reached_nodes, vec![0,1,2,3]);
+    assert_eq!(manager.global_state.num_filtered, 1);
+    assert!(get_events_logger(&manager).events.contains(&"filtered 4 node count 4".to_string()));
+}
@@ -0,0 +1,109 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use graph_process_manager_core::process::filter_registry::{BuiltFilter, FilterParameterKind, FilterSpecificationError, GenericFiltersRegistry};
+
+use common::*;
+
+
+
+fn get_registry(built_durations : Rc<RefCell<Vec<Duration>>>) -> GenericFiltersRegistry<CounterConfig> {
+    let mut registry = GenericFiltersRegistry::new();
+    registry.register_filter("timeout", vec![("duration",FilterParameterKind::Duration)], Box::new(move |values| {
+        built_durations.borrow_mut().push(values[0].as_duration().unwrap());
+        Ok(BuiltFilter::NodePre(Box::new(NodeValueFilter(vec![]))))
+    }));
+    registry.register_filter("max_value", vec![("value",FilterParameterKind::UnsignedInteger)], Box::new(|values| {
+        let value = values[0].as_u32().ok_or("the value must fit in 32 bits")?;
+        Ok(BuiltFilter::NodePre(Box::new(NodeValueFilter(vec![value]))))
+    }));
+    registry
+}
+
+fn parse_duration(raw_value : &str) -> Result<Duration,FilterSpecificationError> {
+    let built_durations = Rc::new(RefCell::new(vec![]));
+    let registry = get_registry(built_durations.clone());
+    registry.build_filters_manager(&format!("timeout={}", raw_value), false)?;
+    let duration = built_durations.borrow()[0];
+    Ok(duration)
+}
+
+#[test]
+fn durations_are_parsed_with_their_units() {
+    assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+    assert_eq!(parse_duration("10s"), Ok(Duration::from_secs(10)));
+    assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+    assert_eq!(parse_duration("1h"), Ok(Duration::from_secs(3600)));
+    assert_eq!(parse_duration("0s"), Ok(Duration::ZERO));
+    let max_hours = u64::MAX / 3600;
+    assert_eq!(parse_duration(&format!("{}h", max_hours)), Ok(Duration::from_secs(max_hours * 3600)));
+}
+
+#[test]
+fn malformed_durations_are_rejected() {
+    for raw_value in ["10", "s", "3y", "10 s", "-1s", "1.5s"] {
+        match parse_duration(raw_value) {
+            Err(FilterSpecificationError::BadParameter { filter, parameter, expected, value }) => {
+                assert_eq!(filter, "timeout");
+                assert_eq!(parameter, "duration");
+                assert_eq!(expected, FilterParameterKind::Duration);
+                assert_eq!(value, raw_value);
+            },
+            _ => panic!("'{}' should be rejected as malformed", raw_value)
+        }
+    }
+}
+
+#[test]
+fn out_of_range_durations_are_rejected() {
+    let out_of_range = [
+        format!("{}h", u64::MAX / 3600 + 1),
+        format!("{}m", u64::MAX / 60 + 1),
+        format!("{}h", u64::MAX),
+        "99999999999999999999s".to_string(),
+        "99999999999999999999ms".to_string()
+    ];
+    for raw_value in out_of_range {
+        assert_eq!(
+            parse_duration(&raw_value), 
+            Err(FilterSpecificationError::OutOfRangeParameter { 
+                filter : "timeout".to_string(), 
+                parameter : "duration".to_string(), 
+                value : raw_value.clone() 
+            })
+        );
+    }
+}
+
+#[test]
+fn out_of_range_integers_are_rejected() {
+    let registry = get_registry(Rc::new(RefCell::new(vec![])));
+    assert!(matches!(
+        registry.build_filters_manager("max_value=99999999999999999999", false),
+        Err(FilterSpecificationError::OutOfRangeParameter { .. })
+    ));
+    assert!(matches!(
+        registry.build_filters_manager("max_value=99999999999", false),
+        Err(FilterSpecificationError::InvalidParameters { .. })
+    ));
+    assert!(registry.build_filters_manager("max_value=9; timeout=1s", false).is_ok());
+}
@@ -0,0 +1,140 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+mod common;
+
+use std::time::Duration;
+
+use graph_process_manager_core::process::filter::GenericFiltersManager;
+use graph_process_manager_core::process::manager::GenericProcessManager;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+fn run_with_two_filters_on_the_same_node(collect_all_filtration_results : bool) -> Vec<String> {
+    let filters_manager = GenericFiltersManager::new(
+        vec![Box::new(NodeValueFilter(vec![1])), Box::new(NodeValueFilter(vec![1,2]))], 
+        vec![], 
+        vec![], 
+        vec![], 
+        collect_all_filtration_results
+    );
+    let mut manager = new_counter_manager(CounterContext::new(vec![1], 3), QueueSearchStrategy::BFS, filters_manager, None, 0);
+    manager.start_process();
+    assert_eq!(manager.global_state.num_filtered as usize, if collect_all_filtration_results {2} else {1});
+    get_events_logger(&manager).events.iter().filter(|x| x.starts_with("filtered")).cloned().collect()
+}
+
+#[test]
+fn only_the_first_filtration_result_is_kept_by_default() {
+    assert_eq!(run_with_two_filters_on_the_same_node(false), vec!["filtered 2 value 1"]);
+}
+
+#[test]
+fn all_filtration_results_may_be_collected() {
+    assert_eq!(run_with_two_filters_on_the_same_node(true), vec!["filtered 2 value 1", "filtered 2 value 1"]);
+}
+
+
+
+fn new_manager_with_two_filters_on_the_same_node() -> GenericProcessManager<CounterConfig> {
+    let filters_manager = GenericFiltersManager::new(
+        vec![Box::new(NodeValueFilter(vec![1])), Box::new(NodeValueFilter(vec![1,2]))], 
+        vec![], 
+        vec![], 
+        vec![], 
+        false
+    );
+    new_counter_manager(CounterContext::new(vec![1], 3), QueueSearchStrategy::BFS, filters_manager, None, 0)
+}
+
+#[test]
+fn filter_activations_are_counted_and_summarized() {
+    let mut manager = new_manager_with_two_filters_on_the_same_node();
+    manager.start_process();
+    let filters_info = manager.get_filters_manager().get_filters_info();
+    assert_eq!(filters_info.len(), 2);
+    assert_eq!(filters_info[0].name, "NodePreFilter0");
+    assert_eq!(filters_info[0].statistics.num_applications, 2);
+    assert_eq!(filters_info[0].statistics.num_activations, 1);
+    assert_eq!(filters_info[0].statistics.activation_depths.get(&1), Some(&1));
+    assert_eq!(filters_info[1].statistics.num_applications, 1);
+    assert_eq!(filters_info[1].statistics.num_activations, 0);
+    let events = &get_events_logger(&manager).events;
+    assert_eq!(
+        events[events.len()-3..].to_vec(), 
+        vec!["summary NodePreFilter0 1", "summary NodePreFilter1 0", "terminate"]
+    );
+}
+
+#[test]
+fn disabled_filters_are_not_applied() {
+    let mut manager = new_manager_with_two_filters_on_the_same_node();
+    assert!(manager.set_filter_enabled("NodePreFilter0", false));
+    assert!(!manager.set_filter_enabled("unknown", false));
+    manager.start_process();
+    let filters_info = manager.get_filters_manager().get_filters_info();
+    assert!(!filters_info[0].enabled);
+    assert_eq!(filters_info[0].statistics.num_applications, 0);
+    assert_eq!(filters_info[1].statistics.num_activations, 1);
+    assert_eq!(manager.global_state.num_filtered, 1);
+}
+
+#[test]
+fn filter_durations_are_only_measured_on_demand() {
+    let mut manager = new_manager_with_two_filters_on_the_same_node();
+    assert!(!manager.get_filters_manager().measures_filter_durations());
+    manager.start_process();
+    for filter_info in manager.get_filters_manager().get_filters_info() {
+        assert_eq!(filter_info.statistics.total_duration, Duration::ZERO);
+    }
+    let mut timed_manager = new_manager_with_two_filters_on_the_same_node();
+    timed_manager.set_filter_timing(true);
+    assert!(timed_manager.get_filters_manager().measures_filter_durations());
+    timed_manager.start_process();
+    // durations may be too short to be measured but the counters are the same
+    let filters_info = timed_manager.get_filters_manager().get_filters_info();
+    assert_eq!(filters_info[0].statistics.num_applications, 2);
+    assert_eq!(filters_info[0].statistics.num_activations, 1);
+}
+
+
+
+#[test]
+fn edge_filters_discard_the_child_node_before_it_is_reached() {
+    let filters_manager = GenericFiltersManager::new(
+        vec![], 
+        vec![], 
+        vec![], 
+        vec![Box::new(ChildValueEdgeFilter(vec![2]))], 
+        false
+    );
+    let mut manager = new_counter_manager(CounterContext::new(vec![1,2], 3), QueueSearchStrategy::BFS, filters_manager, None, 0);
+    manager.start_process();
+    // the step is fired but the node it yields is neither logged nor reached
+    assert_eq!(manager.global_state.num_fired_steps, 4);
+    assert_eq!(manager.global_state.reached_nodes, vec![0,1,3]);
+    assert_eq!(manager.global_state.num_filtered, 2);
+    let events = &get_events_logger(&manager).events;
+    assert_eq!(
+        events.iter().filter(|x| x.contains("filtered")).cloned().collect::<Vec<String>>(), 
+        vec!["edge_filtered 1 2 2 edge 0 2", "edge_filtered 3 1 2 edge 1 2"]
+    );
+    assert!(!events.iter().any(|x| x.starts_with("node") && x.ends_with(" 2")));
+    assert_eq!(manager.get_filters_manager().get_filters_info()[0].name, "EdgeFilter0");
+    assert_eq!(manager.get_filters_manager().get_filters_info()[0].statistics.num_activations, 2);
+}
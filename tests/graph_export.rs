@@ -0,0 +1,55 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use graph_process_manager_core::loggers::explored_graph::builder::ExploredGraphBuilder;
+use graph_process_manager_core::loggers::graph_export::exporter::export_explored_graph;
+use graph_process_manager_core::loggers::graph_export::format::GraphExportFormat;
+use graph_process_manager_core::loggers::graph_export::logger::StreamingGraphExportLogger;
+use graph_process_manager_core::process::node_memoizer::NodeMemoizationOptions;
+use graph_process_manager_core::process::rendering::DisplayLabeller;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+#[test]
+fn streamed_and_exported_graphs_agree_on_terminal_and_filtered_nodes() {
+    let mut filters_manager = no_filters();
+    filters_manager.add_node_pre_filter("value", Box::new(NodeValueFilter(vec![3])));
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1,2], 4), QueueSearchStrategy::BFS, filters_manager, Some(NodeMemoizationOptions::default()), 0
+    );
+    manager.loggers.push(Box::new(StreamingGraphExportLogger::new(vec![], DisplayLabeller, GraphExportFormat::GraphMl)));
+    manager.loggers.push(Box::new(ExploredGraphBuilder::<CounterConfig>::new(true)));
+    manager.start_process();
+    let streaming_logger = manager.get_logger_of_type::<StreamingGraphExportLogger<Vec<u8>,DisplayLabeller>>().unwrap();
+    assert!(streaming_logger.get_io_error().is_none());
+    let streamed = String::from_utf8(streaming_logger.sink.clone()).unwrap();
+    let graph = manager.get_logger_of_type::<ExploredGraphBuilder<CounterConfig>>().unwrap().get_graph();
+    let mut exported = vec![];
+    export_explored_graph(graph, &manager.context_and_param, &DisplayLabeller, GraphExportFormat::GraphMl, &mut exported).unwrap();
+    let exported = String::from_utf8(exported).unwrap();
+    for document in [&streamed, &exported] {
+        assert_eq!(document.matches("<node ").count(), 5);
+        // only 4 is terminal, 3 being filtered
+        assert_eq!(document.matches("<data key=\"terminal\">true</data>").count(), 1);
+        assert!(document.contains("<data key=\"label\">4</data><data key=\"depth\">2</data>"));
+        assert_eq!(document.matches("<data key=\"filtered\">true</data>").count(), 1);
+    }
+}
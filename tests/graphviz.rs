@@ -0,0 +1,134 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use graph_process_manager_core::loggers::graphviz::logger::{GraphvizProcessLogger, GraphvizRenderer};
+use graph_process_manager_core::loggers::graphviz::style::DefaultGraphvizStyler;
+use graph_process_manager_core::process::filter::GenericFiltersManager;
+use graph_process_manager_core::process::node_memoizer::NodeMemoizationOptions;
+use graph_process_manager_core::process::rendering::{AbstractProcessLabeller, DisplayLabeller};
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+/** 
+ * Labels the counter nodes via Display and gives optional renderings to some of them : 
+ * tooltips to all nodes, a style class to the nodes from 3 onwards and an image to the initial node.
+ * **/
+struct DecoratingLabeller;
+
+impl AbstractProcessLabeller<CounterConfig> for DecoratingLabeller {
+    fn get_node_label(&self, _context_and_param : &CounterContext, node : &CounterNode) -> String {
+        node.to_string()
+    }
+
+    fn get_step_label(&self, _context_and_param : &CounterContext, step : &u32) -> String {
+        step.to_string()
+    }
+
+    fn get_filtration_result_label(&self, _context_and_param : &CounterContext, filtration_result : &String) -> String {
+        filtration_result.clone()
+    }
+
+    fn get_node_tooltip(&self, _context_and_param : &CounterContext, node : &CounterNode) -> Option<String> {
+        Some(format!("counter at {}", node.0))
+    }
+
+    fn get_node_style_class(&self, _context_and_param : &CounterContext, node : &CounterNode) -> Option<String> {
+        if node.0 >= 3 {
+            Some("large".to_string())
+        } else {
+            None
+        }
+    }
+
+    fn export_node_image(&self, _context_and_param : &CounterContext, node : &CounterNode, node_id : u32, output_directory : &Path) -> Option<PathBuf> {
+        if node.0 == 0 {
+            Some(output_directory.join(format!("node_{}.png", node_id)))
+        } else {
+            None
+        }
+    }
+}
+
+fn draw<Labeller>(name : &str, filters_manager : GenericFiltersManager<CounterConfig>, labeller : Labeller) -> Vec<String> 
+    where 
+        Labeller : 'static + AbstractProcessLabeller<CounterConfig>
+{
+    let file_path = std::env::temp_dir().join(format!("gpm_graphviz_{}_{}.dot", name, std::process::id()));
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1,2], 4), QueueSearchStrategy::BFS, filters_manager, Some(NodeMemoizationOptions::default()), 0
+    );
+    manager.loggers.push(Box::new(
+        GraphvizProcessLogger::<CounterConfig,_,_>::new(GraphvizRenderer::new(file_path.clone(), labeller, DefaultGraphvizStyler))
+    ));
+    manager.start_process();
+    let logger = manager.get_logger_of_type::<GraphvizProcessLogger<CounterConfig,Labeller,DefaultGraphvizStyler>>().unwrap();
+    assert!(logger.get_io_error().is_none());
+    let dot = std::fs::read_to_string(&file_path).unwrap();
+    let _ = std::fs::remove_file(&file_path);
+    dot.lines().map(|x| x.to_string()).collect()
+}
+
+fn get_node_line<'a>(lines : &'a [String], label : &str) -> &'a str {
+    lines.iter().find(|x| x.starts_with('n') && x.contains(&format!("[label=\"{}\"", label))).unwrap()
+}
+
+#[test]
+fn graphviz_draws_nodes_steps_and_filtrations() {
+    let lines = draw("all", no_filters(), DisplayLabeller);
+    assert_eq!(lines.first().unwrap(), "digraph process_graph {");
+    assert_eq!(lines.last().unwrap(), "}");
+    // 0 1 2 3 4 5 are reached and 4 and 5 have no children
+    assert_eq!(lines.iter().filter(|x| x.contains("shape=box")).count(), 6);
+    assert_eq!(lines.iter().filter(|x| x.contains("peripheries=2")).count(), 2);
+    assert!(get_node_line(&lines, "4").contains("peripheries=2"));
+    assert!(!get_node_line(&lines, "3").contains("peripheries=2"));
+    // two steps are fired from each of 0 1 2 3
+    assert_eq!(lines.iter().filter(|x| x.contains(" -> n")).count(), 8);
+}
+
+#[test]
+fn graphviz_does_not_style_filtered_nodes_as_terminal() {
+    let mut filters_manager = no_filters();
+    filters_manager.add_node_pre_filter("value", Box::new(NodeValueFilter(vec![3])));
+    let lines = draw("filtered", filters_manager, DisplayLabeller);
+    assert!(!get_node_line(&lines, "3").contains("peripheries=2"));
+    assert!(get_node_line(&lines, "4").contains("peripheries=2"));
+    assert_eq!(lines.iter().filter(|x| x.starts_with('f') && x.contains("label=\"value 3\"")).count(), 1);
+    assert_eq!(lines.iter().filter(|x| x.contains(" -> f")).count(), 1);
+}
+
+#[test]
+fn graphviz_sets_the_optional_renderings_given_by_the_labeller() {
+    let lines = draw("decorated", no_filters(), DecoratingLabeller);
+    for value in 0..6 {
+        assert!(get_node_line(&lines, &value.to_string()).contains(&format!("tooltip=\"counter at {}\"", value)));
+    }
+    assert_eq!(lines.iter().filter(|x| x.contains("class=\"large\"")).count(), 3);
+    let initial_node_line = get_node_line(&lines, "0");
+    assert!(initial_node_line.contains("image=\""));
+    assert!(initial_node_line.contains("node_"));
+    assert_eq!(lines.iter().filter(|x| x.contains("image=")).count(), 1);
+    // the optional renderings of the default labeller are not set
+    let lines = draw("plain", no_filters(), DisplayLabeller);
+    assert!(!lines.iter().any(|x| x.contains("tooltip=") || x.contains("class=") || x.contains("image=")));
+}
@@ -0,0 +1,61 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use graph_process_manager_core::loggers::html_report::logger::{HtmlReportLogger, HtmlReportRenderer};
+use graph_process_manager_core::process::node_memoizer::NodeMemoizationOptions;
+use graph_process_manager_core::process::rendering::DisplayLabeller;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+#[test]
+fn html_report_embeds_the_discovery_tree() {
+    let file_path = std::env::temp_dir().join(format!("gpm_html_report_{}.html", std::process::id()));
+    let mut filters_manager = no_filters();
+    filters_manager.add_node_pre_filter("value", Box::new(NodeValueFilter(vec![3])));
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1,2], 4), QueueSearchStrategy::BFS, filters_manager, Some(NodeMemoizationOptions::default()), 0
+    );
+    manager.loggers.push(Box::new(
+        HtmlReportLogger::new(HtmlReportRenderer::new(file_path.clone(), DisplayLabeller, "<counter>".to_string()))
+    ));
+    manager.start_process();
+    let logger = manager.get_logger_of_type::<HtmlReportLogger<CounterConfig,DisplayLabeller>>().unwrap();
+    assert!(logger.get_io_error().is_none());
+    let report = std::fs::read_to_string(&file_path).unwrap();
+    let _ = std::fs::remove_file(&file_path);
+    assert!(report.contains("&lt;counter&gt;"));
+    let graph = logger.get_graph();
+    let initial_node_id = graph.get_initial_node().unwrap().node_id;
+    assert!(report.contains(&format!("{{\"root\":{},\"nodes\":[", initial_node_id)));
+    for graph_node in graph.nodes.values() {
+        let value = graph_node.node.as_ref().unwrap().0;
+        let parent = match graph_node.discovery_parent_id {
+            Some(parent_id) => parent_id.to_string(),
+            None => "null".to_string()
+        };
+        let expected = format!("{{\"id\":{},\"label\":\"{}\",\"tooltip\":null,\"class\":null,\"parent\":{},", graph_node.node_id, value, parent);
+        assert!(report.contains(&expected), "{}", expected);
+    }
+    // only 4 is terminal, 3 being filtered
+    assert_eq!(report.matches("\"terminal\":true").count(), 1);
+    assert_eq!(report.matches("{\"revisit\":").count(), 2);
+    assert_eq!(report.matches("{\"filtration\":\"value 3\"").count(), 1);
+}
@@ -0,0 +1,91 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use graph_process_manager_core::loggers::json_lines::logger::JsonLinesProcessLogger;
+use graph_process_manager_core::loggers::json_lines::serializer::LabelJsonSerializer;
+use graph_process_manager_core::process::filter_kinds::max_depth::MaxDepthFilter;
+use graph_process_manager_core::process::node_memoizer::NodeMemoizationOptions;
+use graph_process_manager_core::process::rendering::DisplayLabeller;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+type JsonLogger = JsonLinesProcessLogger<Vec<u8>,LabelJsonSerializer<DisplayLabeller>>;
+
+fn get_event_name(line : &str) -> &str {
+    let start = line.find("\"event\":\"").unwrap() + "\"event\":\"".len();
+    let end = start + line[start..].find('"').unwrap();
+    &line[start..end]
+}
+
+#[test]
+fn json_lines_logger_writes_every_event() {
+    let mut filters_manager = no_filters();
+    filters_manager.add_step_filter("depth", Box::new(MaxDepthFilter::new(2, |depth| format!("depth {}", depth))));
+    filters_manager.add_edge_filter("value", Box::new(ChildValueEdgeFilter(vec![3])));
+    assert!(filters_manager.set_filter_deferring("depth", true));
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1,2], 6), 
+        QueueSearchStrategy::BFS, 
+        filters_manager, 
+        Some(NodeMemoizationOptions { depth_aware : true, ..Default::default() }), 
+        0
+    );
+    manager.loggers.push(Box::new(JsonLogger::new(vec![], LabelJsonSerializer::new(DisplayLabeller))));
+    assert!(manager.start_process());
+    assert!(manager.set_filter_enabled("depth", false));
+    assert!(manager.resume_from_deferred_frontier());
+    let logger = manager.get_logger_of_type::<JsonLogger>().unwrap();
+    assert!(logger.get_io_error().is_none());
+    let output = String::from_utf8(logger.sink.clone()).unwrap();
+    let lines : Vec<&str> = output.lines().collect();
+    for (seq,line) in lines.iter().enumerate() {
+        assert!(line.starts_with(&format!("{{\"seq\":{},\"event\":", seq)));
+        assert!(line.ends_with('}'));
+    }
+    let count_lines = |event : &str| lines.iter().filter(|line| get_event_name(line) == event).count();
+    let events_logger = get_events_logger(&manager);
+    for (event,events_logger_prefix) in [
+        ("initialize", "initialize"),
+        ("new_node", "node "),
+        ("new_step", "step "),
+        ("memo_hit", "memo_hit "),
+        ("memo_size", "memo_size "),
+        ("filtered", "filtered "),
+        ("step_filtered", "step_filtered "),
+        ("edge_filtered", "edge_filtered "),
+        ("deferred", "deferred "),
+        ("last_child_step_processed", "last_child "),
+        ("node_without_children", "no_children "),
+        ("resume", "resume"),
+        ("terminate", "terminate")
+    ] {
+        assert_eq!(count_lines(event), events_logger.count_events(events_logger_prefix), "{}", event);
+    }
+    assert!(count_lines("deferred") > 0);
+    assert_eq!(count_lines("resume"), 1);
+    assert_eq!(count_lines("filters_summary"), 2);
+    // edge filtrations carry the step and the child node
+    let edge_filtered : Vec<&&str> = lines.iter().filter(|line| get_event_name(line) == "edge_filtered").collect();
+    assert!(!edge_filtered.is_empty());
+    assert!(edge_filtered.iter().all(|line| line.contains("\"child_node\":\"3\"")));
+    assert!(edge_filtered.iter().any(|line| line.contains("\"step\":\"2\"") && line.contains("\"filtration_result\":\"edge 1 3\"")));
+    assert!(lines.iter().any(|line| line.contains("\"name\":\"depth\",\"kind\":\"StepFilter\"")));
+}
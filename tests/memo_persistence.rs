@@ -0,0 +1,161 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use graph_process_manager_core::process::memo_persistence::{AbstractNodeSerializer, NodeMemoPersistence};
+use graph_process_manager_core::process::filter::GenericFiltersManager;
+use graph_process_manager_core::process::node_memoizer::NodeMemoizationOptions;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+struct CounterNodeSerializer;
+
+impl AbstractNodeSerializer<CounterConfig> for CounterNodeSerializer {
+    fn serialize_node(&self, node : &CounterNode) -> Vec<u8> {
+        node.0.to_le_bytes().to_vec()
+    }
+
+    fn deserialize_node(&self, bytes : &[u8]) -> Option<CounterNode> {
+        Some(CounterNode(u32::from_le_bytes(bytes.try_into().ok()?)))
+    }
+}
+
+fn get_memo_file_path(name : &str) -> PathBuf {
+    let file_path = std::env::temp_dir().join(format!("gpm_memo_persistence_{}_{}.bin", name, std::process::id()));
+    let _ = std::fs::remove_file(&file_path);
+    file_path
+}
+
+fn run_with_persistence(
+    context : CounterContext,
+    filters : Vec<u32>,
+    memoization_options : NodeMemoizationOptions<CounterConfig>,
+    initial_value : u32,
+    file_path : &Path
+) -> (usize,common::CounterState,Vec<String>,Option<String>) {
+    let filters_manager = GenericFiltersManager::new(vec![Box::new(NodeValueFilter(filters))], vec![], vec![], vec![], false);
+    let mut manager = new_counter_manager(context, QueueSearchStrategy::BFS, filters_manager, Some(memoization_options), initial_value);
+    let num_loaded = manager.set_memo_persistence(
+        NodeMemoPersistence::new(file_path.to_path_buf(), Box::new(CounterNodeSerializer))
+    ).unwrap();
+    assert!(manager.start_process());
+    let events = get_events_logger(&manager).events.clone();
+    let error = manager.get_memo_persistence_error().map(|e| e.to_string());
+    (num_loaded, std::mem::take(&mut manager.global_state), events, error)
+}
+
+#[test]
+fn memo_round_trip_gives_warm_start() {
+    let file_path = get_memo_file_path("round_trip");
+    let (num_loaded,first_state,_,error) = run_with_persistence(
+        CounterContext::new(vec![1,2], 6), vec![], NodeMemoizationOptions::default(), 0, &file_path
+    );
+    assert_eq!(num_loaded, 0);
+    assert!(error.is_none());
+    // values 0 to 7 are reached
+    assert_eq!(first_state.reached_nodes.len(), 8);
+    let (num_loaded,second_state,events,error) = run_with_persistence(
+        CounterContext::new(vec![1,2], 6), vec![], NodeMemoizationOptions::default(), 0, &file_path
+    );
+    assert_eq!(num_loaded, 8);
+    assert!(error.is_none());
+    // only the initial node is expanded, its children are known from the previous run
+    assert_eq!(second_state.reached_nodes, vec![0]);
+    // the restored nodes are notified before the initial node so that the steps from the latter target known nodes
+    let restored_events : Vec<&String> = events[1..9].iter().filter(|x| x.starts_with("node ")).collect();
+    assert_eq!(restored_events.len(), 8);
+    // the initial node replaces the restored node of the same value in the memo
+    assert_eq!(events[9..11].to_vec(), vec!["memo_size 8", "node 9 0"]);
+    assert_eq!(events.iter().filter(|x| x.starts_with("memo_hit")).count(), 2);
+    for step_event in events.iter().filter(|x| x.starts_with("step ")) {
+        let target_node_id = step_event.split(' ').nth(3).unwrap();
+        assert!(restored_events.iter().any(|x| x.split(' ').nth(1).unwrap() == target_node_id));
+    }
+    let _ = std::fs::remove_file(&file_path);
+}
+
+#[test]
+fn memo_persistence_only_saves_fully_explored_nodes() {
+    let file_path = get_memo_file_path("partial");
+    // the node of value 4 is filtered so that the nodes from which it can be reached are not fully explored
+    let (_,first_state,_,error) = run_with_persistence(
+        CounterContext::new(vec![1,5], 6), vec![4], NodeMemoizationOptions::default(), 0, &file_path
+    );
+    assert!(error.is_none());
+    assert_eq!(first_state.num_filtered, 1);
+    // the values 5, 6, 7, 8 and 10 cannot reach 4 (9 is only reachable from 4)
+    let (num_loaded,second_state,_,_) = run_with_persistence(
+        CounterContext::new(vec![1,5], 6), vec![], NodeMemoizationOptions::default(), 0, &file_path
+    );
+    assert_eq!(num_loaded, 5);
+    let mut reached = second_state.reached_nodes.clone();
+    reached.sort();
+    assert_eq!(reached, vec![0,1,2,3,4,9]);
+    let _ = std::fs::remove_file(&file_path);
+}
+
+#[test]
+fn memo_persistence_refuses_to_save_after_early_termination() {
+    let file_path = get_memo_file_path("early");
+    let mut context = CounterContext::new(vec![1,2], 6);
+    context.max_reached_nodes = Some(3);
+    let (_,_,_,error) = run_with_persistence(
+        context, vec![], NodeMemoizationOptions::default(), 0, &file_path
+    );
+    assert!(error.is_some());
+    assert!(!file_path.exists());
+}
+
+#[test]
+fn memo_persistence_keeps_depths_for_depth_aware_memoization() {
+    let file_path = get_memo_file_path("depths");
+    let depth_aware_options = || NodeMemoizationOptions { depth_aware : true, ..Default::default() };
+    run_with_persistence(
+        CounterContext::new(vec![1], 5), vec![], depth_aware_options(), 0, &file_path
+    );
+    // starting from 2, the values 3 to 5 are reached at smaller depths than in the previous run
+    let (num_loaded,second_state,events,_) = run_with_persistence(
+        CounterContext::new(vec![1], 5), vec![], depth_aware_options(), 2, &file_path
+    );
+    assert_eq!(num_loaded, 6);
+    assert_eq!(second_state.reached_nodes, vec![2,3,4,5]);
+    assert_eq!(events.iter().filter(|x| x.starts_with("reexpanded")).count(), 3);
+    let _ = std::fs::remove_file(&file_path);
+}
+
+#[test]
+fn memo_persistence_reports_truncated_files() {
+    let file_path = get_memo_file_path("truncated");
+    // a node at depth 0 announced with 4 bytes but that only has 2
+    let mut bytes = 0u32.to_le_bytes().to_vec();
+    bytes.extend_from_slice(&4u32.to_le_bytes());
+    bytes.extend_from_slice(&[1,2]);
+    std::fs::write(&file_path, bytes).unwrap();
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1], 2), QueueSearchStrategy::BFS, no_filters(), Some(NodeMemoizationOptions::default()), 0
+    );
+    let error = manager.set_memo_persistence(
+        NodeMemoPersistence::new(file_path.clone(), Box::new(CounterNodeSerializer))
+    ).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+    let _ = std::fs::remove_file(&file_path);
+}
@@ -0,0 +1,144 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use graph_process_manager_core::process::filter::GenericFiltersManager;
+use graph_process_manager_core::process::filter_kinds::max_depth::MaxDepthFilter;
+use graph_process_manager_core::process::manager::GenericProcessManager;
+use graph_process_manager_core::process::node_memoizer::{NodeMemoStorage, NodeMemoizationOptions};
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+/** 
+ * From 0, both 250 and 150 are reached (in that order), and 250 is included in 150.
+ * **/
+fn run_antichain(prune_queued_steps_of_subsumed_nodes : bool) -> GenericProcessManager<CounterConfig> {
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![150,250], 300), 
+        QueueSearchStrategy::BFS, 
+        no_filters(), 
+        Some(NodeMemoizationOptions { antichain : true, prune_queued_steps_of_subsumed_nodes, ..Default::default() }), 
+        0
+    );
+    manager.start_process();
+    manager
+}
+
+#[test]
+fn antichain_memoization_removes_subsumed_nodes_from_the_memo() {
+    let manager = run_antichain(false);
+    let logger = get_events_logger(&manager);
+    assert_eq!(logger.events.iter().rev().find(|x| x.starts_with("memo_size")).unwrap(), "memo_size 2");
+    assert_eq!(manager.get_memoization_report().num_memoized_nodes, 2);
+    // the steps of the subsumed node are still processed
+    assert_eq!(logger.count_events("step 2 "), 2);
+    assert_eq!(manager.global_state.num_fired_steps, 6);
+}
+
+#[test]
+fn antichain_memoization_may_prune_the_queued_steps_of_subsumed_nodes() {
+    let manager = run_antichain(true);
+    let logger = get_events_logger(&manager);
+    assert_eq!(manager.get_memoization_report().num_memoized_nodes, 2);
+    assert_eq!(logger.count_events("step 2 "), 0);
+    // the subsumed node is notified as done right when the subsuming node is reached
+    let subsumed_position = logger.events.iter().position(|x| x == "last_child 2").unwrap();
+    assert_eq!(logger.events[subsumed_position + 1..subsumed_position + 3].to_vec(), vec!["memo_size 2", "node 3 150"]);
+    assert_eq!(manager.global_state.num_fired_steps, 4);
+}
+
+/** 
+ * In DFS with a depth of at most 2, 2 is first reached at depth 2 (via 1) 
+ * and 4 can only be reached from 2 at depth 1.
+ * **/
+fn run_depth_bounded_dfs(depth_aware : bool) -> GenericProcessManager<CounterConfig> {
+    let filters_manager = GenericFiltersManager::new(
+        vec![], 
+        vec![], 
+        vec![Box::new(MaxDepthFilter::new(2, |depth| format!("depth {}", depth)))], 
+        vec![], 
+        false
+    );
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![2,1], 4), 
+        QueueSearchStrategy::DFS, 
+        filters_manager, 
+        Some(NodeMemoizationOptions { depth_aware, ..Default::default() }), 
+        0
+    );
+    manager.start_process();
+    manager
+}
+
+#[test]
+fn depth_unaware_memoization_misses_nodes_in_depth_bounded_searches() {
+    let manager = run_depth_bounded_dfs(false);
+    assert_eq!(get_events_logger(&manager).count_events("reexpanded"), 0);
+    assert!(!manager.global_state.reached_nodes.contains(&4));
+}
+
+#[test]
+fn depth_aware_memoization_reexpands_nodes_reached_at_a_smaller_depth() {
+    let manager = run_depth_bounded_dfs(true);
+    let logger = get_events_logger(&manager);
+    assert_eq!(logger.count_events("reexpanded"), 1);
+    assert!(logger.events.contains(&"reexpanded 3 9 1".to_string()));
+    // the reexpanded node is a new node, from which 4 is reached
+    assert!(logger.events.contains(&"node 9 2".to_string()));
+    assert!(logger.events.contains(&"step 9 2 10".to_string()));
+    assert!(manager.global_state.reached_nodes.contains(&4));
+}
+
+#[test]
+fn fingerprint_memoization_only_merges_equal_nodes() {
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![150,250], 300), 
+        QueueSearchStrategy::BFS, 
+        no_filters(), 
+        Some(NodeMemoizationOptions { antichain : true, storage : NodeMemoStorage::Fingerprints64, ..Default::default() }), 
+        0
+    );
+    manager.start_process();
+    let report = manager.get_memoization_report();
+    assert!(!report.subsumption_checks_enabled);
+    // without subsumption, 250 is kept besides 150 and 400 is not merged into 0
+    // but 400, which is reached twice, is still merged
+    let mut reached = manager.global_state.reached_nodes.clone();
+    reached.sort();
+    assert_eq!(reached, vec![0,150,250,300,400,500]);
+    assert_eq!(report.num_memoized_nodes, 6);
+}
+
+#[test]
+fn sweep_line_evicts_the_memoized_nodes_behind_the_progress_front() {
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1], 20), 
+        QueueSearchStrategy::DFS, 
+        no_filters(), 
+        Some(NodeMemoizationOptions { sweep_line_period : Some(5), ..Default::default() }), 
+        0
+    );
+    manager.start_process();
+    assert_eq!(manager.global_state.reached_nodes.len(), 21);
+    let report = manager.get_memoization_report();
+    assert!(report.num_swept_nodes > 0);
+    assert!(report.num_memoized_nodes <= 5);
+    assert_eq!(report.num_memoized_nodes + report.num_swept_nodes, 21);
+}
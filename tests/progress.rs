@@ -0,0 +1,58 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use std::time::Duration;
+
+use graph_process_manager_core::loggers::progress::ProgressReporterLogger;
+use graph_process_manager_core::process::manager::GenericProcessManager;
+use graph_process_manager_core::process::node_memoizer::NodeMemoizationOptions;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+fn run_with_progress_reporter(memoization_options : NodeMemoizationOptions<CounterConfig>) -> GenericProcessManager<CounterConfig> {
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1], 20), QueueSearchStrategy::DFS, no_filters(), Some(memoization_options), 0
+    );
+    manager.loggers.push(Box::new(ProgressReporterLogger::new(Vec::<u8>::new(), Duration::from_secs(3600))));
+    manager.start_process();
+    manager
+}
+
+fn get_progress_summary(manager : &GenericProcessManager<CounterConfig>) -> String {
+    let logger = manager.get_logger_of_type::<ProgressReporterLogger<Vec<u8>>>().unwrap();
+    String::from_utf8(logger.sink.clone()).unwrap()
+}
+
+fn get_memo_sizes(manager : &GenericProcessManager<CounterConfig>) -> Vec<usize> {
+    get_events_logger(manager).events.iter()
+        .filter_map(|event| event.strip_prefix("memo_size "))
+        .map(|size| size.parse().unwrap())
+        .collect()
+}
+
+#[test]
+fn progress_reporter_summarizes_the_memo_size() {
+    let manager = run_with_progress_reporter(NodeMemoizationOptions::default());
+    let num_memoized_nodes = manager.get_memoization_report().num_memoized_nodes;
+    assert_eq!(num_memoized_nodes, 21);
+    assert_eq!(get_memo_sizes(&manager), (1..=21).collect::<Vec<usize>>());
+    assert!(get_progress_summary(&manager).contains("21 memoized nodes, 0 memo hits"));
+}
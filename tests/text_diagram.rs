@@ -0,0 +1,82 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use graph_process_manager_core::loggers::explored_graph::builder::ExploredGraphBuilder;
+use graph_process_manager_core::loggers::text_diagram::drawer::draw_explored_graph;
+use graph_process_manager_core::loggers::text_diagram::logger::{TextDiagramProcessLogger, TextDiagramRenderer};
+use graph_process_manager_core::loggers::text_diagram::syntax::TextDiagramSyntax;
+use graph_process_manager_core::process::filter_kinds::max_depth::MaxDepthFilter;
+use graph_process_manager_core::process::node_memoizer::NodeMemoizationOptions;
+use graph_process_manager_core::process::rendering::DisplayLabeller;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+#[test]
+fn mermaid_diagram_marks_terminal_nodes_memo_hits_and_filtrations() {
+    let mut filters_manager = no_filters();
+    filters_manager.add_node_pre_filter("value", Box::new(NodeValueFilter(vec![3])));
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1,2], 4), QueueSearchStrategy::BFS, filters_manager, Some(NodeMemoizationOptions::default()), 0
+    );
+    manager.loggers.push(Box::new(ExploredGraphBuilder::<CounterConfig>::new(true)));
+    manager.start_process();
+    let graph = manager.get_logger_of_type::<ExploredGraphBuilder<CounterConfig>>().unwrap().get_graph();
+    let mut diagram = vec![];
+    draw_explored_graph(graph, &manager.context_and_param, &DisplayLabeller, &TextDiagramSyntax::Mermaid, None, &mut diagram).unwrap();
+    let diagram = String::from_utf8(diagram).unwrap();
+    let lines : Vec<&str> = diagram.lines().collect();
+    assert_eq!(lines[0], "flowchart TD");
+    assert_eq!(lines.iter().filter(|x| x.ends_with(":::terminal")).count(), 1);
+    assert!(lines.iter().any(|x| x.ends_with("[\"4\"]:::terminal")));
+    assert!(lines.iter().any(|x| x.ends_with("[\"3\"]")));
+    assert_eq!(lines.iter().filter(|x| x.contains(" -.->|")).count(), 2);
+    assert_eq!(lines.iter().filter(|x| x.contains(" -->|")).count(), 4);
+    assert_eq!(lines.iter().filter(|x| x.contains(" --x f")).count(), 1);
+}
+
+#[test]
+fn plantuml_diagram_covers_all_the_passes_of_the_process() {
+    let file_path = std::env::temp_dir().join(format!("gpm_text_diagram_{}.puml", std::process::id()));
+    let mut filters_manager = no_filters();
+    filters_manager.add_step_filter("depth", Box::new(MaxDepthFilter::new(1, |depth| format!("depth {}", depth))));
+    assert!(filters_manager.set_filter_deferring("depth", true));
+    let mut manager = new_counter_manager(
+        CounterContext::new(vec![1,2], 4), QueueSearchStrategy::BFS, filters_manager, Some(NodeMemoizationOptions::default()), 0
+    );
+    manager.loggers.push(Box::new(TextDiagramProcessLogger::<CounterConfig,_>::new(
+        TextDiagramRenderer::new(file_path.clone(), DisplayLabeller, TextDiagramSyntax::PlantUml, Some(8))
+    )));
+    manager.start_process();
+    assert!(manager.set_filter_enabled("depth", false));
+    assert!(manager.resume_from_deferred_frontier());
+    let logger = manager.get_logger_of_type::<TextDiagramProcessLogger<CounterConfig,DisplayLabeller>>().unwrap();
+    assert!(logger.get_io_error().is_none());
+    assert_eq!(logger.get_graph().nodes.len(), 6);
+    let diagram = std::fs::read_to_string(&file_path).unwrap();
+    let _ = std::fs::remove_file(&file_path);
+    let lines : Vec<&str> = diagram.lines().collect();
+    assert_eq!(lines.first().unwrap(), &"@startuml");
+    assert_eq!(lines.last().unwrap(), &"@enduml");
+    assert_eq!(lines.iter().filter(|x| x.starts_with("state \"") && x.contains(" as n")).count(), 6);
+    assert_eq!(lines.iter().filter(|x| x.starts_with("[*] --> n")).count(), 1);
+    // the deferred steps of the first pass are drawn as filtered branches
+    assert!(lines.iter().any(|x| x.contains("-[#CC0000,dotted]-> f")));
+}
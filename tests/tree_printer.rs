@@ -0,0 +1,123 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+mod common;
+
+use graph_process_manager_core::loggers::tree_printer::logger::{TreePrinterLogger, TreePrinterRenderer};
+use graph_process_manager_core::loggers::tree_printer::options::{TreeGlyphs, TreePrinterOptions};
+use graph_process_manager_core::process::filter::GenericFiltersManager;
+use graph_process_manager_core::process::manager::GenericProcessManager;
+use graph_process_manager_core::process::node_memoizer::NodeMemoizationOptions;
+use graph_process_manager_core::process::rendering::DisplayLabeller;
+use graph_process_manager_core::queue::priorities::GenericProcessPriorities;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use common::*;
+
+
+
+fn print_tree(increments : Vec<u32>, bound : u32, options : TreePrinterOptions) -> Vec<String> {
+    print_explored_tree(increments, bound, options, QueueSearchStrategy::BFS, no_filters(), None)
+}
+
+fn print_explored_tree(
+    increments : Vec<u32>, 
+    bound : u32, 
+    options : TreePrinterOptions,
+    strategy : QueueSearchStrategy,
+    filters_manager : GenericFiltersManager<CounterConfig>,
+    memoization_options : Option<NodeMemoizationOptions<CounterConfig>>
+) -> Vec<String> {
+    let mut manager = GenericProcessManager::<CounterConfig>::new(
+        CounterContext::new(increments, bound),
+        strategy,
+        GenericProcessPriorities::new(CounterPriorities, false),
+        filters_manager,
+        vec![Box::new(TreePrinterLogger::new(TreePrinterRenderer::new(vec![], DisplayLabeller, options)))],
+        memoization_options,
+        CounterNode(0)
+    );
+    manager.start_process();
+    let logger = manager.get_logger_of_type::<TreePrinterLogger<CounterConfig,Vec<u8>,DisplayLabeller>>().unwrap();
+    assert!(logger.get_io_error().is_none());
+    String::from_utf8(logger.renderer.sink.clone()).unwrap().lines().map(|x| x.to_string()).collect()
+}
+
+fn collapsing_options(collapse_chains_longer_than : usize) -> TreePrinterOptions {
+    TreePrinterOptions::new(None, Some(collapse_chains_longer_than), true, TreeGlyphs::Ascii)
+}
+
+#[test]
+fn chains_shorter_than_three_nodes_are_never_collapsed() {
+    for collapse_chains_longer_than in [0,1,2] {
+        // chains below the root of 1 and 2 nodes
+        for bound in [1,2] {
+            let collapsed = print_tree(vec![1], bound, collapsing_options(collapse_chains_longer_than));
+            let not_collapsed = print_tree(vec![1], bound, TreePrinterOptions::new(None, None, true, TreeGlyphs::Ascii));
+            assert_eq!(collapsed, not_collapsed);
+            assert_eq!(collapsed.len(), (bound as usize) + 1);
+        }
+    }
+}
+
+#[test]
+fn long_chains_are_collapsed() {
+    // a chain of 4 nodes below the root
+    for collapse_chains_longer_than in [0,1,2,3] {
+        let lines = print_tree(vec![1], 4, collapsing_options(collapse_chains_longer_than));
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].contains("(n2) ... 2 collapsed ... 1 -> 4 (n5)"), "{}", lines[1]);
+    }
+    let lines = print_tree(vec![1], 4, collapsing_options(4));
+    assert_eq!(lines.len(), 5);
+    assert!(lines.iter().all(|x| !x.contains("collapsed")));
+}
+
+#[test]
+fn chains_end_at_branching_nodes() {
+    // 0 -> 1 -> 2 -> 3 then 3 branches
+    let mut lines = print_tree(vec![1], 3, collapsing_options(2));
+    assert_eq!(lines.len(), 2);
+    lines = print_tree(vec![1,100], 3, collapsing_options(2));
+    assert!(lines.iter().all(|x| !x.contains("collapsed")));
+}
+
+#[test]
+fn trees_are_printed_as_explored_with_depth_first_search() {
+    let ascii_options = || TreePrinterOptions::new(None, None, true, TreeGlyphs::Ascii);
+    let new_filters_manager = || {
+        let mut filters_manager = no_filters();
+        filters_manager.add_node_pre_filter("value", Box::new(NodeValueFilter(vec![2])));
+        filters_manager
+    };
+    for strategy in [QueueSearchStrategy::DFS, QueueSearchStrategy::BFS] {
+        let lines = print_explored_tree(
+            vec![1,2], 3, ascii_options(), strategy, new_filters_manager(), Some(NodeMemoizationOptions::default())
+        );
+        assert_eq!(lines[0], "0 (n1)");
+        // 0 1 2 3 are reached, 2 is filtered and reached again from 1
+        assert_eq!(lines.iter().filter(|x| x.contains(" (n")).count(), 4);
+        assert_eq!(lines.iter().filter(|x| x.contains("x value 2")).count(), 1);
+        assert_eq!(lines.iter().filter(|x| x.contains("(revisit)")).count(), 1);
+    }
+    let lines = print_explored_tree(
+        vec![1,2], 3, ascii_options(), QueueSearchStrategy::DFS, new_filters_manager(), Some(NodeMemoizationOptions::default())
+    );
+    // the filtration result is printed right below the filtered node
+    let filtered_node_position = lines.iter().position(|x| x.contains(" -> 2 (n")).unwrap();
+    assert!(lines[filtered_node_position + 1].ends_with("x value 2"));
+    assert!(lines.iter().all(|x| !x.contains("`-- ")));
+}